@@ -34,6 +34,7 @@ enum ErrnoValue {
   ErrnoValue_Success = 0,
   ErrnoValue_Other = 1,
   ErrnoValue_OutOfGas = 2,
+  ErrnoValue_StorageQuotaExceeded = 3,
 };
 typedef int32_t ErrnoValue;
 
@@ -293,6 +294,7 @@ typedef struct {
 
 typedef struct {
   int32_t (*next_db)(iterator_t, UnmanagedVector*, UnmanagedVector*);
+  int32_t (*close_db)(iterator_t, UnmanagedVector*);
 } Iterator_vtable;
 
 typedef struct {
@@ -319,6 +321,11 @@ typedef struct {
 
 typedef struct {
   int32_t (*get_block_info)(const api_t*, uint64_t*, uint64_t*, UnmanagedVector*);
+  int32_t (*get_block_context)(const api_t*, UnmanagedVector*, UnmanagedVector*, UnmanagedVector*, uint64_t*, UnmanagedVector*);
+  int32_t (*bank_withdraw)(const api_t*, U8SliceView, U8SliceView, uint64_t, UnmanagedVector*);
+  int32_t (*bank_deposit)(const api_t*, U8SliceView, U8SliceView, uint64_t, UnmanagedVector*);
+  int32_t (*bank_balance)(const api_t*, U8SliceView, U8SliceView, uint64_t*, UnmanagedVector*);
+  int32_t (*bank_supply)(const api_t*, U8SliceView, uint64_t*, UnmanagedVector*);
 } GoApi_vtable;
 
 typedef struct {
@@ -395,6 +402,12 @@ UnmanagedVector decode_move_resource(Db db,
 
 UnmanagedVector decode_script_bytes(UnmanagedVector *errmsg, ByteSliceView script_bytes);
 
+UnmanagedVector decode_table_entry(Db db,
+                                   UnmanagedVector *errmsg,
+                                   ByteSliceView table_handle,
+                                   ByteSliceView key_bytes,
+                                   ByteSliceView value_bytes);
+
 void destroy_unmanaged_vector(UnmanagedVector v);
 
 UnmanagedVector execute_contract(vm_t *vm_ptr,
@@ -405,6 +418,7 @@ UnmanagedVector execute_contract(vm_t *vm_ptr,
                                  UnmanagedVector *errmsg,
                                  ByteSliceView session_id,
                                  ByteSliceView sender,
+                                 ByteSliceView fee_payer,
                                  ByteSliceView message);
 
 UnmanagedVector execute_script(vm_t *vm_ptr,
@@ -415,13 +429,30 @@ UnmanagedVector execute_script(vm_t *vm_ptr,
                                UnmanagedVector *errmsg,
                                ByteSliceView session_id,
                                ByteSliceView sender,
+                               ByteSliceView fee_payer,
                                ByteSliceView message);
 
+UnmanagedVector export_genesis_state(Db db, UnmanagedVector *errmsg);
+
+void import_genesis_state(Db db, UnmanagedVector *errmsg, ByteSliceView genesis_state);
+
 void initialize(vm_t *vm_ptr,
                 Db db,
                 bool _verbose,
                 UnmanagedVector *errmsg,
-                ByteSliceView module_bundle);
+                ByteSliceView module_bundle,
+                ByteSliceView stdlib_config);
+
+void initialize_with_config(vm_t *vm_ptr,
+                            Db db,
+                            GoApi api,
+                            bool _verbose,
+                            UnmanagedVector *errmsg,
+                            ByteSliceView genesis_config);
+
+UnmanagedVector list_account_data(Db db, UnmanagedVector *errmsg, ByteSliceView address);
+
+void load_vm_config(vm_t *vm_ptr, Db db, UnmanagedVector *errmsg);
 
 UnmanagedVector new_unmanaged_vector(bool nil, const uint8_t *ptr, size_t length);
 
@@ -445,12 +476,39 @@ UnmanagedVector query_contract(vm_t *vm_ptr,
                                UnmanagedVector *errmsg,
                                ByteSliceView message);
 
+/**
+ * exported function to allow a struct as an argument of entry functions and
+ * scripts; the arguments are built by the `constructor` function of its module
+ */
+void register_txn_arg_struct(vm_t *vm_ptr,
+                             UnmanagedVector *errmsg,
+                             ByteSliceView struct_name,
+                             ByteSliceView constructor);
+
 void release_vm(vm_t *vm);
 
+UnmanagedVector scan_table(Db db,
+                           UnmanagedVector *errmsg,
+                           ByteSliceView table_handle,
+                           ByteSliceView start_key,
+                           uint64_t limit,
+                           int32_t order);
+
+/**
+ * exported function to limit the number of bytes stored by an account,
+ * including the tables it pays for; zero removes the limit
+ */
+void set_storage_quota(vm_t *vm_ptr, UnmanagedVector *errmsg, uint64_t storage_quota);
+
 UnmanagedVector test_move_package(UnmanagedVector *errmsg,
                                   NovaCompilerArgument nova_args,
                                   NovaCompilerTestOption test_opt);
 
+/**
+ * exported function to disallow a struct registered by `register_txn_arg_struct`
+ */
+void unregister_txn_arg_struct(vm_t *vm_ptr, UnmanagedVector *errmsg, ByteSliceView struct_name);
+
 /**
  * Returns a version number of this library as a C string.
  *
@@ -13,8 +13,14 @@ typedef GoError (*remove_db_fn)(db_t *ptr, U8SliceView key, UnmanagedVector *err
 typedef GoError (*scan_db_fn)(db_t *ptr, U8SliceView prefix, U8SliceView start, U8SliceView end, int32_t order, GoIter *out, UnmanagedVector *errOut);
 // and api
 typedef GoError (*get_block_info_fn)(api_t *ptr, uint64_t *height, uint64_t *timestamp,  UnmanagedVector *errOut);
+typedef GoError (*get_block_context_fn)(api_t *ptr, UnmanagedVector *chainID, UnmanagedVector *blockHash, UnmanagedVector *proposer, uint64_t *timestampNanos, UnmanagedVector *errOut);
+typedef GoError (*bank_withdraw_fn)(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t amount, UnmanagedVector *errOut);
+typedef GoError (*bank_deposit_fn)(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t amount, UnmanagedVector *errOut);
+typedef GoError (*bank_balance_fn)(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t *balance, UnmanagedVector *errOut);
+typedef GoError (*bank_supply_fn)(api_t *ptr, U8SliceView denom, uint64_t *supply, UnmanagedVector *errOut);
 // and iterator
 typedef GoError (*next_db_fn)(iterator_t ptr, UnmanagedVector *key, UnmanagedVector *errOut);
+typedef GoError (*close_db_fn)(iterator_t ptr, UnmanagedVector *errOut);
 
 // forward declarations (db)
 GoError cGet_cgo(db_t *ptr, U8SliceView key, UnmanagedVector *val, UnmanagedVector *errOut);
@@ -23,8 +29,14 @@ GoError cDelete_cgo(db_t *ptr, U8SliceView key, UnmanagedVector *errOut);
 GoError cScan_cgo(db_t *ptr, U8SliceView prefix, U8SliceView start, U8SliceView end, int32_t order, GoIter *out, UnmanagedVector *errOut);
 // api
 GoError cGetBlockInfo_cgo(api_t *ptr, uint64_t *height, uint64_t *timestamp, UnmanagedVector *errOut);
+GoError cGetBlockContext_cgo(api_t *ptr, UnmanagedVector *chainID, UnmanagedVector *blockHash, UnmanagedVector *proposer, uint64_t *timestampNanos, UnmanagedVector *errOut);
+GoError cBankWithdraw_cgo(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t amount, UnmanagedVector *errOut);
+GoError cBankDeposit_cgo(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t amount, UnmanagedVector *errOut);
+GoError cBankBalance_cgo(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t *balance, UnmanagedVector *errOut);
+GoError cBankSupply_cgo(api_t *ptr, U8SliceView denom, uint64_t *supply, UnmanagedVector *errOut);
 // iterator
 GoError cNext_cgo(iterator_t *ptr, UnmanagedVector *key, UnmanagedVector *errOut);
+GoError cClose_cgo(iterator_t *ptr, UnmanagedVector *errOut);
 */
 import "C"
 
@@ -147,7 +159,8 @@ func buildDB(state *DBState) C.Db {
 }
 
 var iterator_vtable = C.Iterator_vtable{
-	next_db: (C.next_db_fn)(C.cNext_cgo),
+	next_db:  (C.next_db_fn)(C.cNext_cgo),
+	close_db: (C.close_db_fn)(C.cClose_cgo),
 }
 
 // An iterator including referenced objects is 117 bytes large (calculated using https://github.com/DmitriyVTitov/size).
@@ -237,10 +250,25 @@ func cDelete(ptr *C.db_t, key C.U8SliceView, errOut *C.UnmanagedVector) (ret C.G
 
 type GoAPI interface {
 	GetBlockInfo() (uint64, uint64)
+	// GetBlockContext returns chain id, block hash, proposer address and timestamp in nanoseconds
+	GetBlockContext() (string, []byte, []byte, uint64)
+	// BankWithdraw moves coins of the denom from the account into the bridge escrow.
+	// BankWithdraw and BankDeposit are only called after a message succeeded,
+	// in the order of the execution, right before its write set is stored.
+	BankWithdraw(denom string, addr []byte, amount uint64) error
+	// BankDeposit moves coins of the denom from the bridge escrow into the account
+	BankDeposit(denom string, addr []byte, amount uint64) error
+	BankBalance(denom string, addr []byte) (uint64, error)
+	BankSupply(denom string) (uint64, error)
 }
 
 var api_vtable = C.GoApi_vtable{
-	get_block_info: (C.get_block_info_fn)(C.cGetBlockInfo_cgo),
+	get_block_info:    (C.get_block_info_fn)(C.cGetBlockInfo_cgo),
+	get_block_context: (C.get_block_context_fn)(C.cGetBlockContext_cgo),
+	bank_withdraw:     (C.bank_withdraw_fn)(C.cBankWithdraw_cgo),
+	bank_deposit:      (C.bank_deposit_fn)(C.cBankDeposit_cgo),
+	bank_balance:      (C.bank_balance_fn)(C.cBankBalance_cgo),
+	bank_supply:       (C.bank_supply_fn)(C.cBankSupply_cgo),
 }
 
 // contract: original pointer/struct referenced must live longer than C.GoApi struct
@@ -278,6 +306,116 @@ func cGetBlockInfo(ptr *C.api_t, height *C.uint64_t, timestamp *C.uint64_t, errO
 	return C.GoError_None
 }
 
+//export cGetBlockContext
+func cGetBlockContext(ptr *C.api_t, chainID *C.UnmanagedVector, blockHash *C.UnmanagedVector, proposer *C.UnmanagedVector, timestampNanos *C.uint64_t, errOut *C.UnmanagedVector) (ret C.GoError) {
+	defer recoverPanic(&ret)
+
+	if chainID == nil || blockHash == nil || proposer == nil || timestampNanos == nil || errOut == nil {
+		return C.GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	c, h, p, t := api.GetBlockContext()
+	*chainID = newUnmanagedVector([]byte(c))
+	*blockHash = newUnmanagedVector(h)
+	*proposer = newUnmanagedVector(p)
+	*timestampNanos = C.uint64_t(t)
+
+	return C.GoError_None
+}
+
+//export cBankWithdraw
+func cBankWithdraw(ptr *C.api_t, denom C.U8SliceView, addr C.U8SliceView, amount C.uint64_t, errOut *C.UnmanagedVector) (ret C.GoError) {
+	defer recoverPanic(&ret)
+
+	if errOut == nil {
+		return C.GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	if err := api.BankWithdraw(string(copyU8Slice(denom)), copyU8Slice(addr), uint64(amount)); err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.GoError_User
+	}
+
+	return C.GoError_None
+}
+
+//export cBankDeposit
+func cBankDeposit(ptr *C.api_t, denom C.U8SliceView, addr C.U8SliceView, amount C.uint64_t, errOut *C.UnmanagedVector) (ret C.GoError) {
+	defer recoverPanic(&ret)
+
+	if errOut == nil {
+		return C.GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	if err := api.BankDeposit(string(copyU8Slice(denom)), copyU8Slice(addr), uint64(amount)); err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.GoError_User
+	}
+
+	return C.GoError_None
+}
+
+//export cBankBalance
+func cBankBalance(ptr *C.api_t, denom C.U8SliceView, addr C.U8SliceView, balance *C.uint64_t, errOut *C.UnmanagedVector) (ret C.GoError) {
+	defer recoverPanic(&ret)
+
+	if balance == nil || errOut == nil {
+		return C.GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	b, err := api.BankBalance(string(copyU8Slice(denom)), copyU8Slice(addr))
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.GoError_User
+	}
+	*balance = C.uint64_t(b)
+
+	return C.GoError_None
+}
+
+//export cBankSupply
+func cBankSupply(ptr *C.api_t, denom C.U8SliceView, supply *C.uint64_t, errOut *C.UnmanagedVector) (ret C.GoError) {
+	defer recoverPanic(&ret)
+
+	if supply == nil || errOut == nil {
+		return C.GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	s, err := api.BankSupply(string(copyU8Slice(denom)))
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.GoError_User
+	}
+	*supply = C.uint64_t(s)
+
+	return C.GoError_None
+}
+
 //export cScan
 func cScan(ptr *C.db_t, prefix C.U8SliceView, start C.U8SliceView, end C.U8SliceView, order ci32, out *C.GoIter, errOut *C.UnmanagedVector) (ret C.GoError) {
 	defer recoverPanic(&ret)
@@ -364,3 +502,22 @@ func cNext(ref C.iterator_t, key *C.UnmanagedVector, errOut *C.UnmanagedVector)
 	*key = newUnmanagedVector(k)
 	return C.GoError_None
 }
+
+//export cClose
+func cClose(ref C.iterator_t, errOut *C.UnmanagedVector) (ret C.GoError) {
+	defer recoverPanic(&ret)
+	if ref.call_id == 0 || errOut == nil {
+		// we received an invalid pointer
+		return C.GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	if err := closeIterator(uint64(ref.call_id), uint64(ref.iterator_index)); err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.GoError_User
+	}
+
+	return C.GoError_None
+}
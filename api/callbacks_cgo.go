@@ -11,8 +11,14 @@ GoError cDelete(db_t *ptr, U8SliceView key, UnmanagedVector *errOut);
 GoError cScan(db_t *ptr, U8SliceView prefix, U8SliceView start, U8SliceView end, int32_t order, GoIter *out, UnmanagedVector *errOut);
 // imports (api)
 GoError cGetBlockInfo(api_t *ptr, uint64_t *height, uint64_t *timestamp, UnmanagedVector *errOut);
+GoError cGetBlockContext(api_t *ptr, UnmanagedVector *chainID, UnmanagedVector *blockHash, UnmanagedVector *proposer, uint64_t *timestampNanos, UnmanagedVector *errOut);
+GoError cBankWithdraw(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t amount, UnmanagedVector *errOut);
+GoError cBankDeposit(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t amount, UnmanagedVector *errOut);
+GoError cBankBalance(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t *balance, UnmanagedVector *errOut);
+GoError cBankSupply(api_t *ptr, U8SliceView denom, uint64_t *supply, UnmanagedVector *errOut);
 // imports (iterator)
 GoError cNext(iterator_t *ptr, UnmanagedVector *key, UnmanagedVector *errOut);
+GoError cClose(iterator_t *ptr, UnmanagedVector *errOut);
 
 // Gateway functions (db)
 GoError cGet_cgo(db_t *ptr, U8SliceView key, UnmanagedVector *val, UnmanagedVector *errOut) {
@@ -32,11 +38,29 @@ GoError cScan_cgo(db_t *ptr, U8SliceView prefix, U8SliceView start, U8SliceView
 GoError cNext_cgo(iterator_t *ptr, UnmanagedVector *key, UnmanagedVector *errOut) {
 	return cNext(ptr, key, errOut);
 }
+GoError cClose_cgo(iterator_t *ptr, UnmanagedVector *errOut) {
+	return cClose(ptr, errOut);
+}
 
 // Gateway functions (api)
 GoError cGetBlockInfo_cgo(api_t *ptr, uint64_t *height, uint64_t *timestamp, UnmanagedVector *errOut) {
     return cGetBlockInfo(ptr, height, timestamp, errOut);
 }
+GoError cGetBlockContext_cgo(api_t *ptr, UnmanagedVector *chainID, UnmanagedVector *blockHash, UnmanagedVector *proposer, uint64_t *timestampNanos, UnmanagedVector *errOut) {
+    return cGetBlockContext(ptr, chainID, blockHash, proposer, timestampNanos, errOut);
+}
+GoError cBankWithdraw_cgo(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t amount, UnmanagedVector *errOut) {
+    return cBankWithdraw(ptr, denom, addr, amount, errOut);
+}
+GoError cBankDeposit_cgo(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t amount, UnmanagedVector *errOut) {
+    return cBankDeposit(ptr, denom, addr, amount, errOut);
+}
+GoError cBankBalance_cgo(api_t *ptr, U8SliceView denom, U8SliceView addr, uint64_t *balance, UnmanagedVector *errOut) {
+    return cBankBalance(ptr, denom, addr, balance, errOut);
+}
+GoError cBankSupply_cgo(api_t *ptr, U8SliceView denom, uint64_t *supply, UnmanagedVector *errOut) {
+    return cBankSupply(ptr, denom, supply, errOut);
+}
 */
 import "C"
 
@@ -45,7 +45,10 @@ func endCall(callID uint64) {
 	remove := removeFrame(callID)
 	// free all iterators in the frame when we release it
 	for _, iter := range remove {
-		iter.Close()
+		// skip iterators already released by closeIterator
+		if iter != nil {
+			iter.Close()
+		}
 	}
 }
 
@@ -86,6 +89,22 @@ func retrieveIterator(callID uint64, index uint64) dbm.Iterator {
 	return myFrame[posInFrame]
 }
 
+// closeIterator closes the iterator at the given index and releases it from the frame.
+// The slot is kept as nil, so the indexes of the other iterators stay valid.
+func closeIterator(callID uint64, index uint64) error {
+	iteratorFramesMutex.Lock()
+	defer iteratorFramesMutex.Unlock()
+	myFrame := iteratorFrames[callID]
+	posInFrame := int(index) - 1
+	if posInFrame < 0 || posInFrame >= len(myFrame) || myFrame[posInFrame] == nil {
+		return fmt.Errorf("iterator %d not found in call %d", index, callID)
+	}
+
+	err := myFrame[posInFrame].Close()
+	myFrame[posInFrame] = nil
+	return err
+}
+
 // prefixEndBytes returns the []byte that would end a
 // range query for all []byte with a certain prefix
 // Deals with last byte of prefix being FF without overflowing
@@ -111,3 +111,32 @@ func TestRetrieveIterator(t *testing.T) {
 	endCall(callID1)
 	endCall(callID2)
 }
+
+func TestCloseIterator(t *testing.T) {
+	const limit = 2000
+	callID := startCall()
+
+	store := dbm.NewMemDB()
+	var iter dbm.Iterator
+	var err error
+
+	iter, _ = store.Iterator(nil, nil)
+	index1, err := storeIterator(callID, iter, limit)
+	require.NoError(t, err)
+	iter, _ = store.Iterator(nil, nil)
+	index2, err := storeIterator(callID, iter, limit)
+	require.NoError(t, err)
+
+	// closing releases only the given iterator
+	err = closeIterator(callID, index1)
+	require.NoError(t, err)
+	require.Nil(t, retrieveIterator(callID, index1))
+	require.NotNil(t, retrieveIterator(callID, index2))
+
+	// closing twice fails
+	err = closeIterator(callID, index1)
+	require.Error(t, err)
+
+	// closed iterators are skipped when the call ends
+	endCall(callID)
+}
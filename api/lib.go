@@ -33,6 +33,9 @@ func errorWithMessage(err error, b C.UnmanagedVector) error {
 		return types.OutOfGasError{}
 	}
 	msg := copyAndDestroyUnmanagedVector(b)
+	if errno, ok := err.(syscall.Errno); ok && int(errno) == C.ErrnoValue_StorageQuotaExceeded {
+		return types.StorageQuotaExceededError{Msg: string(msg)}
+	}
 	if msg == nil {
 		return err
 	}
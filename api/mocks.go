@@ -1,6 +1,8 @@
 package api
 
 import (
+	"errors"
+
 	dbm "github.com/tendermint/tm-db"
 )
 
@@ -74,11 +76,13 @@ var _ GoAPI = MockAPI{}
 
 type MockAPI struct {
 	BlockInfo *MockBlockInfo
+	Bank      *MockBank
 }
 
 func NewMockAPI(blockInfo *MockBlockInfo) *MockAPI {
 	return &MockAPI{
 		BlockInfo: blockInfo,
+		Bank:      NewMockBank(),
 	}
 }
 
@@ -86,16 +90,118 @@ func (m MockAPI) GetBlockInfo() (uint64, uint64) {
 	return m.BlockInfo.GetBlockInfo()
 }
 
+func (m MockAPI) GetBlockContext() (string, []byte, []byte, uint64) {
+	return m.BlockInfo.GetBlockContext()
+}
+
+func (m MockAPI) BankWithdraw(denom string, addr []byte, amount uint64) error {
+	return m.Bank.Withdraw(denom, addr, amount)
+}
+
+func (m MockAPI) BankDeposit(denom string, addr []byte, amount uint64) error {
+	return m.Bank.Deposit(denom, addr, amount)
+}
+
+func (m MockAPI) BankBalance(denom string, addr []byte) (uint64, error) {
+	return m.Bank.Balance(denom, addr), nil
+}
+
+func (m MockAPI) BankSupply(denom string) (uint64, error) {
+	return m.Bank.Supply(denom), nil
+}
+
 type MockBlockInfo struct {
 	height    uint64
 	timestamp uint64
+
+	chainID        string
+	blockHash      []byte
+	proposer       []byte
+	timestampNanos uint64
 }
 
 // NewMockBlockInfo return MockBlockInfo instance
 func NewMockBlockInfo(height uint64, timestamp uint64) MockBlockInfo {
-	return MockBlockInfo{height, timestamp}
+	return MockBlockInfo{
+		height:         height,
+		timestamp:      timestamp,
+		chainID:        "novum-1",
+		blockHash:      make([]byte, 32),
+		proposer:       make([]byte, 20),
+		timestampNanos: timestamp * 1_000_000_000,
+	}
+}
+
+// SetBlockContext overrides chain id, block hash, proposer and timestamp in nanoseconds
+func (m *MockBlockInfo) SetBlockContext(chainID string, blockHash []byte, proposer []byte, timestampNanos uint64) {
+	m.chainID = chainID
+	m.blockHash = blockHash
+	m.proposer = proposer
+	m.timestampNanos = timestampNanos
 }
 
 func (m MockBlockInfo) GetBlockInfo() (uint64, uint64) {
 	return m.height, m.timestamp
 }
+
+func (m MockBlockInfo) GetBlockContext() (string, []byte, []byte, uint64) {
+	return m.chainID, m.blockHash, m.proposer, m.timestampNanos
+}
+
+/***** Mock Bank ****/
+
+// MockBank is an in-memory bank ledger; withdrawn coins are kept
+// in a per-denom escrow until they are deposited again.
+type MockBank struct {
+	balances map[string]uint64
+	escrow   map[string]uint64
+	supply   map[string]uint64
+}
+
+// NewMockBank return MockBank instance
+func NewMockBank() *MockBank {
+	return &MockBank{
+		balances: make(map[string]uint64),
+		escrow:   make(map[string]uint64),
+		supply:   make(map[string]uint64),
+	}
+}
+
+func balanceKey(denom string, addr []byte) string {
+	return denom + "/" + string(addr)
+}
+
+// Mint creates new coins of the denom in the account
+func (b *MockBank) Mint(denom string, addr []byte, amount uint64) {
+	b.balances[balanceKey(denom, addr)] += amount
+	b.supply[denom] += amount
+}
+
+func (b *MockBank) Withdraw(denom string, addr []byte, amount uint64) error {
+	key := balanceKey(denom, addr)
+	if b.balances[key] < amount {
+		return errors.New("insufficient funds")
+	}
+
+	b.balances[key] -= amount
+	b.escrow[denom] += amount
+	return nil
+}
+
+func (b *MockBank) Deposit(denom string, addr []byte, amount uint64) error {
+	if b.escrow[denom] < amount {
+		return errors.New("insufficient escrow")
+	}
+
+	b.escrow[denom] -= amount
+	b.balances[balanceKey(denom, addr)] += amount
+	return nil
+}
+
+func (b MockBank) Balance(denom string, addr []byte) uint64 {
+	return b.balances[balanceKey(denom, addr)]
+}
+
+func (b MockBank) Supply(denom string) uint64 {
+	return b.supply[denom]
+}
@@ -26,13 +26,15 @@ func AllocateVM() VM {
 }
 
 // Initialize call ffi(`initialize`) to initialize vm
-// and publish standard libraries
+// and publish standard libraries; every standard library
+// is published when stdlibConfig is nil
 // CONTRACT: should be executed at chain genesis
 func Initialize(
 	vm VM,
 	store KVStore,
 	verbose bool,
 	moduleBundle []byte,
+	stdlibConfig []byte,
 ) error {
 	var err error
 
@@ -44,10 +46,12 @@ func Initialize(
 
 	mb := makeView(moduleBundle)
 	defer runtime.KeepAlive(mb)
+	sc := makeView(stdlibConfig)
+	defer runtime.KeepAlive(sc)
 
 	errmsg := newUnmanagedVector(nil)
 
-	_, err = C.initialize(vm.ptr, db, cbool(verbose), &errmsg, mb)
+	_, err = C.initialize(vm.ptr, db, cbool(verbose), &errmsg, mb, sc)
 	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
 		// Depending on the nature of the error, `gasUsed` will either have a meaningful value, or just 0.                                                                            │                                 struct ByteSliceView checksum,
 		return errorWithMessage(err, errmsg)
@@ -56,6 +60,118 @@ func Initialize(
 	return err
 }
 
+// InitializeWithConfig call ffi(`initialize_with_config`) to apply
+// the genesis config in a single session
+func InitializeWithConfig(
+	vm VM,
+	store KVStore,
+	api GoAPI,
+	verbose bool,
+	genesisConfig []byte,
+) error {
+	var err error
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+	_api := buildAPI(&api)
+
+	gc := makeView(genesisConfig)
+	defer runtime.KeepAlive(gc)
+
+	errmsg := newUnmanagedVector(nil)
+
+	_, err = C.initialize_with_config(vm.ptr, db, _api, cbool(verbose), &errmsg, gc)
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return errorWithMessage(err, errmsg)
+	}
+
+	return err
+}
+
+// LoadVMConfig call ffi(`load_vm_config`) to configure the vm
+// with the gas schedule and the module publishers of the store
+func LoadVMConfig(
+	vm VM,
+	store KVStore,
+) error {
+	var err error
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	errmsg := newUnmanagedVector(nil)
+
+	_, err = C.load_vm_config(vm.ptr, db, &errmsg)
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return errorWithMessage(err, errmsg)
+	}
+
+	return nil
+}
+
+// RegisterTxnArgStruct call ffi(`register_txn_arg_struct`) to allow
+// a struct as an argument of entry functions and scripts
+func RegisterTxnArgStruct(
+	vm VM,
+	structName string,
+	constructor string,
+) error {
+	sn := makeView([]byte(structName))
+	defer runtime.KeepAlive(sn)
+	ct := makeView([]byte(constructor))
+	defer runtime.KeepAlive(ct)
+
+	errmsg := newUnmanagedVector(nil)
+
+	_, err := C.register_txn_arg_struct(vm.ptr, &errmsg, sn, ct)
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return errorWithMessage(err, errmsg)
+	}
+
+	return nil
+}
+
+// UnregisterTxnArgStruct call ffi(`unregister_txn_arg_struct`) to disallow
+// a struct registered by RegisterTxnArgStruct
+func UnregisterTxnArgStruct(
+	vm VM,
+	structName string,
+) error {
+	sn := makeView([]byte(structName))
+	defer runtime.KeepAlive(sn)
+
+	errmsg := newUnmanagedVector(nil)
+
+	_, err := C.unregister_txn_arg_struct(vm.ptr, &errmsg, sn)
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return errorWithMessage(err, errmsg)
+	}
+
+	return nil
+}
+
+// SetStorageQuota call ffi(`set_storage_quota`) to limit the number of bytes
+// stored by an account; zero removes the limit
+func SetStorageQuota(
+	vm VM,
+	storageQuota uint64,
+) error {
+	errmsg := newUnmanagedVector(nil)
+
+	_, err := C.set_storage_quota(vm.ptr, &errmsg, cu64(storageQuota))
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return errorWithMessage(err, errmsg)
+	}
+
+	return nil
+}
+
 // PublishModuleBundle call ffi(`publish_module_bundle`) to store module bundle
 func PublishModuleBundle(
 	vm VM,
@@ -102,6 +218,7 @@ func ExecuteContract(
 	gasLimit uint64,
 	sessionID []byte,
 	sender []byte,
+	feePayer []byte,
 	message []byte,
 ) ([]byte, error) {
 	var err error
@@ -117,12 +234,14 @@ func ExecuteContract(
 	defer runtime.KeepAlive(sid)
 	senderView := makeView(sender)
 	defer runtime.KeepAlive(senderView)
+	feePayerView := makeView(feePayer)
+	defer runtime.KeepAlive(feePayerView)
 	msg := makeView(message)
 	defer runtime.KeepAlive(msg)
 
 	errmsg := newUnmanagedVector(nil)
 
-	res, err := C.execute_contract(vm.ptr, db, _api, cbool(verbose), cu64(gasLimit), &errmsg, sid, senderView, msg)
+	res, err := C.execute_contract(vm.ptr, db, _api, cbool(verbose), cu64(gasLimit), &errmsg, sid, senderView, feePayerView, msg)
 	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
 		return nil, errorWithMessage(err, errmsg)
 	}
@@ -140,6 +259,7 @@ func ExecuteScript(
 	gasLimit uint64,
 	sessionID []byte,
 	sender []byte,
+	feePayer []byte,
 	message []byte,
 ) ([]byte, error) {
 	var err error
@@ -155,12 +275,14 @@ func ExecuteScript(
 	defer runtime.KeepAlive(sid)
 	senderView := makeView(sender)
 	defer runtime.KeepAlive(senderView)
+	feePayerView := makeView(feePayer)
+	defer runtime.KeepAlive(feePayerView)
 	msg := makeView(message)
 	defer runtime.KeepAlive(msg)
 
 	errmsg := newUnmanagedVector(nil)
 
-	res, err := C.execute_script(vm.ptr, db, _api, cbool(verbose), cu64(gasLimit), &errmsg, sid, senderView, msg)
+	res, err := C.execute_script(vm.ptr, db, _api, cbool(verbose), cu64(gasLimit), &errmsg, sid, senderView, feePayerView, msg)
 	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
 		return nil, errorWithMessage(err, errmsg)
 	}
@@ -233,6 +355,153 @@ func DecodeMoveResource(
 	return copyAndDestroyUnmanagedVector(res), err
 }
 
+// DecodeTableEntry decode table entry bytes to key and value
+// instances with the table types and return as jSON string.
+// The value is loaded from the store when valueBytes is nil.
+func DecodeTableEntry(
+	store KVStore,
+	tableHandle []byte,
+	keyBytes []byte,
+	valueBytes []byte,
+) ([]byte, error) {
+	var err error
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	tableHandleView := makeView(tableHandle)
+	defer runtime.KeepAlive(tableHandleView)
+
+	keyBytesView := makeView(keyBytes)
+	defer runtime.KeepAlive(keyBytesView)
+
+	valueBytesView := makeView(valueBytes)
+	defer runtime.KeepAlive(valueBytesView)
+
+	errmsg := newUnmanagedVector(nil)
+
+	res, err := C.decode_table_entry(db, &errmsg, tableHandleView, keyBytesView, valueBytesView)
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return nil, errorWithMessage(err, errmsg)
+	}
+
+	return copyAndDestroyUnmanagedVector(res), err
+}
+
+// ScanTable returns a page of table entries as jSON string,
+// starting from startKey (nil for the first page) in the given
+// order (1: Ascending, 2: Descending) with the cursor of the next page
+func ScanTable(
+	store KVStore,
+	tableHandle []byte,
+	startKey []byte,
+	limit uint64,
+	order int32,
+) ([]byte, error) {
+	var err error
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	tableHandleView := makeView(tableHandle)
+	defer runtime.KeepAlive(tableHandleView)
+
+	startKeyView := makeView(startKey)
+	defer runtime.KeepAlive(startKeyView)
+
+	errmsg := newUnmanagedVector(nil)
+
+	res, err := C.scan_table(db, &errmsg, tableHandleView, startKeyView, cu64(limit), ci32(order))
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return nil, errorWithMessage(err, errmsg)
+	}
+
+	return copyAndDestroyUnmanagedVector(res), err
+}
+
+// ListAccountData returns the names of the modules and the decoded
+// resources stored under the address as jSON string
+func ListAccountData(
+	store KVStore,
+	address []byte,
+) ([]byte, error) {
+	var err error
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	addressView := makeView(address)
+	defer runtime.KeepAlive(addressView)
+
+	errmsg := newUnmanagedVector(nil)
+
+	res, err := C.list_account_data(db, &errmsg, addressView)
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return nil, errorWithMessage(err, errmsg)
+	}
+
+	return copyAndDestroyUnmanagedVector(res), err
+}
+
+// ExportGenesisState returns the whole state of the store
+// as jSON string to be imported into a new chain
+func ExportGenesisState(
+	store KVStore,
+) ([]byte, error) {
+	var err error
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	errmsg := newUnmanagedVector(nil)
+
+	res, err := C.export_genesis_state(db, &errmsg)
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return nil, errorWithMessage(err, errmsg)
+	}
+
+	return copyAndDestroyUnmanagedVector(res), err
+}
+
+// ImportGenesisState writes the state exported by
+// ExportGenesisState into the store
+func ImportGenesisState(
+	store KVStore,
+	genesisState []byte,
+) error {
+	var err error
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	genesisStateView := makeView(genesisState)
+	defer runtime.KeepAlive(genesisStateView)
+
+	errmsg := newUnmanagedVector(nil)
+
+	_, err = C.import_genesis_state(db, &errmsg, genesisStateView)
+	if err != nil && err.(syscall.Errno) != C.ErrnoValue_Success {
+		return errorWithMessage(err, errmsg)
+	}
+
+	return err
+}
+
 // DecodeModuleBytes decode module bytes to MoveModule
 // instance and return as jSON string
 func DecodeModuleBytes(
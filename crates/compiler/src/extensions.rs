@@ -1,7 +1,10 @@
 use crate::mocks::{BlankTableViewImpl, MockApi};
 use move_deps::move_unit_test;
 use move_deps::move_vm_runtime::native_extensions::NativeContextExtensions;
-use nova_natives::{block::NativeBlockContext, code::NativeCodeContext, table::NativeTableContext};
+use nova_natives::{
+    bank::NativeBankContext, block::NativeBlockContext, code::NativeCodeContext,
+    table::NativeTableContext, transaction_context::NativeTransactionContext,
+};
 
 static mut BLANK_TABLE_RESOLVER: BlankTableViewImpl = BlankTableViewImpl;
 
@@ -14,8 +17,10 @@ fn unit_test_extensions_hook(exts: &mut NativeContextExtensions) {
     exts.add(NativeTableContext::new([0; 32], unsafe {
         &mut BLANK_TABLE_RESOLVER
     }));
-    exts.add(NativeBlockContext::new(&MockApi {
-        height: 0,
-        timestamp: 0,
-    }));
+
+    // leaked to satisfy the 'static bound of the hook; one per test is negligible
+    let api: &'static MockApi = Box::leak(Box::new(MockApi::default()));
+    exts.add(NativeBlockContext::new(api));
+    exts.add(NativeBankContext::new(api));
+    exts.add(NativeTransactionContext::new([0; 32], vec![], None));
 }
@@ -1,6 +1,8 @@
 use anyhow::Error;
-use nova_natives::{block::BlockInfoResolver, table::TableResolver};
-use nova_types::table::TableHandle;
+use nova_natives::table::TableResolver;
+use nova_types::table::{KeyEncoding, TableHandle};
+
+pub use nova_natives::mocks::MockApi;
 
 /// A dummy storage containing no modules or resources.
 #[derive(Debug, Clone)]
@@ -15,6 +17,10 @@ impl TableResolver for BlankTableViewImpl {
         Ok(None)
     }
 
+    fn resolve_key_encoding(&self, _handle: &TableHandle) -> Result<KeyEncoding, Error> {
+        Ok(KeyEncoding::Bcs)
+    }
+
     fn create_iterator(
         &mut self,
         _handle: &TableHandle,
@@ -28,15 +34,8 @@ impl TableResolver for BlankTableViewImpl {
     fn next_key(&mut self, _iterator_id: u32) -> anyhow::Result<Option<Vec<u8>>> {
         Ok(None)
     }
-}
-
-pub struct MockApi {
-    pub height: u64,
-    pub timestamp: u64,
-}
 
-impl BlockInfoResolver for MockApi {
-    fn get_block_info(&self) -> anyhow::Result<(u64 /* height */, u64 /* timestamp */)> {
-        Ok((self.height, self.timestamp))
+    fn destroy_iterator(&mut self, _iterator_id: u32) -> anyhow::Result<()> {
+        Ok(())
     }
 }
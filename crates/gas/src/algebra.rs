@@ -17,3 +17,8 @@ pub type GasScalingFactor = GasQuantity<UnitDiv<InternalGasUnit, GasUnit>>;
 pub type Fee = GasQuantity<Hexa>;
 
 pub type FeePerGasUnit = GasQuantity<UnitDiv<Hexa, GasUnit>>;
+
+/// Unit of percentage.
+pub enum Percent {}
+
+pub type Percentage = GasQuantity<Percent>;
@@ -0,0 +1,32 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::move_core_types::gas_algebra::InternalGas;
+
+#[derive(Debug, Clone)]
+pub struct WithdrawGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct DepositGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct BalanceGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct SupplyGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub withdraw: WithdrawGasParameters,
+    pub deposit: DepositGasParameters,
+    pub balance: BalanceGasParameters,
+    pub supply: SupplyGasParameters,
+}
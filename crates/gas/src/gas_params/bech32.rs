@@ -0,0 +1,22 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::move_core_types::gas_algebra::{InternalGas, InternalGasPerByte};
+
+#[derive(Debug, Clone)]
+pub struct EncodeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+#[derive(Debug, Clone)]
+pub struct DecodeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub encode: EncodeGasParameters,
+    pub decode: DecodeGasParameters,
+}
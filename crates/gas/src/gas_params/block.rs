@@ -5,7 +5,31 @@ pub struct GetBlockInfoGasParameters {
     pub base_cost: InternalGas,
 }
 
+#[derive(Debug, Clone)]
+pub struct GetChainIdGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetBlockHashGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetProposerGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetTimestampNanosGasParameters {
+    pub base: InternalGas,
+}
+
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub get_block_info: GetBlockInfoGasParameters,
+    pub get_chain_id: GetChainIdGasParameters,
+    pub get_block_hash: GetBlockHashGasParameters,
+    pub get_proposer: GetProposerGasParameters,
+    pub get_timestamp_nanos: GetTimestampNanosGasParameters,
 }
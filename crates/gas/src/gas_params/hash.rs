@@ -0,0 +1,36 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::move_core_types::gas_algebra::{InternalGas, InternalGasPerByte};
+
+#[derive(Debug, Clone)]
+pub struct Keccak256GasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+#[derive(Debug, Clone)]
+pub struct Sha2_512GasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+#[derive(Debug, Clone)]
+pub struct Ripemd160GasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+#[derive(Debug, Clone)]
+pub struct Blake2b256GasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub keccak256: Keccak256GasParameters,
+    pub sha2_512: Sha2_512GasParameters,
+    pub ripemd160: Ripemd160GasParameters,
+    pub blake2b_256: Blake2b256GasParameters,
+}
@@ -1,8 +1,14 @@
 pub mod account;
+pub mod bank;
+pub mod bech32;
 pub mod block;
 pub mod code;
 pub mod event;
+pub mod hash;
+pub mod object;
+pub mod randomness;
 pub mod table;
+pub mod transaction_context;
 pub mod type_info;
 pub mod util;
 
@@ -0,0 +1,15 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::move_core_types::gas_algebra::{InternalGas, InternalGasPerByte};
+
+#[derive(Debug, Clone)]
+pub struct ExistsAtGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub exists_at: ExistsAtGasParameters,
+}
@@ -0,0 +1,14 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::move_core_types::gas_algebra::InternalGas;
+
+#[derive(Debug, Clone)]
+pub struct NextSeedGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub next_seed: NextSeedGasParameters,
+}
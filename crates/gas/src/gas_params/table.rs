@@ -89,3 +89,8 @@ impl PrepareBoxGasParameters {
 pub struct NextBoxGasParameters {
     pub base: InternalGas,
 }
+
+#[derive(Debug, Clone)]
+pub struct DestroyTableIterGasParameters {
+    pub base: InternalGas,
+}
@@ -0,0 +1,33 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::move_core_types::gas_algebra::{InternalGas, InternalGasPerArg};
+
+#[derive(Debug, Clone)]
+pub struct GetTxnHashGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetSendersGasParameters {
+    pub base: InternalGas,
+    pub per_sender: InternalGasPerArg,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenerateUniqueAddressGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetRemainingGasGasParameters {
+    pub base: InternalGas,
+}
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub get_txn_hash: GetTxnHashGasParameters,
+    pub get_senders: GetSendersGasParameters,
+    pub generate_unique_address: GenerateUniqueAddressGasParameters,
+    pub get_remaining_gas: GetRemainingGasGasParameters,
+}
@@ -15,8 +15,14 @@ pub struct SetBlockInfoForTestingGasParameters {
     pub base_cost: InternalGas,
 }
 
+#[derive(Debug, Clone)]
+pub struct SetBlockContextForTestingGasParameters {
+    pub base: InternalGas,
+}
+
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub create_signers_for_testing: CreateSignersForTestingGasParameters,
     pub set_block_info_for_testing: SetBlockInfoForTestingGasParameters,
+    pub set_block_context_for_testing: SetBlockContextForTestingGasParameters,
 }
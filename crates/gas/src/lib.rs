@@ -34,7 +34,7 @@ mod transaction;
 pub use algebra::*;
 pub use instr::InstructionGasParameters;
 pub use meter::{
-    FromOnChainGasSchedule, InitialGasSchedule, NativeGasParameters, NovaGasMeter,
+    FromOnChainGasSchedule, GasBalance, InitialGasSchedule, NativeGasParameters, NovaGasMeter,
     NovaGasParameters, ToOnChainGasSchedule,
 };
 pub use misc::{AbstractValueSizeGasParameters, MiscGasParameters};
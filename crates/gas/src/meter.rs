@@ -9,6 +9,7 @@ use crate::{
 use move_deps::move_binary_format::errors::{Location, PartialVMError, PartialVMResult, VMResult};
 use move_deps::move_core_types::effects::Op;
 use move_deps::move_core_types::{
+    account_address::AccountAddress,
     gas_algebra::{InternalGas, NumArgs, NumBytes},
     language_storage::ModuleId,
     vm_status::StatusCode,
@@ -18,8 +19,10 @@ use move_deps::move_vm_types::{
     gas::{GasMeter, SimpleInstruction},
     views::{TypeView, ValueView},
 };
-use nova_types::access_path::AccessPath;
+use nova_types::{access_path::AccessPath, storage_usage::StorageDeposit};
+use std::cell::Cell;
 use std::collections::BTreeMap;
+use std::rc::Rc;
 
 pub(crate) const EXECUTION_GAS_MULTIPLIER: u64 = 20;
 
@@ -152,16 +155,43 @@ pub struct NovaGasMeter {
     gas_params: NovaGasParameters,
     balance: InternalGas,
     gas_limit: InternalGas,
+
+    // mirror of `balance` which can be observed through `GasBalance`
+    shared_balance: Rc<Cell<u64>>,
+}
+
+/// A read-only handle to the remaining balance of a `NovaGasMeter`.
+/// It allows native functions to observe the remaining gas without access to the meter.
+#[derive(Debug, Clone)]
+pub struct GasBalance {
+    balance: Rc<Cell<u64>>,
+    txn_params: TransactionGasParameters,
+}
+
+impl GasBalance {
+    pub fn remaining(&self) -> Gas {
+        InternalGas::new(self.balance.get())
+            .to_unit_round_down_with_params(&self.txn_params)
+    }
 }
 
 impl NovaGasMeter {
     pub fn new(gas_params: NovaGasParameters, balance: impl Into<Gas>) -> Self {
         let balance = balance.into().to_unit_with_params(&gas_params.txn);
         let gas_limit = balance.clone();
+        let shared_balance = Rc::new(Cell::new(balance.into()));
         Self {
             gas_params,
             balance,
             gas_limit,
+            shared_balance,
+        }
+    }
+
+    pub fn gas_balance(&self) -> GasBalance {
+        GasBalance {
+            balance: self.shared_balance.clone(),
+            txn_params: self.gas_params.txn.clone(),
         }
     }
 
@@ -179,10 +209,12 @@ impl NovaGasMeter {
         match self.balance.checked_sub(amount) {
             Some(new_balance) => {
                 self.balance = new_balance;
+                self.shared_balance.set(new_balance.into());
                 Ok(())
             }
             None => {
                 self.balance = 0.into();
+                self.shared_balance.set(0);
                 Err(PartialVMError::new(StatusCode::OUT_OF_GAS))
             }
         }
@@ -490,4 +522,34 @@ impl NovaGasMeter {
         let cost = self.gas_params.txn.calculate_write_set_gas(ops);
         self.charge(cost).map_err(|e| e.finish(Location::Undefined))
     }
+
+    /// Charge the storage deposit for the bytes added by the message.
+    pub fn charge_storage_deposit(&mut self, storage_deposit: &StorageDeposit) -> VMResult<()> {
+        self.charge(InternalGas::new(storage_deposit.charge))
+            .map_err(|e| e.finish(Location::Undefined))
+    }
+
+    /// Convert the storage deposit charged and the refunds of the accounts to gas units.
+    pub fn storage_fee(
+        &self,
+        storage_deposit: &StorageDeposit,
+    ) -> (u64, BTreeMap<AccountAddress, u64>) {
+        let txn_params = &self.gas_params.txn;
+        let charge: u64 = InternalGas::new(storage_deposit.charge)
+            .to_unit_round_up_with_params(txn_params)
+            .into();
+        let refunds = storage_deposit
+            .refunds
+            .iter()
+            .map(|(addr, refund)| {
+                let refund: u64 = InternalGas::new(*refund)
+                    .to_unit_round_down_with_params(txn_params)
+                    .into();
+                (*addr, refund)
+            })
+            .filter(|(_, refund)| *refund != 0)
+            .collect();
+
+        (charge, refunds)
+    }
 }
@@ -5,6 +5,10 @@ crate::natives::define_gas_parameters_for_natives!(GasParameters, "nova", [
     [.account.create_signer.base_cost, "account.create_signer.base", 300 * MUL],
 
     [.block.get_block_info.base_cost, "block.get_block_info.base", 500 * MUL],
+    [.block.get_chain_id.base, "block.get_chain_id.base", 500 * MUL],
+    [.block.get_block_hash.base, "block.get_block_hash.base", 500 * MUL],
+    [.block.get_proposer.base, "block.get_proposer.base", 500 * MUL],
+    [.block.get_timestamp_nanos.base, "block.get_timestamp_nanos.base", 500 * MUL],
 
     [.type_info.type_of.base, "type_info.type_of.base", 300 * MUL],
     [.type_info.type_of.unit, "type_info.type_of.unit", 5 * MUL],
@@ -23,12 +27,43 @@ crate::natives::define_gas_parameters_for_natives!(GasParameters, "nova", [
     // TODO(Gas): the on-chain name is wrong...
     [.event.write_to_event_store.per_abstract_value_unit, "event.write_to_event_store.per_abstract_memory_unit", 5_000],
 
+    [.hash.keccak256.base, "hash.keccak256.base", 3000 * MUL],
+    [.hash.keccak256.per_byte, "hash.keccak256.per_byte", 50 * MUL],
+    [.hash.sha2_512.base, "hash.sha2_512.base", 3000 * MUL],
+    [.hash.sha2_512.per_byte, "hash.sha2_512.per_byte", 50 * MUL],
+    [.hash.ripemd160.base, "hash.ripemd160.base", 3000 * MUL],
+    [.hash.ripemd160.per_byte, "hash.ripemd160.per_byte", 50 * MUL],
+    [.hash.blake2b_256.base, "hash.blake2b_256.base", 3000 * MUL],
+    [.hash.blake2b_256.per_byte, "hash.blake2b_256.per_byte", 50 * MUL],
+
+    [.bech32.encode.base, "bech32.encode.base", 500 * MUL],
+    [.bech32.encode.per_byte, "bech32.encode.per_byte", 10 * MUL],
+    [.bech32.decode.base, "bech32.decode.base", 500 * MUL],
+    [.bech32.decode.per_byte, "bech32.decode.per_byte", 10 * MUL],
+
+    [.transaction_context.get_txn_hash.base, "transaction_context.get_txn_hash.base", 200 * MUL],
+    [.transaction_context.get_senders.base, "transaction_context.get_senders.base", 200 * MUL],
+    [.transaction_context.get_senders.per_sender, "transaction_context.get_senders.per_sender", 20 * MUL],
+    [.transaction_context.generate_unique_address.base, "transaction_context.generate_unique_address.base", 500 * MUL],
+    [.transaction_context.get_remaining_gas.base, "transaction_context.get_remaining_gas.base", 200 * MUL],
+
+    [.randomness.next_seed.base, "randomness.next_seed.base", 3000 * MUL],
+
+    [.bank.withdraw.base, "bank.withdraw.base", 1000 * MUL],
+    [.bank.deposit.base, "bank.deposit.base", 1000 * MUL],
+    [.bank.balance.base, "bank.balance.base", 500 * MUL],
+    [.bank.supply.base, "bank.supply.base", 500 * MUL],
+
+    [.object.exists_at.base, "object.exists_at.base", 500 * MUL],
+    [.object.exists_at.per_byte, "object.exists_at.per_byte", 1 * MUL],
+
 
     // TODO(Gas): these should only be enabled when feature "testing" is present
     // TODO(Gas): rename these in the move repo
     [test_only .unit_test.create_signers_for_testing.base_cost, "unit_test.create_signers_for_testing.base", 1],
     [test_only .unit_test.create_signers_for_testing.unit_cost, "unit_test.create_signers_for_testing.unit", 1],
-    [test_only .unit_test.set_block_info_for_testing.base_cost, "unit_test.set_block_info_for_testing.base", 1]
+    [test_only .unit_test.set_block_info_for_testing.base_cost, "unit_test.set_block_info_for_testing.base", 1],
+    [test_only .unit_test.set_block_context_for_testing.base, "unit_test.set_block_context_for_testing.base", 1]
 ]);
 
 use crate::gas_params::*;
@@ -41,6 +76,12 @@ pub struct GasParameters {
     pub util: util::GasParameters,
     pub code: code::GasParameters,
     pub event: event::GasParameters,
+    pub hash: hash::GasParameters,
+    pub bech32: bech32::GasParameters,
+    pub transaction_context: transaction_context::GasParameters,
+    pub randomness: randomness::GasParameters,
+    pub bank: bank::GasParameters,
+    pub object: object::GasParameters,
     pub unit_test: unit_test::GasParameters,
 }
 
@@ -59,6 +100,10 @@ impl GasParameters {
                 get_block_info: block::GetBlockInfoGasParameters {
                     base_cost: 0.into(),
                 },
+                get_chain_id: block::GetChainIdGasParameters { base: 0.into() },
+                get_block_hash: block::GetBlockHashGasParameters { base: 0.into() },
+                get_proposer: block::GetProposerGasParameters { base: 0.into() },
+                get_timestamp_nanos: block::GetTimestampNanosGasParameters { base: 0.into() },
             },
             type_info: type_info::GasParameters {
                 type_of: type_info::TypeOfGasParameters {
@@ -88,6 +133,62 @@ impl GasParameters {
                     per_abstract_value_unit: 0.into(),
                 },
             },
+            hash: hash::GasParameters {
+                keccak256: hash::Keccak256GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                sha2_512: hash::Sha2_512GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                ripemd160: hash::Ripemd160GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                blake2b_256: hash::Blake2b256GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            bech32: bech32::GasParameters {
+                encode: bech32::EncodeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                decode: bech32::DecodeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            transaction_context: transaction_context::GasParameters {
+                get_txn_hash: transaction_context::GetTxnHashGasParameters { base: 0.into() },
+                get_senders: transaction_context::GetSendersGasParameters {
+                    base: 0.into(),
+                    per_sender: 0.into(),
+                },
+                generate_unique_address: transaction_context::GenerateUniqueAddressGasParameters {
+                    base: 0.into(),
+                },
+                get_remaining_gas: transaction_context::GetRemainingGasGasParameters {
+                    base: 0.into(),
+                },
+            },
+            randomness: randomness::GasParameters {
+                next_seed: randomness::NextSeedGasParameters { base: 0.into() },
+            },
+            bank: bank::GasParameters {
+                withdraw: bank::WithdrawGasParameters { base: 0.into() },
+                deposit: bank::DepositGasParameters { base: 0.into() },
+                balance: bank::BalanceGasParameters { base: 0.into() },
+                supply: bank::SupplyGasParameters { base: 0.into() },
+            },
+            object: object::GasParameters {
+                exists_at: object::ExistsAtGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
             unit_test: unit_test::GasParameters {
                 create_signers_for_testing: unit_test::CreateSignersForTestingGasParameters {
                     base_cost: 0.into(),
@@ -96,6 +197,9 @@ impl GasParameters {
                 set_block_info_for_testing: unit_test::SetBlockInfoForTestingGasParameters {
                     base_cost: 0.into(),
                 },
+                set_block_context_for_testing: unit_test::SetBlockContextForTestingGasParameters {
+                    base: 0.into(),
+                },
             },
         }
     }
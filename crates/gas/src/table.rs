@@ -34,6 +34,8 @@ crate::natives::define_gas_parameters_for_natives!(GasParameters, "table", [
     [.prepare_box.per_byte_serialized, "prepare_box.per_byte_serialized", 10 * MUL],
 
     [.next_box.base, "next_box.base", 1000 * MUL],
+
+    [.destroy_table_iter.base, optional "destroy_table_iter.base", 100 * MUL],
 ]);
 
 #[derive(Debug, Clone)]
@@ -50,6 +52,7 @@ pub struct GasParameters {
     pub new_table_iter: NewTableIteratorGasParameters,
     pub prepare_box: PrepareBoxGasParameters,
     pub next_box: NextBoxGasParameters,
+    pub destroy_table_iter: DestroyTableIterGasParameters,
 }
 
 impl GasParameters {
@@ -89,6 +92,7 @@ impl GasParameters {
                 per_byte_serialized: 0.into(),
             },
             next_box: NextBoxGasParameters { base: 0.into() },
+            destroy_table_iter: DestroyTableIterGasParameters { base: 0.into() },
         }
     }
 }
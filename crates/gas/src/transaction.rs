@@ -1,7 +1,7 @@
 //! This module defines all the gas parameters for transactions, along with their initial values
 //! in the genesis and a mapping between the Rust representation and the on-chain gas schedule.
 
-use crate::algebra::{GasScalingFactor, GasUnit};
+use crate::algebra::{GasScalingFactor, GasUnit, Percentage};
 // use aptos_types::{state_store::state_key::StateKey, write_set::WriteOp};
 use move_deps::move_core_types::{
     effects::Op,
@@ -10,7 +10,7 @@ use move_deps::move_core_types::{
         ToUnitFractionalWithParams, ToUnitWithParams,
     },
 };
-use nova_types::access_path::AccessPath;
+use nova_types::{access_path::AccessPath, storage_usage::StorageDepositPrice};
 
 crate::params::define_gas_parameters!(
     TransactionGasParameters,
@@ -72,6 +72,18 @@ crate::params::define_gas_parameters!(
             "write_data.per_byte_in_val",
             30 * 10_000 // sdk.WriteCostPerByte = 3
         ],
+        // The storage deposit charged per byte added to the storage of an account.
+        [
+            storage_fee_per_byte: InternalGasPerByte,
+            optional "storage_fee.per_byte",
+            10 * 10_000 // 10 SDK gas per byte
+        ],
+        // The percentage of the storage deposit refunded per byte removed from storage.
+        [
+            storage_fee_refund_percentage: Percentage,
+            optional "storage_fee.refund_percentage",
+            50
+        ],
     ]
 );
 
@@ -140,6 +152,14 @@ impl TransactionGasParameters {
 
         cost_ops + cost_bytes
     }
+
+    /// The price of the storage deposit held by the accounts for their bytes.
+    pub fn storage_deposit_price(&self) -> StorageDepositPrice {
+        StorageDepositPrice {
+            per_byte: self.storage_fee_per_byte.into(),
+            refund_percentage: self.storage_fee_refund_percentage.into(),
+        }
+    }
 }
 
 impl ToUnitWithParams<InternalGasUnit> for GasUnit {
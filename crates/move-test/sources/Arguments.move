@@ -0,0 +1,47 @@
+/// This module provides entry functions taking struct arguments, for use in argument tests
+module TestAccount::Arguments {
+    use std::ascii;
+    use std::option::{Self, Option};
+    use std::string::{Self, String};
+    use std::vector;
+
+    const EOUT_OF_RANGE: u64 = 1;
+
+    /// A point of the grid, registered as a transaction argument by the tests
+    struct Point has copy, drop {
+        x: u64,
+        y: u64,
+    }
+
+    public fun new_point(x: u64, y: u64): Point {
+        assert!(x <= 100 && y <= 100, EOUT_OF_RANGE);
+        Point { x, y }
+    }
+
+    public entry fun ascii_length(s: ascii::String): u64 {
+        ascii::length(&s)
+    }
+
+    public entry fun utf8_length(s: String): u64 {
+        string::length(&s)
+    }
+
+    public entry fun sum_points(points: vector<Point>): u64 {
+        let sum = 0;
+        let i = 0;
+        while (i < vector::length(&points)) {
+            let point = vector::borrow(&points, i);
+            sum = sum + point.x + point.y;
+            i = i + 1;
+        };
+        sum
+    }
+
+    public entry fun sum_optional_point(point: Option<Point>): u64 {
+        if (option::is_none(&point)) {
+            return 0
+        };
+        let point = option::destroy_some(point);
+        point.x + point.y
+    }
+}
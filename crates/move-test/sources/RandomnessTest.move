@@ -0,0 +1,42 @@
+/// Modules opted in to the randomness check, for the verifier tests
+module TestAccount::RandomnessEntry {
+    use std::signer;
+    use nova_std::randomness;
+
+    struct RandomnessEntryOnly {}
+
+    struct Roll has key {
+        value: u64,
+    }
+
+    public entry fun roll(s: signer) acquires Roll {
+        let value = roll_dice();
+        if (exists<Roll>(signer::address_of(&s))) {
+            borrow_global_mut<Roll>(signer::address_of(&s)).value = value;
+        } else {
+            move_to(&s, Roll { value });
+        }
+    }
+
+    entry fun roll_private(s: signer) acquires Roll {
+        roll(s)
+    }
+
+    fun roll_dice(): u64 {
+        randomness::u64_range(1, 7)
+    }
+}
+
+module TestAccount::RandomnessPublic {
+    use nova_std::randomness;
+
+    struct RandomnessEntryOnly {}
+
+    public fun roll(): u64 {
+        roll_dice()
+    }
+
+    fun roll_dice(): u64 {
+        randomness::u64_range(1, 7)
+    }
+}
@@ -140,6 +140,17 @@ module TestAccount::TableTestData {
         move_to(&s, S { t });
     }
 
+    public entry fun prepare_ordered_table_for_iterator(s: signer) {
+        let t = T::new_with_ordered_keys<u64, u64>(&s);
+
+        T::add(&mut t, 1, 1);
+        T::add(&mut t, 2, 2);
+        T::add(&mut t, 256, 256);
+        T::add(&mut t, 65536, 65536);
+
+        move_to(&s, S { t });
+    }
+
     public entry fun iterate_ascending(acc: address) acquires S {
         let t_ref = &borrow_global<S<u64, u64>>(acc).t;
 
@@ -155,6 +166,7 @@ module TestAccount::TableTestData {
             i = i + 1;
         };
         assert!(!T::prepare<u64, u64>(&mut iter), 101);
+        T::destroy(iter);
 
         let iter = T::iter(t_ref, option::some(2), option::some(5), 1);
         
@@ -168,6 +180,7 @@ module TestAccount::TableTestData {
             i = i + 1;
         };
         assert!(!T::prepare<u64, u64>(&mut iter), 102);
+        T::destroy(iter);
     }
 
     public entry fun iterate_descending(acc: address) acquires S {
@@ -185,6 +198,7 @@ module TestAccount::TableTestData {
             i = i - 1;
         };
         assert!(!T::prepare<u64, u64>(&mut iter), 101);
+        T::destroy(iter);
 
         let iter = T::iter(t_ref, option::some(2), option::some(5), 2);
         
@@ -198,5 +212,6 @@ module TestAccount::TableTestData {
             i = i - 1;
         };
         assert!(!T::prepare<u64, u64>(&mut iter), 102);
+        T::destroy(iter);
     }
 }
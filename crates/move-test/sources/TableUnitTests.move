@@ -297,6 +297,7 @@ module TestAccount::TableUnitTests {
         assert!(value == &4, 104);
 
         assert!(!T::prepare<u64, u64>(&mut iter), 105);
+        T::destroy(iter);
 
         // clear
         T::remove(&mut t, 1);
@@ -306,4 +307,109 @@ module TestAccount::TableUnitTests {
         T::remove(&mut t, 5);
         T::drop_unchecked(t)
     }
+
+    #[test(s = @0x42)]
+    fun test_iter_range_ordered_keys(s: signer) {
+        let t = T::new_with_ordered_keys<u64, u64>(&s);
+        T::add(&mut t, 256, 256);
+        T::add(&mut t, 2, 2);
+        T::add(&mut t, 1, 1);
+        T::add(&mut t, 65536, 65536);
+
+        // ascending from 2, in numeric order rather than BCS byte order
+        let iter = T::iter_range(&t, option::some(2), option::none(), T::order_ascending());
+        assert!(T::prepare<u64, u64>(&mut iter), 101);
+        let (key, value) = T::next<u64, u64>(&mut iter);
+        assert!(key == 2 && value == &2, 101);
+
+        assert!(T::prepare<u64, u64>(&mut iter), 102);
+        let (key, _) = T::next<u64, u64>(&mut iter);
+        assert!(key == 256, 102);
+
+        assert!(T::prepare<u64, u64>(&mut iter), 103);
+        let (key, _) = T::next<u64, u64>(&mut iter);
+        assert!(key == 65536, 103);
+
+        assert!(!T::prepare<u64, u64>(&mut iter), 104);
+        T::destroy(iter);
+
+        // descending below 65536
+        let iter = T::iter_range(&t, option::none(), option::some(65536), T::order_descending());
+        let keys = vector::empty<u64>();
+        while (T::prepare<u64, u64>(&mut iter)) {
+            let (key, _) = T::next<u64, u64>(&mut iter);
+            vector::push_back(&mut keys, key);
+        };
+        T::destroy(iter);
+        assert!(vector::length(&keys) == 3, 105);
+        assert!(*vector::borrow(&keys, 0) == 256, 105);
+        assert!(*vector::borrow(&keys, 1) == 2, 105);
+        assert!(*vector::borrow(&keys, 2) == 1, 105);
+
+        T::drop_unchecked(t)
+    }
+
+    #[test(s = @0x42)]
+    #[expected_failure(abort_code = 26375)]
+    fun test_ordered_keys_unsupported_type(s: signer) {
+        let t = T::new_with_ordered_keys<option::Option<u64>, u64>(&s);
+        T::drop_unchecked(t)
+    }
+
+    #[test(s = @0x42)]
+    #[expected_failure(abort_code = 65640)]
+    fun test_iter_range_invalid_order(s: signer) {
+        let t = T::new<u64, u64>(&s);
+        let iter = T::iter_range(&t, option::none(), option::none(), 3);
+        T::destroy(iter);
+        T::drop_unchecked(t)
+    }
+
+    #[test(s = @0x42)]
+    fun test_destroy_iterator(s: signer) {
+        let t = T::new<u64, u64>(&s);
+        T::add(&mut t, 1, 1);
+
+        // destroyed iterators do not count toward the open iterator limit
+        let i = 0;
+        while (i < 1100) {
+            let iter = T::iter(&t, option::none(), option::none(), 1);
+            assert!(T::prepare<u64, u64>(&mut iter), 101);
+            T::destroy(iter);
+            i = i + 1;
+        };
+
+        T::drop_unchecked(t)
+    }
+
+    #[test(s = @0x42)]
+    #[expected_failure(abort_code = 27143)]
+    fun test_destroy_unknown_iterator(s: signer) {
+        let t = T::new<u64, u64>(&s);
+        let iter = T::iter(&t, option::none(), option::none(), 1);
+        T::destroy(iter);
+
+        // the id of the destroyed iterator is not open anymore
+        T::destroy(T::unchecked_iter(0));
+        T::drop_unchecked(t)
+    }
+
+    #[test(s = @0x42)]
+    #[expected_failure(abort_code = 26889)]
+    fun test_too_many_open_iterators(s: signer) {
+        let t = T::new<u64, u64>(&s);
+
+        let iters = vector::empty<T::TableIter>();
+        let i = 0;
+        while (i <= 1024) {
+            vector::push_back(&mut iters, T::iter(&t, option::none(), option::none(), 1));
+            i = i + 1;
+        };
+
+        while (!vector::is_empty(&iters)) {
+            T::destroy(vector::pop_back(&mut iters));
+        };
+        vector::destroy_empty(iters);
+        T::drop_unchecked(t)
+    }
 }
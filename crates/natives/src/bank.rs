@@ -0,0 +1,272 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use better_any::{Tid, TidAble};
+use move_deps::{
+    move_binary_format::errors::{PartialVMError, PartialVMResult},
+    move_core_types::{account_address::AccountAddress, vm_status::StatusCode},
+    move_vm_runtime::native_functions::{NativeContext, NativeFunction},
+    move_vm_types::{
+        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+    },
+};
+use nova_gas::gas_params::bank::*;
+use nova_types::bank_change_set::{BankChangeSet, BankOp};
+use smallvec::smallvec;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Arc;
+
+use anyhow::Result;
+
+/// Callbacks to the chain's bank module, used by the coins which are
+/// mapped to a bank denom. The bank keeps the only ledger of those coins;
+/// a `Coin` value in flight is backed by the funds held in the bridge escrow.
+///
+/// The natives only read the bank. Withdrawals and deposits are buffered in
+/// the `BankChangeSet` of the message, which the chain applies through
+/// `bank_withdraw` and `bank_deposit` only when the message succeeds.
+/// This is a trait to allow Mocks in the test code.
+pub trait BankBridge {
+    /// Move `amount` of `denom` from the account's bank balance into the bridge escrow.
+    fn bank_withdraw(&self, denom: &str, addr: &AccountAddress, amount: u64) -> Result<()>;
+    /// Move `amount` of `denom` from the bridge escrow into the account's bank balance.
+    fn bank_deposit(&self, denom: &str, addr: &AccountAddress, amount: u64) -> Result<()>;
+    fn bank_balance(&self, denom: &str, addr: &AccountAddress) -> Result<u64>;
+    fn bank_supply(&self, denom: &str) -> Result<u64>;
+}
+
+/// The native bank context.
+#[derive(Tid)]
+pub struct NativeBankContext<'a> {
+    api: &'a dyn BankBridge,
+    ops: Vec<BankOp>,
+    /// Balance changes of the buffered `ops`, on top of the bank balances.
+    balance_changes: BTreeMap<(String, AccountAddress), i128>,
+}
+
+impl<'a> NativeBankContext<'a> {
+    pub fn new(api: &'a dyn BankBridge) -> Self {
+        Self {
+            api,
+            ops: vec![],
+            balance_changes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the bank operations buffered during the session.
+    pub fn into_change_set(self) -> BankChangeSet {
+        BankChangeSet::new(self.ops)
+    }
+
+    /// Returns the bank balance including the buffered operations.
+    fn balance(&self, denom: &str, addr: &AccountAddress) -> Result<u64> {
+        let balance = self.api.bank_balance(denom, addr)? as i128;
+        let change = self
+            .balance_changes
+            .get(&(denom.to_string(), *addr))
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(u64::try_from(balance + change)?)
+    }
+
+    fn record(&mut self, op: BankOp) {
+        let (denom, address, change) = match &op {
+            BankOp::Withdraw {
+                denom,
+                address,
+                amount,
+            } => (denom, address, -(*amount as i128)),
+            BankOp::Deposit {
+                denom,
+                address,
+                amount,
+            } => (denom, address, *amount as i128),
+        };
+
+        *self
+            .balance_changes
+            .entry((denom.clone(), *address))
+            .or_default() += change;
+        self.ops.push(op);
+    }
+}
+
+fn parse_denom(denom: Vec<u8>) -> PartialVMResult<String> {
+    String::from_utf8(denom).map_err(|_| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+            .with_message("denom is not a valid utf8 string".to_string())
+    })
+}
+
+/***************************************************************************************************
+ * native fun withdraw
+ *
+ *   gas cost: base_cost
+ *
+ *   the withdrawal is buffered and fails when the balance, including
+ *   the buffered operations, is insufficient; zero amounts are not buffered
+ *
+ **************************************************************************************************/
+fn native_withdraw(
+    gas_params: &WithdrawGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let amount = pop_arg!(args, u64);
+    let addr = pop_arg!(args, AccountAddress);
+    let denom = parse_denom(pop_arg!(args, Vec<u8>))?;
+    if amount == 0 {
+        return Ok(NativeResult::ok(gas_params.base, smallvec![]));
+    }
+
+    let bank_context = context.extensions_mut().get_mut::<NativeBankContext>();
+    match bank_context.balance(&denom, &addr) {
+        Ok(balance) if balance >= amount => {}
+        _ => {
+            return Ok(NativeResult::err(
+                gas_params.base,
+                super::status::NFE_BANK_OPERATION_FAILED,
+            ));
+        }
+    }
+
+    bank_context.record(BankOp::Withdraw {
+        denom,
+        address: addr,
+        amount,
+    });
+
+    Ok(NativeResult::ok(gas_params.base, smallvec![]))
+}
+
+pub fn make_native_withdraw(gas_params: WithdrawGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_withdraw(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun deposit
+ *
+ *   gas cost: base_cost
+ *
+ *   the deposit is buffered until the message succeeds; zero amounts
+ *   are not buffered
+ *
+ **************************************************************************************************/
+fn native_deposit(
+    gas_params: &DepositGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let amount = pop_arg!(args, u64);
+    let addr = pop_arg!(args, AccountAddress);
+    let denom = parse_denom(pop_arg!(args, Vec<u8>))?;
+    if amount == 0 {
+        return Ok(NativeResult::ok(gas_params.base, smallvec![]));
+    }
+
+    let bank_context = context.extensions_mut().get_mut::<NativeBankContext>();
+    bank_context.record(BankOp::Deposit {
+        denom,
+        address: addr,
+        amount,
+    });
+
+    Ok(NativeResult::ok(gas_params.base, smallvec![]))
+}
+
+pub fn make_native_deposit(gas_params: DepositGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_deposit(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun balance
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_balance(
+    gas_params: &BalanceGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let addr = pop_arg!(args, AccountAddress);
+    let denom = parse_denom(pop_arg!(args, Vec<u8>))?;
+
+    let bank_context = context.extensions().get::<NativeBankContext>();
+    let balance = bank_context
+        .balance(&denom, &addr)
+        .map_err(|_| PartialVMError::new(StatusCode::LOOKUP_FAILED))?;
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(balance)],
+    ))
+}
+
+pub fn make_native_balance(gas_params: BalanceGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_balance(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun supply
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_supply(
+    gas_params: &SupplyGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let denom = parse_denom(pop_arg!(args, Vec<u8>))?;
+
+    let bank_context = context.extensions().get::<NativeBankContext>();
+    let supply = bank_context
+        .api
+        .bank_supply(&denom)
+        .map_err(|_| PartialVMError::new(StatusCode::LOOKUP_FAILED))?;
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(supply)],
+    ))
+}
+
+pub fn make_native_supply(gas_params: SupplyGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_supply(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "withdraw_internal",
+            make_native_withdraw(gas_params.withdraw),
+        ),
+        ("deposit_internal", make_native_deposit(gas_params.deposit)),
+        ("balance_internal", make_native_balance(gas_params.balance)),
+        ("supply_internal", make_native_supply(gas_params.supply)),
+    ];
+
+    crate::helpers::make_module_natives(natives)
+}
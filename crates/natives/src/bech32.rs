@@ -0,0 +1,113 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::{
+    move_binary_format::errors::PartialVMResult,
+    move_core_types::{account_address::AccountAddress, gas_algebra::NumBytes},
+    move_vm_runtime::native_functions::{NativeContext, NativeFunction},
+    move_vm_types::{
+        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+    },
+};
+use nova_gas::gas_params::bech32::*;
+use nova_types::bech32::{decode_address, encode_address};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/***************************************************************************************************
+ * native fun encode
+ *
+ *   gas cost: base_cost + per_byte * hrp_len
+ *
+ **************************************************************************************************/
+fn native_encode(
+    gas_params: &EncodeGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let addr = pop_arg!(args, AccountAddress);
+    let hrp = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(hrp.len() as u64);
+
+    let encoded = match String::from_utf8(hrp)
+        .ok()
+        .and_then(|hrp| encode_address(&hrp, &addr).ok())
+    {
+        Some(encoded) => encoded,
+        None => {
+            return Ok(NativeResult::err(
+                cost,
+                super::status::NFE_UNABLE_TO_ENCODE_BECH32,
+            ))
+        }
+    };
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(encoded.into_bytes())],
+    ))
+}
+
+pub fn make_native_encode(gas_params: EncodeGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_encode(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun decode
+ *
+ *   gas cost: base_cost + per_byte * bech32_addr_len
+ *
+ **************************************************************************************************/
+fn native_decode(
+    gas_params: &DecodeGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bech32_addr = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bech32_addr.len() as u64);
+
+    let (hrp, addr) = match String::from_utf8(bech32_addr)
+        .ok()
+        .and_then(|bech32_addr| decode_address(&bech32_addr).ok())
+    {
+        Some(decoded) => decoded,
+        None => {
+            return Ok(NativeResult::err(
+                cost,
+                super::status::NFE_UNABLE_TO_DECODE_BECH32,
+            ))
+        }
+    };
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(hrp.into_bytes()), Value::address(addr)],
+    ))
+}
+
+pub fn make_native_decode(gas_params: DecodeGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_decode(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("encode_internal", make_native_encode(gas_params.encode)),
+        ("decode_internal", make_native_decode(gas_params.decode)),
+    ];
+
+    crate::helpers::make_module_natives(natives)
+}
@@ -1,10 +1,12 @@
 use better_any::{Tid, TidAble};
 use move_deps::{
     move_binary_format::errors::{PartialVMError, PartialVMResult},
-    move_core_types::vm_status::StatusCode,
+    move_core_types::{account_address::AccountAddress, vm_status::StatusCode},
     move_vm_runtime::native_functions::{NativeContext, NativeFunction},
     move_vm_types::{
-        loaded_data::runtime_types::Type, natives::function::NativeResult, values::Value,
+        loaded_data::runtime_types::Type,
+        natives::function::NativeResult,
+        values::{Struct, Value},
     },
 };
 use nova_gas::gas_params::block::*;
@@ -14,18 +16,22 @@ use std::sync::Arc;
 
 use anyhow::Result;
 
+/// Chain level information of the block which is currently executed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockContext {
+    pub chain_id: String,
+    pub block_hash: Vec<u8>,
+    pub proposer: AccountAddress,
+    pub timestamp_nanos: u64,
+}
+
 /// Callbacks to system functions defined outside of the move modules.
 /// This is a trait to allow Mocks in the test code.
 pub trait BlockInfoResolver {
     fn get_block_info(&self) -> Result<(u64 /* height */, u64 /* timestamp */)>;
+    fn get_block_context(&self) -> Result<BlockContext>;
 }
 
-/***************************************************************************************************
- * native fun create_address
- *
- *   gas cost: base_cost
- *
- **************************************************************************************************/
 /// The native code context.
 #[derive(Tid)]
 pub struct NativeBlockContext<'a> {
@@ -35,6 +41,8 @@ pub struct NativeBlockContext<'a> {
     height: u64,
     #[cfg(feature = "testing")]
     timestamp: u64,
+    #[cfg(feature = "testing")]
+    block_context: Option<BlockContext>,
 }
 
 impl<'a> NativeBlockContext<'a> {
@@ -46,6 +54,8 @@ impl<'a> NativeBlockContext<'a> {
             height: 0,
             #[cfg(feature = "testing")]
             timestamp: 0,
+            #[cfg(feature = "testing")]
+            block_context: None,
         }
     }
 
@@ -54,8 +64,30 @@ impl<'a> NativeBlockContext<'a> {
         self.height = height;
         self.timestamp = timestamp;
     }
+
+    #[cfg(feature = "testing")]
+    pub fn set_block_context(&mut self, block_context: BlockContext) {
+        self.block_context = Some(block_context);
+    }
+
+    pub(crate) fn block_context(&self) -> PartialVMResult<BlockContext> {
+        #[cfg(feature = "testing")]
+        if let Some(block_context) = &self.block_context {
+            return Ok(block_context.clone());
+        }
+
+        self.api
+            .get_block_context()
+            .map_err(|_| PartialVMError::new(StatusCode::LOOKUP_FAILED))
+    }
 }
 
+/***************************************************************************************************
+ * native fun get_block_info
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
 fn native_get_block_info(
     gas_params: &GetBlockInfoGasParameters,
     context: &NativeContext,
@@ -94,15 +126,157 @@ pub fn make_native_get_block_info(gas_params: GetBlockInfoGasParameters) -> Nati
     })
 }
 
+/***************************************************************************************************
+ * native fun get_chain_id
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_get_chain_id(
+    gas_params: &GetChainIdGasParameters,
+    context: &NativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let cost = gas_params.base;
+
+    let block_context = context
+        .extensions()
+        .get::<NativeBlockContext>()
+        .block_context()?;
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::struct_(Struct::pack(vec![Value::vector_u8(
+            block_context.chain_id.into_bytes()
+        )]))],
+    ))
+}
+
+pub fn make_native_get_chain_id(gas_params: GetChainIdGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_get_chain_id(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun get_block_hash
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_get_block_hash(
+    gas_params: &GetBlockHashGasParameters,
+    context: &NativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let cost = gas_params.base;
+
+    let block_context = context
+        .extensions()
+        .get::<NativeBlockContext>()
+        .block_context()?;
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(block_context.block_hash)],
+    ))
+}
+
+pub fn make_native_get_block_hash(gas_params: GetBlockHashGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_get_block_hash(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun get_proposer
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_get_proposer(
+    gas_params: &GetProposerGasParameters,
+    context: &NativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let cost = gas_params.base;
+
+    let block_context = context
+        .extensions()
+        .get::<NativeBlockContext>()
+        .block_context()?;
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::address(block_context.proposer)],
+    ))
+}
+
+pub fn make_native_get_proposer(gas_params: GetProposerGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_get_proposer(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun get_timestamp_nanos
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_get_timestamp_nanos(
+    gas_params: &GetTimestampNanosGasParameters,
+    context: &NativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let cost = gas_params.base;
+
+    let block_context = context
+        .extensions()
+        .get::<NativeBlockContext>()
+        .block_context()?;
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u64(block_context.timestamp_nanos)],
+    ))
+}
+
+pub fn make_native_get_timestamp_nanos(
+    gas_params: GetTimestampNanosGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_get_timestamp_nanos(&gas_params, context, ty_args, args)
+    })
+}
+
 /***************************************************************************************************
  * module
  *
  **************************************************************************************************/
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [(
-        "get_block_info_internal",
-        make_native_get_block_info(gas_params.get_block_info),
-    )];
+    let natives = [
+        (
+            "get_block_info_internal",
+            make_native_get_block_info(gas_params.get_block_info),
+        ),
+        (
+            "get_chain_id",
+            make_native_get_chain_id(gas_params.get_chain_id),
+        ),
+        (
+            "get_block_hash",
+            make_native_get_block_hash(gas_params.get_block_hash),
+        ),
+        (
+            "get_proposer",
+            make_native_get_proposer(gas_params.get_proposer),
+        ),
+        (
+            "get_timestamp_nanos",
+            make_native_get_timestamp_nanos(gas_params.get_timestamp_nanos),
+        ),
+    ];
 
     crate::helpers::make_module_natives(natives)
 }
@@ -0,0 +1,206 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use move_deps::{
+    move_binary_format::errors::PartialVMResult,
+    move_core_types::gas_algebra::NumBytes,
+    move_vm_runtime::native_functions::{NativeContext, NativeFunction},
+    move_vm_types::{
+        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+    },
+};
+use nova_gas::gas_params::hash::*;
+use ripemd160::Ripemd160;
+use sha2::Sha512;
+use sha3::{Digest, Keccak256};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+pub(crate) fn keccak256(bytes: &[u8]) -> Vec<u8> {
+    Keccak256::digest(bytes).to_vec()
+}
+
+pub(crate) fn sha2_512(bytes: &[u8]) -> Vec<u8> {
+    Sha512::digest(bytes).to_vec()
+}
+
+pub(crate) fn ripemd160(bytes: &[u8]) -> Vec<u8> {
+    Ripemd160::digest(bytes).to_vec()
+}
+
+pub(crate) fn blake2b_256(bytes: &[u8]) -> Vec<u8> {
+    let mut hasher = VarBlake2b::new(32).expect("32 is a valid blake2b output size");
+    hasher.update(bytes);
+
+    let mut output = vec![0u8; 32];
+    hasher.finalize_variable(|res| output.copy_from_slice(res));
+    output
+}
+
+/***************************************************************************************************
+ * native fun keccak256
+ *
+ *   gas cost: base_cost + per_byte * bytes_len
+ *
+ **************************************************************************************************/
+fn native_keccak256(
+    gas_params: &Keccak256GasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(keccak256(&bytes))],
+    ))
+}
+
+pub fn make_native_keccak256(gas_params: Keccak256GasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_keccak256(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun sha2_512
+ *
+ *   gas cost: base_cost + per_byte * bytes_len
+ *
+ **************************************************************************************************/
+fn native_sha2_512(
+    gas_params: &Sha2_512GasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(sha2_512(&bytes))],
+    ))
+}
+
+pub fn make_native_sha2_512(gas_params: Sha2_512GasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_sha2_512(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun ripemd160
+ *
+ *   gas cost: base_cost + per_byte * bytes_len
+ *
+ **************************************************************************************************/
+fn native_ripemd160(
+    gas_params: &Ripemd160GasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(ripemd160(&bytes))],
+    ))
+}
+
+pub fn make_native_ripemd160(gas_params: Ripemd160GasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_ripemd160(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun blake2b_256
+ *
+ *   gas cost: base_cost + per_byte * bytes_len
+ *
+ **************************************************************************************************/
+fn native_blake2b_256(
+    gas_params: &Blake2b256GasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(blake2b_256(&bytes))],
+    ))
+}
+
+pub fn make_native_blake2b_256(gas_params: Blake2b256GasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_blake2b_256(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("keccak256", make_native_keccak256(gas_params.keccak256)),
+        ("sha2_512", make_native_sha2_512(gas_params.sha2_512)),
+        ("ripemd160", make_native_ripemd160(gas_params.ripemd160)),
+        (
+            "blake2b_256",
+            make_native_blake2b_256(gas_params.blake2b_256),
+        ),
+    ];
+
+    crate::helpers::make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_known_vectors() {
+        assert_eq!(
+            hex(&keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex(&keccak256(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+        assert_eq!(
+            hex(&sha2_512(b"abc")),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(
+            hex(&ripemd160(b"abc")),
+            "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+        );
+        assert_eq!(
+            hex(&blake2b_256(b"abc")),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+    }
+}
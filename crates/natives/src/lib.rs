@@ -4,13 +4,21 @@
 mod helpers;
 
 pub mod account;
+pub mod bank;
+pub mod bech32;
 pub mod block;
 pub mod code;
 pub mod event;
+pub mod hash;
+pub mod object;
+pub mod randomness;
 pub mod table;
+pub mod transaction_context;
 pub mod type_info;
 pub mod util;
 
+#[cfg(feature = "testing")]
+pub mod mocks;
 #[cfg(feature = "testing")]
 pub mod unit_test;
 
@@ -34,6 +42,12 @@ pub mod status {
     pub const NFE_EXPECTED_STRUCT_TYPE_TAG: u64 = 0x1;
     // Failure in address parsing (likely no correct length)
     pub const NFE_UNABLE_TO_PARSE_ADDRESS: u64 = 0x2;
+    // Failure in bech32 encoding (likely invalid hrp)
+    pub const NFE_UNABLE_TO_ENCODE_BECH32: u64 = 0x3;
+    // Failure in bech32 decoding (invalid checksum, variant or address length)
+    pub const NFE_UNABLE_TO_DECODE_BECH32: u64 = 0x4;
+    // Failure in the bank callback (likely insufficient balance)
+    pub const NFE_BANK_OPERATION_FAILED: u64 = 0x5;
 }
 
 pub fn nova_natives(
@@ -56,6 +70,15 @@ pub fn nova_natives(
     add_natives_from_module!("type_info", type_info::make_all(gas_params.type_info));
     add_natives_from_module!("util", util::make_all(gas_params.util));
     add_natives_from_module!("code", code::make_all(gas_params.code));
+    add_natives_from_module!("hash", hash::make_all(gas_params.hash));
+    add_natives_from_module!("bech32", bech32::make_all(gas_params.bech32));
+    add_natives_from_module!(
+        "transaction_context",
+        transaction_context::make_all(gas_params.transaction_context)
+    );
+    add_natives_from_module!("randomness", randomness::make_all(gas_params.randomness));
+    add_natives_from_module!("bank", bank::make_all(gas_params.bank));
+    add_natives_from_module!("object", object::make_all(gas_params.object));
     add_natives_from_module!(
         "event",
         event::make_all(gas_params.event, calc_abstract_val_size)
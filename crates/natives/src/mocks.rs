@@ -0,0 +1,111 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mocks of the chain callbacks, shared by the unit tests of the vm and the compiler.
+
+use anyhow::anyhow;
+use move_deps::move_core_types::account_address::AccountAddress;
+use std::{cell::RefCell, collections::BTreeMap};
+
+use crate::{
+    bank::BankBridge,
+    block::{BlockContext, BlockInfoResolver},
+};
+
+#[derive(Default)]
+pub struct MockApi {
+    pub height: u64,
+    pub timestamp: u64,
+    pub block_context: BlockContext,
+    pub bank: MockBank,
+}
+
+impl BlockInfoResolver for MockApi {
+    fn get_block_info(&self) -> anyhow::Result<(u64 /* height */, u64 /* timestamp */)> {
+        Ok((self.height, self.timestamp))
+    }
+
+    fn get_block_context(&self) -> anyhow::Result<BlockContext> {
+        Ok(self.block_context.clone())
+    }
+}
+
+/// In-memory bank ledger; withdrawn coins are kept in a per-denom escrow
+/// until they are deposited again.
+#[derive(Default)]
+pub struct MockBank {
+    balances: RefCell<BTreeMap<(String, AccountAddress), u64>>,
+    escrow: RefCell<BTreeMap<String, u64>>,
+    supply: RefCell<BTreeMap<String, u64>>,
+}
+
+impl MockBank {
+    pub fn mint(&self, denom: &str, addr: &AccountAddress, amount: u64) {
+        *self
+            .balances
+            .borrow_mut()
+            .entry((denom.to_string(), *addr))
+            .or_default() += amount;
+        *self
+            .supply
+            .borrow_mut()
+            .entry(denom.to_string())
+            .or_default() += amount;
+    }
+}
+
+impl BankBridge for MockApi {
+    fn bank_withdraw(&self, denom: &str, addr: &AccountAddress, amount: u64) -> anyhow::Result<()> {
+        let mut balances = self.bank.balances.borrow_mut();
+        let balance = balances.entry((denom.to_string(), *addr)).or_default();
+        if *balance < amount {
+            return Err(anyhow!("insufficient funds"));
+        }
+
+        *balance -= amount;
+        *self
+            .bank
+            .escrow
+            .borrow_mut()
+            .entry(denom.to_string())
+            .or_default() += amount;
+        Ok(())
+    }
+
+    fn bank_deposit(&self, denom: &str, addr: &AccountAddress, amount: u64) -> anyhow::Result<()> {
+        let mut escrow = self.bank.escrow.borrow_mut();
+        let escrowed = escrow.entry(denom.to_string()).or_default();
+        if *escrowed < amount {
+            return Err(anyhow!("insufficient escrow"));
+        }
+
+        *escrowed -= amount;
+        *self
+            .bank
+            .balances
+            .borrow_mut()
+            .entry((denom.to_string(), *addr))
+            .or_default() += amount;
+        Ok(())
+    }
+
+    fn bank_balance(&self, denom: &str, addr: &AccountAddress) -> anyhow::Result<u64> {
+        Ok(self
+            .bank
+            .balances
+            .borrow()
+            .get(&(denom.to_string(), *addr))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn bank_supply(&self, denom: &str) -> anyhow::Result<u64> {
+        Ok(self
+            .bank
+            .supply
+            .borrow()
+            .get(denom)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
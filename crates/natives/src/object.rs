@@ -0,0 +1,61 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::{
+    move_binary_format::errors::PartialVMResult,
+    move_core_types::account_address::AccountAddress,
+    move_vm_runtime::native_functions::{NativeContext, NativeFunction},
+    move_vm_types::{
+        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+    },
+};
+use nova_gas::gas_params::object::*;
+use smallvec::smallvec;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/***************************************************************************************************
+ * native fun exists_at
+ *
+ *   Returns `true` if a resource of type `T` is stored at the address.
+ *
+ *   gas cost: base + per_byte * loaded_bytes
+ *
+ **************************************************************************************************/
+fn native_exists_at(
+    gas_params: &ExistsAtGasParameters,
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let type_ = ty_args.pop().unwrap();
+    let address = pop_arg!(args, AccountAddress);
+
+    let (exists, loaded) = context
+        .exists_at(address, &type_)
+        .map_err(|e| e.to_partial())?;
+
+    let mut cost = gas_params.base;
+    if let Some(num_bytes) = loaded {
+        cost += gas_params.per_byte * num_bytes;
+    }
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(exists)]))
+}
+
+pub fn make_native_exists_at(gas_params: ExistsAtGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_exists_at(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [("exists_at", make_native_exists_at(gas_params.exists_at))];
+
+    crate::helpers::make_module_natives(natives)
+}
@@ -0,0 +1,77 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_deps::{
+    move_binary_format::errors::PartialVMResult,
+    move_vm_runtime::native_functions::{NativeContext, NativeFunction},
+    move_vm_types::{
+        loaded_data::runtime_types::Type, natives::function::NativeResult, values::Value,
+    },
+};
+use nova_gas::gas_params::randomness::*;
+use sha3::{Digest, Sha3_256};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::{block::NativeBlockContext, transaction_context::NativeTransactionContext};
+
+/// Salt appended to the seed preimage, which keeps the seeds apart from
+/// the other values derived from the same `txn_hash`.
+const RANDOMNESS_SALT: &[u8] = b"randomness";
+
+/***************************************************************************************************
+ * native fun next_seed
+ *
+ *   Returns sha3_256(txn_hash | block_hash | counter | salt), which is predictable
+ *   by anyone who knows the transaction hash and the block hash.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_next_seed(
+    gas_params: &NextSeedGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.is_empty());
+
+    let block_hash = context
+        .extensions()
+        .get::<NativeBlockContext>()
+        .block_context()?
+        .block_hash;
+
+    let txn_context = context
+        .extensions_mut()
+        .get_mut::<NativeTransactionContext>();
+    let count = txn_context.next_randomness_count();
+
+    let mut digest = Sha3_256::new();
+    Digest::update(&mut digest, txn_context.txn_hash());
+    Digest::update(&mut digest, &block_hash);
+    Digest::update(&mut digest, count.to_be_bytes());
+    Digest::update(&mut digest, RANDOMNESS_SALT);
+    let seed = digest.finalize().to_vec();
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::vector_u8(seed)],
+    ))
+}
+
+pub fn make_native_next_seed(gas_params: NextSeedGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_next_seed(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [("next_seed", make_native_next_seed(gas_params.next_seed))];
+
+    crate::helpers::make_module_natives(natives)
+}
@@ -19,13 +19,14 @@ use move_deps::move_vm_types::{
 use nova_gas::gas_params::table::*;
 use nova_gas::table::GasParameters;
 use nova_types::iterator::Order;
-use nova_types::table::{TableChange, TableChangeSet, TableHandle, TableInfo};
+use nova_types::ordered_key::{decode_ordered_key, encode_ordered_key, ordered_key_layout};
+use nova_types::table::{KeyEncoding, TableChange, TableChangeSet, TableHandle, TableInfo};
 use sha3::{Digest, Sha3_256};
 use smallvec::smallvec;
 
 use std::ops::{Bound, RangeBounds};
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::{btree_map::Entry, BTreeMap, BTreeSet, VecDeque},
     sync::Arc,
 };
@@ -39,6 +40,9 @@ pub trait TableResolver {
         key: &[u8],
     ) -> anyhow::Result<Option<Vec<u8>>>;
 
+    /// Returns the key encoding the table was created with.
+    fn resolve_key_encoding(&self, handle: &TableHandle) -> anyhow::Result<KeyEncoding>;
+
     fn create_iterator(
         &mut self,
         handle: &TableHandle,
@@ -48,6 +52,9 @@ pub trait TableResolver {
     ) -> anyhow::Result<u32>;
 
     fn next_key(&mut self, iterator_id: u32) -> anyhow::Result<Option<Vec<u8>>>;
+
+    /// Releases the iterator and the storage resources held by it.
+    fn destroy_iterator(&mut self, iterator_id: u32) -> anyhow::Result<()>;
 }
 
 /// A table operation, for supporting cost calculation.
@@ -69,15 +76,23 @@ pub struct NativeTableContext<'a> {
     resolver: &'a mut dyn TableResolver,
     txn_hash: [u8; 32],
     table_data: RefCell<TableData>,
-    iterators: RefCell<Vec<TableIter>>,
+    iterators: RefCell<BTreeMap<usize, TableIter>>,
+    next_iterator_id: Cell<usize>,
 }
 
 // See stdlib/Error.move
 const _ECATEGORY_INVALID_STATE: u8 = 0;
 const ECATEGORY_INVALID_ARGUMENT: u8 = 7;
+const ECATEGORY_RESOURCE_EXHAUSTED: u8 = 9;
 
 const ALREADY_EXISTS: u64 = (100 << 8) + ECATEGORY_INVALID_ARGUMENT as u64;
 const NOT_FOUND: u64 = (101 << 8) + ECATEGORY_INVALID_ARGUMENT as u64;
+const UNSUPPORTED_KEY_TYPE: u64 = (103 << 8) + ECATEGORY_INVALID_ARGUMENT as u64;
+const TOO_MANY_OPEN_ITERATORS: u64 = (105 << 8) + ECATEGORY_RESOURCE_EXHAUSTED as u64;
+const ITERATOR_NOT_FOUND: u64 = (106 << 8) + ECATEGORY_INVALID_ARGUMENT as u64;
+
+/// The maximum number of table iterators which can be open at the same time in a session.
+pub const MAX_OPEN_ITERATORS: usize = 1024;
 // Move side raises this
 const _NOT_EMPTY: u64 = (102 << 8) + _ECATEGORY_INVALID_STATE as u64;
 
@@ -97,6 +112,7 @@ struct TableData {
 /// A structure representing a single table.
 struct Table {
     handle: TableHandle,
+    key_encoding: KeyEncoding,
     key_layout: MoveTypeLayout,
     value_layout: MoveTypeLayout,
     content: BTreeMap<Vec<u8>, GlobalValue>,
@@ -132,6 +148,7 @@ impl<'a> NativeTableContext<'a> {
             txn_hash,
             table_data: Default::default(),
             iterators: Default::default(),
+            next_iterator_id: Default::default(),
         }
     }
 
@@ -203,10 +220,25 @@ impl TableData {
     ) -> PartialVMResult<&mut Table> {
         Ok(match self.tables.entry(handle) {
             Entry::Vacant(e) => {
+                let key_encoding = match self.new_tables.get(&handle) {
+                    Some(info) => info.key_encoding,
+                    None => context
+                        .extensions()
+                        .get::<NativeTableContext>()
+                        .resolver
+                        .resolve_key_encoding(&handle)
+                        .map_err(|err| {
+                            partial_extension_error(format!(
+                                "remote table resolver failure: {}",
+                                err
+                            ))
+                        })?,
+                };
                 let key_layout = get_type_layout(context, key_ty)?;
                 let value_layout = get_type_layout(context, value_ty)?;
                 let table = Table {
                     handle,
+                    key_encoding,
                     key_layout,
                     value_layout,
                     content: Default::default(),
@@ -219,6 +251,37 @@ impl TableData {
 }
 
 impl Table {
+    /// Serializes a key with the key encoding of the table.
+    fn serialize_key(&self, key: &Value) -> PartialVMResult<Vec<u8>> {
+        let key_bytes = serialize(&self.key_layout, key)?;
+        self.encode_key(key_bytes)
+    }
+
+    /// Converts BCS encoded key bytes into the key encoding of the table.
+    fn encode_key(&self, key_bytes: Vec<u8>) -> PartialVMResult<Vec<u8>> {
+        match self.key_encoding {
+            KeyEncoding::Bcs => Ok(key_bytes),
+            KeyEncoding::Ordered => {
+                encode_ordered_key(&self.key_layout, &key_bytes).map_err(|err| {
+                    partial_extension_error(format!("cannot encode table key: {}", err))
+                })
+            }
+        }
+    }
+
+    /// Deserializes a key stored with the key encoding of the table.
+    fn deserialize_key(&self, key_bytes: &[u8]) -> PartialVMResult<Value> {
+        match self.key_encoding {
+            KeyEncoding::Bcs => deserialize(&self.key_layout, key_bytes),
+            KeyEncoding::Ordered => {
+                let bcs_bytes = decode_ordered_key(&self.key_layout, key_bytes).map_err(|err| {
+                    partial_extension_error(format!("cannot decode table key: {}", err))
+                })?;
+                deserialize(&self.key_layout, &bcs_bytes)
+            }
+        }
+    }
+
     fn get_or_create_global_value(
         &mut self,
         context: &NativeTableContext,
@@ -287,7 +350,7 @@ impl TableIter {
 
 /// Returns all natives for tables.
 pub fn all_natives(table_addr: AccountAddress, gas_params: GasParameters) -> NativeFunctionTable {
-    let natives: [(&str, &str, NativeFunction); 12] = [
+    let natives: [(&str, &str, NativeFunction); 13] = [
         (
             "table",
             "new_table_handle",
@@ -348,6 +411,11 @@ pub fn all_natives(table_addr: AccountAddress, gas_params: GasParameters) -> Nat
             "next_box",
             make_native_next_box(gas_params.next_box),
         ),
+        (
+            "table",
+            "destroy_table_iter",
+            make_native_destroy_table_iter(gas_params.destroy_table_iter),
+        ),
     ];
 
     native_functions::make_table_from_iter(table_addr, natives)
@@ -360,7 +428,21 @@ fn native_new_table_handle(
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
     assert_eq!(ty_args.len(), 2);
-    assert_eq!(args.len(), 1);
+    assert_eq!(args.len(), 2);
+
+    let ordered_keys = pop_arg!(args, bool);
+    let payer = pop_arg!(args, AccountAddress);
+
+    let key_type = context.type_to_type_tag(&ty_args[0])?;
+    let value_type = context.type_to_type_tag(&ty_args[1])?;
+    let key_encoding = if ordered_keys {
+        if ordered_key_layout(&key_type).is_none() {
+            return Ok(NativeResult::err(gas_params.base, UNSUPPORTED_KEY_TYPE));
+        }
+        KeyEncoding::Ordered
+    } else {
+        KeyEncoding::Bcs
+    };
 
     let table_context = context.extensions().get::<NativeTableContext>();
     let mut table_data = table_context.table_data.borrow_mut();
@@ -375,14 +457,14 @@ fn native_new_table_handle(
     let bytes = digest.finalize().to_vec();
     let handle = AccountAddress::from_bytes(&bytes[0..AccountAddress::LENGTH])
         .map_err(|_| partial_extension_error("Unable to create table handle"))?;
-    let key_type = context.type_to_type_tag(&ty_args[0])?;
-    let value_type = context.type_to_type_tag(&ty_args[1])?;
     assert!(table_data
         .new_tables
-        .insert(TableHandle(handle), TableInfo::new(key_type, value_type))
+        .insert(
+            TableHandle(handle),
+            TableInfo::new(key_type, value_type, key_encoding)
+        )
         .is_none());
 
-    let payer = pop_arg!(args, AccountAddress);
     assert!(table_data
         .payer_changes
         .insert(TableHandle(handle), payer)
@@ -450,7 +532,7 @@ fn native_add_box(
 
     let table = table_data.get_or_create_table(context, handle, &ty_args[0], &ty_args[2])?;
 
-    let key_bytes = serialize(&table.key_layout, &key)?;
+    let key_bytes = table.serialize_key(&key)?;
     cost += gas_params.per_byte_serialized * NumBytes::new(key_bytes.len() as u64);
 
     let (gv, loaded) = table.get_or_create_global_value(table_context, key_bytes)?;
@@ -493,7 +575,7 @@ fn native_borrow_box(
 
     let mut cost = gas_params.base;
 
-    let key_bytes = serialize(&table.key_layout, &key)?;
+    let key_bytes = table.serialize_key(&key)?;
     cost += gas_params.per_byte_serialized * NumBytes::new(key_bytes.len() as u64);
 
     let (gv, loaded) = table.get_or_create_global_value(table_context, key_bytes)?;
@@ -536,7 +618,7 @@ fn native_contains_box(
 
     let mut cost = gas_params.base;
 
-    let key_bytes = serialize(&table.key_layout, &key)?;
+    let key_bytes = table.serialize_key(&key)?;
     cost += gas_params.per_byte_serialized * NumBytes::new(key_bytes.len() as u64);
 
     let (gv, loaded) = table.get_or_create_global_value(table_context, key_bytes)?;
@@ -578,7 +660,7 @@ fn native_remove_box(
 
     let mut cost = gas_params.base;
 
-    let key_bytes = serialize(&table.key_layout, &key)?;
+    let key_bytes = table.serialize_key(&key)?;
     cost += gas_params.per_byte_serialized * NumBytes::new(key_bytes.len() as u64);
 
     let (gv, loaded) = table.get_or_create_global_value(table_context, key_bytes)?;
@@ -659,10 +741,31 @@ fn native_new_table_iter(
     assert_eq!(ty_args.len(), 3);
     assert_eq!(args.len(), 4);
 
+    let table_context = context.extensions().get::<NativeTableContext>();
+    if table_context.iterators.borrow().len() >= MAX_OPEN_ITERATORS {
+        return Ok(NativeResult::err(gas_params.base, TOO_MANY_OPEN_ITERATORS));
+    }
+
     let order = Order::try_from(pop_arg!(args, u8) as i32)
         .map_err(|_| PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR))?;
     let end_bytes = pop_arg!(args, Vector).to_vec_u8()?;
     let start_bytes = pop_arg!(args, Vector).to_vec_u8()?;
+    let handle = get_table_handle(&pop_arg!(args, StructRef))?;
+
+    // bounds are given BCS encoded, so convert them into the key encoding of the table
+    let (start_bytes, end_bytes) = {
+        let table_context = context.extensions().get::<NativeTableContext>();
+        let mut table_data = table_context.table_data.borrow_mut();
+        let table = table_data.get_or_create_table(context, handle, &ty_args[0], &ty_args[2])?;
+        let encode_bound = |bytes: Vec<u8>| {
+            if bytes.is_empty() {
+                Ok(bytes)
+            } else {
+                table.encode_key(bytes)
+            }
+        };
+        (encode_bound(start_bytes)?, encode_bound(end_bytes)?)
+    };
 
     // convert vector start end args into option iterator arguments
     let start_option: Option<&[u8]> = if start_bytes.len() == 0 {
@@ -676,8 +779,6 @@ fn native_new_table_iter(
         Some(end_bytes.as_ref())
     };
 
-    let handle = get_table_handle(&pop_arg!(args, StructRef))?;
-
     // create iterator and store this to table context
     let changes = iter_table_changes(
         &context,
@@ -701,15 +802,18 @@ fn native_new_table_iter(
             partial_extension_error(format!("remote table resolver failure: {}", err))
         })?;
 
-    let mut iterators = table_context.iterators.borrow_mut();
-    let context_iterator_id = iterators.len();
-    iterators.push(TableIter {
-        iterator_id,
-        handle,
-        changes,
-        next: None,
-        order,
-    });
+    let context_iterator_id = table_context.next_iterator_id.get();
+    table_context.next_iterator_id.set(context_iterator_id + 1);
+    table_context.iterators.borrow_mut().insert(
+        context_iterator_id,
+        TableIter {
+            iterator_id,
+            handle,
+            changes,
+            next: None,
+            order,
+        },
+    );
 
     Ok(NativeResult::ok(
         cost,
@@ -788,7 +892,7 @@ fn native_next_box(
 
     let table_context = context.extensions().get::<NativeTableContext>();
     let mut iterators = table_context.iterators.borrow_mut();
-    let iterator = iterators.get_mut(iterator_id).unwrap();
+    let iterator = iterators.get_mut(&iterator_id).unwrap();
 
     assert!(iterator.next.is_some());
 
@@ -806,6 +910,43 @@ pub fn make_native_next_box(gas_params: NextBoxGasParameters) -> NativeFunction
     )
 }
 
+/// Release the iterator from the table context and
+/// the storage iterator behind it.
+fn native_destroy_table_iter(
+    gas_params: &DestroyTableIterGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    assert_eq!(ty_args.len(), 0);
+    assert_eq!(args.len(), 1);
+
+    let iterator_id = get_iterator_id(&pop_arg!(args, StructRef))? as usize;
+
+    let table_context = context.extensions_mut().get_mut::<NativeTableContext>();
+    let iterator = match table_context.iterators.borrow_mut().remove(&iterator_id) {
+        Some(iterator) => iterator,
+        None => return Ok(NativeResult::err(gas_params.base, ITERATOR_NOT_FOUND)),
+    };
+
+    table_context
+        .resolver
+        .destroy_iterator(iterator.iterator_id)
+        .map_err(|err| {
+            partial_extension_error(format!("remote table resolver failure: {}", err))
+        })?;
+
+    Ok(NativeResult::ok(gas_params.base, smallvec![]))
+}
+
+pub fn make_native_destroy_table_iter(gas_params: DestroyTableIterGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_destroy_table_iter(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 // =========================================================================================
 // Helpers
 
@@ -893,7 +1034,7 @@ fn iter_table_changes(
 fn set_next(context: &mut NativeContext, iterator_id: usize, next: Option<(Value, Value)>) {
     let table_context = context.extensions().get::<NativeTableContext>();
     let mut iterators = table_context.iterators.borrow_mut();
-    let iterator = iterators.get_mut(iterator_id);
+    let iterator = iterators.get_mut(&iterator_id);
     assert!(iterator.is_some());
 
     iterator.unwrap().next = next;
@@ -905,7 +1046,7 @@ fn get_next_key_with_table_handle(
 ) -> PartialVMResult<((Option<Vec<u8>>, Option<Option<NumBytes>>), TableHandle)> {
     let table_context = context.extensions_mut().get_mut::<NativeTableContext>();
     let mut iterators = table_context.iterators.borrow_mut();
-    let iterator = iterators.get_mut(iterator_id);
+    let iterator = iterators.get_mut(&iterator_id);
 
     assert!(iterator.is_some());
     let iterator = iterator.unwrap();
@@ -928,11 +1069,11 @@ fn load_table_entry(
     let table_context = context.extensions().get::<NativeTableContext>();
     let mut table_data = table_context.table_data.borrow_mut();
     let table = table_data.get_or_create_table(context, handle, key_type, value_type)?;
-    let key_layout = table.key_layout.clone();
+    let key = table.deserialize_key(&key_bytes);
 
     let (gv, loaded) = table.get_or_create_global_value(table_context, key_bytes.clone())?;
     let (key_value, serialized) = if gv.exists()? {
-        let key = deserialize(&key_layout, &key_bytes)?;
+        let key = key?;
         let value = gv.borrow_global()?;
         (
             Some((key, value)),
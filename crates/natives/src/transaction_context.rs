@@ -0,0 +1,219 @@
+// Copyright (c) Kernel-Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use better_any::{Tid, TidAble};
+use move_deps::{
+    move_binary_format::errors::PartialVMResult,
+    move_core_types::{account_address::AccountAddress, gas_algebra::NumArgs},
+    move_vm_runtime::native_functions::{NativeContext, NativeFunction},
+    move_vm_types::{
+        loaded_data::runtime_types::Type, natives::function::NativeResult, values::Value,
+    },
+};
+use nova_gas::{gas_params::transaction_context::*, GasBalance};
+use sha3::{Digest, Sha3_256};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Salt appended to the unique address preimage, which keeps the generated
+/// addresses apart from the table handles derived from the same `txn_hash`.
+const UNIQUE_ADDRESS_SALT: &[u8] = b"unique_address";
+
+/// The native transaction context extension.
+#[derive(Tid)]
+pub struct NativeTransactionContext {
+    txn_hash: [u8; 32],
+    senders: Vec<AccountAddress>,
+    unique_address_count: u32,
+    randomness_count: u64,
+    /// `None` when the session is not metered.
+    gas_balance: Option<GasBalance>,
+}
+
+impl NativeTransactionContext {
+    pub fn new(
+        txn_hash: [u8; 32],
+        senders: Vec<AccountAddress>,
+        gas_balance: Option<GasBalance>,
+    ) -> Self {
+        Self {
+            txn_hash,
+            senders,
+            unique_address_count: 0,
+            randomness_count: 0,
+            gas_balance,
+        }
+    }
+
+    pub(crate) fn txn_hash(&self) -> &[u8; 32] {
+        &self.txn_hash
+    }
+
+    /// Return the current randomness counter and increment it.
+    pub(crate) fn next_randomness_count(&mut self) -> u64 {
+        let count = self.randomness_count;
+        self.randomness_count += 1;
+        count
+    }
+}
+
+/***************************************************************************************************
+ * native fun get_txn_hash
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_get_txn_hash(
+    gas_params: &GetTxnHashGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.is_empty());
+
+    let txn_context = context.extensions().get::<NativeTransactionContext>();
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::vector_u8(txn_context.txn_hash.to_vec())],
+    ))
+}
+
+pub fn make_native_get_txn_hash(gas_params: GetTxnHashGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_get_txn_hash(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun get_senders
+ *
+ *   gas cost: base_cost + per_sender * num_senders
+ *
+ **************************************************************************************************/
+fn native_get_senders(
+    gas_params: &GetSendersGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.is_empty());
+
+    let txn_context = context.extensions().get::<NativeTransactionContext>();
+    let senders = txn_context.senders.clone();
+
+    let cost = gas_params.base + gas_params.per_sender * NumArgs::new(senders.len() as u64);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_address(senders)],
+    ))
+}
+
+pub fn make_native_get_senders(gas_params: GetSendersGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_get_senders(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun generate_unique_address
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_generate_unique_address(
+    gas_params: &GenerateUniqueAddressGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.is_empty());
+
+    let txn_context = context
+        .extensions_mut()
+        .get_mut::<NativeTransactionContext>();
+
+    let mut digest = Sha3_256::new();
+    Digest::update(&mut digest, txn_context.txn_hash);
+    Digest::update(&mut digest, txn_context.unique_address_count.to_be_bytes());
+    Digest::update(&mut digest, UNIQUE_ADDRESS_SALT);
+    let bytes = digest.finalize().to_vec();
+    let addr = AccountAddress::from_bytes(&bytes[0..AccountAddress::LENGTH])
+        .expect("Unable to create an address from a hash");
+
+    txn_context.unique_address_count += 1;
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::address(addr)],
+    ))
+}
+
+pub fn make_native_generate_unique_address(
+    gas_params: GenerateUniqueAddressGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_generate_unique_address(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun get_remaining_gas
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_get_remaining_gas(
+    gas_params: &GetRemainingGasGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.is_empty());
+
+    let txn_context = context.extensions().get::<NativeTransactionContext>();
+    let remaining_gas = match &txn_context.gas_balance {
+        Some(gas_balance) => gas_balance.remaining().into(),
+        None => u64::MAX,
+    };
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(remaining_gas)],
+    ))
+}
+
+pub fn make_native_get_remaining_gas(gas_params: GetRemainingGasGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_get_remaining_gas(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "get_txn_hash",
+            make_native_get_txn_hash(gas_params.get_txn_hash),
+        ),
+        (
+            "get_senders",
+            make_native_get_senders(gas_params.get_senders),
+        ),
+        (
+            "generate_unique_address",
+            make_native_generate_unique_address(gas_params.generate_unique_address),
+        ),
+        (
+            "get_remaining_gas",
+            make_native_get_remaining_gas(gas_params.get_remaining_gas),
+        ),
+    ];
+
+    crate::helpers::make_module_natives(natives)
+}
@@ -16,7 +16,7 @@ use nova_gas::gas_params::unit_test::*;
 use smallvec::smallvec;
 use std::{collections::VecDeque, sync::Arc};
 
-use crate::block::NativeBlockContext;
+use crate::block::{BlockContext, NativeBlockContext};
 
 /***************************************************************************************************
  * native fun create_signers_for_testing
@@ -90,6 +90,46 @@ pub fn make_native_set_block_info_for_testing(
     )
 }
 
+fn native_set_block_context_for_testing(
+    gas_params: &SetBlockContextForTestingGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 4);
+
+    let timestamp_nanos = pop_arg!(args, u64);
+    let proposer = pop_arg!(args, AccountAddress);
+    let block_hash = pop_arg!(args, Vec<u8>);
+    let chain_id = pop_arg!(args, Vec<u8>);
+
+    let mut block_context = context.extensions_mut().get_mut::<NativeBlockContext>();
+    NativeBlockContext::set_block_context(
+        &mut block_context,
+        BlockContext {
+            chain_id: String::from_utf8_lossy(&chain_id).to_string(),
+            block_hash,
+            proposer,
+            timestamp_nanos,
+        },
+    );
+
+    let cost = gas_params.base;
+
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+pub fn make_native_set_block_context_for_testing(
+    gas_params: SetBlockContextForTestingGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_set_block_context_for_testing(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
@@ -103,6 +143,10 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             "set_block_info_for_testing",
             make_native_set_block_info_for_testing(gas_params.set_block_info_for_testing),
         ),
+        (
+            "set_block_context_for_testing",
+            make_native_set_block_context_for_testing(gas_params.set_block_context_for_testing),
+        ),
     ];
 
     crate::helpers::make_module_natives(natives)
@@ -62,15 +62,24 @@ fn move_nursery_files() -> Vec<TempPath> {
 fn nova_stdlib_files() -> Vec<TempPath> {
     let files: Vec<&str> = vec![
         include_str!("nova_stdlib/sources/account.move"),
+        include_str!("nova_stdlib/sources/bank.move"),
+        include_str!("nova_stdlib/sources/bech32.move"),
         include_str!("nova_stdlib/sources/block.move"),
         include_str!("nova_stdlib/sources/code.move"),
         include_str!("nova_stdlib/sources/coin.move"),
         include_str!("nova_stdlib/sources/comparator.move"),
+        include_str!("nova_stdlib/sources/fungible_asset.move"),
+        include_str!("nova_stdlib/sources/hash.move"),
+        include_str!("nova_stdlib/sources/object.move"),
+        include_str!("nova_stdlib/sources/randomness.move"),
         include_str!("nova_stdlib/sources/simple_map.move"),
         include_str!("nova_stdlib/sources/table_with_length.move"),
         include_str!("nova_stdlib/sources/table.move"),
+        include_str!("nova_stdlib/sources/token.move"),
+        include_str!("nova_stdlib/sources/transaction_context.move"),
         include_str!("nova_stdlib/sources/type_info.move"),
         include_str!("nova_stdlib/sources/util.move"),
+        include_str!("nova_stdlib/sources/vm_config.move"),
     ];
 
     files
@@ -12,4 +12,12 @@ module std::unit_test {
 
     /// Set block info for testing purpose
     native public fun set_block_info_for_testing(height: u64, timestamp: u64);
+
+    /// Set chain id, block hash, proposer and timestamp in nanoseconds for testing purpose
+    native public fun set_block_context_for_testing(
+        chain_id: vector<u8>,
+        block_hash: vector<u8>,
+        proposer: address,
+        timestamp_nanos: u64
+    );
 }
@@ -1,16 +1,63 @@
 module nova_std::account {
-    #[test_only]
     use std::vector;
-    #[test_only]
     use std::error;
-    #[test_only]
     use std::bcs;
+    use std::hash;
+    use std::signer;
+    use std::event::{Self, EventHandle};
+
+    use nova_std::block;
 
     friend nova_std::coin;
+    friend nova_std::fungible_asset;
+    friend nova_std::object;
 
     /// The provided authentication had an invalid length
     const EMALFORMED_AUTHENTICATION_KEY: u64 = 1;
     const ECANNOT_CREATE_ADDRESS: u64 = 2;
+    /// A resource account was already created at the derived address
+    const ERESOURCE_ACCOUNT_ALREADY_EXISTS: u64 = 3;
+    /// An `Account` was already created at the address
+    const EACCOUNT_ALREADY_EXISTS: u64 = 4;
+    /// No `Account` was created at the address
+    const EACCOUNT_NOT_FOUND: u64 = 5;
+
+    /// Scheme identifier appended to the preimage of resource account addresses,
+    /// which keeps them apart from the other hash derived addresses.
+    const DERIVE_RESOURCE_ACCOUNT_SCHEME: u8 = 255;
+
+    /// Length of the `address` type in bytes.
+    const ADDRESS_LENGTH: u64 = 20;
+
+    /// Capability to create the signer of a resource account. It is returned once, when
+    /// the resource account is created, and can be stored by the module owning the account.
+    struct SignerCapability has drop, store {
+        account: address,
+    }
+
+    /// Resource representing an account known to Move.
+    struct Account has key {
+        /// Block timestamp at which the account was created.
+        creation_timestamp: u64,
+        /// Counter used to create unique ids from this account.
+        guid_creation_num: u64,
+    }
+
+    /// Event emitted when an `Account` is created.
+    struct AccountCreatedEvent has drop, store {
+        account: address,
+        creation_timestamp: u64,
+    }
+
+    /// Holds the handle of `AccountCreatedEvent`s. Stored at `@nova_std`.
+    struct AccountCreatedEvents has key {
+        events: EventHandle<AccountCreatedEvent>,
+    }
+
+    /// Marks an address as a resource account and records from which address it was derived.
+    struct ResourceAccount has key {
+        source: address,
+    }
 
     native fun create_address(bytes: vector<u8>): address;
     native fun create_signer(addr: address): signer;
@@ -19,6 +66,131 @@ module nova_std::account {
         create_signer(addr)
     }
 
+    /// Return whether an `Account` exists at `addr`.
+    public fun exists_at(addr: address): bool {
+        exists<Account>(addr)
+    }
+
+    /// Create an `Account` for the signer.
+    public entry fun create_account(account: &signer) acquires AccountCreatedEvents {
+        let addr = signer::address_of(account);
+        assert!(!exists_at(addr), error::already_exists(EACCOUNT_ALREADY_EXISTS));
+        create_account_internal(addr);
+    }
+
+    /// Create an `Account` at `addr` if it does not exist yet.
+    public(friend) fun create_account_if_not_exists(addr: address) acquires AccountCreatedEvents {
+        if (!exists_at(addr)) {
+            create_account_internal(addr);
+        };
+    }
+
+    fun create_account_internal(addr: address) acquires AccountCreatedEvents {
+        let (_, creation_timestamp) = block::get_block_info();
+        move_to(&create_signer(addr), Account {
+            creation_timestamp,
+            guid_creation_num: 0,
+        });
+
+        if (!exists<AccountCreatedEvents>(@nova_std)) {
+            let nova_std = create_signer(@nova_std);
+            let events = event::new_event_handle<AccountCreatedEvent>(&nova_std);
+            move_to(&nova_std, AccountCreatedEvents { events });
+        };
+
+        event::emit_event<AccountCreatedEvent>(
+            &mut borrow_global_mut<AccountCreatedEvents>(@nova_std).events,
+            AccountCreatedEvent {
+                account: addr,
+                creation_timestamp,
+            },
+        );
+    }
+
+    /// Return the block timestamp at which the account at `addr` was created.
+    public fun get_creation_timestamp(addr: address): u64 acquires Account {
+        assert!(exists_at(addr), error::not_found(EACCOUNT_NOT_FOUND));
+        borrow_global<Account>(addr).creation_timestamp
+    }
+
+    /// Return the number of unique ids created from the account at `addr`.
+    public fun get_guid_creation_num(addr: address): u64 acquires Account {
+        assert!(exists_at(addr), error::not_found(EACCOUNT_NOT_FOUND));
+        borrow_global<Account>(addr).guid_creation_num
+    }
+
+    /// Return a new unique id of the account and increment the counter.
+    public fun create_guid_creation_num(account: &signer): u64 acquires Account {
+        let addr = signer::address_of(account);
+        assert!(exists_at(addr), error::not_found(EACCOUNT_NOT_FOUND));
+
+        let account = borrow_global_mut<Account>(addr);
+        let guid_creation_num = account.guid_creation_num;
+        account.guid_creation_num = guid_creation_num + 1;
+        guid_creation_num
+    }
+
+    /// Return the address of the resource account derived from `source` and `seed`.
+    public fun create_resource_address(source: &address, seed: vector<u8>): address {
+        derive_address(source, seed, DERIVE_RESOURCE_ACCOUNT_SCHEME)
+    }
+
+    /// Return the address derived from `source` and `seed` for the given `scheme`,
+    /// which is the leading bytes of `sha3_256(source | seed | scheme)`.
+    public(friend) fun derive_address(source: &address, seed: vector<u8>, scheme: u8): address {
+        let bytes = bcs::to_bytes(source);
+        vector::append(&mut bytes, seed);
+        vector::push_back(&mut bytes, scheme);
+
+        let hash = hash::sha3_256(bytes);
+        let addr_bytes = vector::empty<u8>();
+        let i = 0;
+        while (i < ADDRESS_LENGTH) {
+            vector::push_back(&mut addr_bytes, *vector::borrow(&hash, i));
+            i = i + 1;
+        };
+
+        create_address(addr_bytes)
+    }
+
+    /// Create a resource account derived from the `source` and `seed`, and return its signer
+    /// together with the `SignerCapability` which allows to recreate the signer later.
+    /// As no private key exists for the account, it can only be controlled through the capability.
+    public fun create_resource_account(
+        source: &signer,
+        seed: vector<u8>
+    ): (signer, SignerCapability) acquires AccountCreatedEvents {
+        let source_addr = signer::address_of(source);
+        let resource_addr = create_resource_address(&source_addr, seed);
+        assert!(
+            !exists<ResourceAccount>(resource_addr),
+            error::already_exists(ERESOURCE_ACCOUNT_ALREADY_EXISTS)
+        );
+
+        // the account may already be created implicitly, e.g. by a coin deposit
+        create_account_if_not_exists(resource_addr);
+
+        let resource = create_signer(resource_addr);
+        move_to(&resource, ResourceAccount { source: source_addr });
+
+        (resource, SignerCapability { account: resource_addr })
+    }
+
+    /// Create the signer of the resource account the `capability` belongs to.
+    public fun create_signer_with_capability(capability: &SignerCapability): signer {
+        create_signer(capability.account)
+    }
+
+    /// Return the address of the resource account the `capability` belongs to.
+    public fun get_signer_capability_address(capability: &SignerCapability): address {
+        capability.account
+    }
+
+    /// Return whether `addr` is a resource account.
+    public fun is_resource_account(addr: address): bool {
+        exists<ResourceAccount>(addr)
+    }
+
     #[test]
     public fun test_create_address(){
         let bob = create_address(x"0000000000000000000000000000000000000b0b");
@@ -42,4 +214,65 @@ module nova_std::account {
             error::invalid_argument(EMALFORMED_AUTHENTICATION_KEY)
         ); 
     }
+
+    #[test]
+    public fun test_create_resource_address() {
+        assert!(
+            create_resource_address(&@0x1, b"seed") == @0x4c1ca3ef7c48cb98ff7a2585612579277e8dce5b,
+            0
+        );
+        assert!(
+            create_resource_address(&@0xb0b, b"seed") == @0x565d44ea3e16425c53c9ee6eb567c32f10d6826d,
+            1
+        );
+        assert!(create_resource_address(&@0xb0b, b"seed") != create_resource_address(&@0xb0b, b"seed2"), 2);
+    }
+
+    #[test(account = @0xb0b)]
+    public fun test_create_account(account: signer) acquires Account, AccountCreatedEvents {
+        let addr = signer::address_of(&account);
+        assert!(!exists_at(addr), 0);
+
+        create_account(&account);
+        assert!(exists_at(addr), 1);
+        assert!(get_creation_timestamp(addr) == 0, 2);
+
+        assert!(create_guid_creation_num(&account) == 0, 3);
+        assert!(create_guid_creation_num(&account) == 1, 4);
+        assert!(get_guid_creation_num(addr) == 2, 5);
+    }
+
+    #[test(account = @0xb0b)]
+    #[expected_failure(abort_code = 0x80004)]
+    public fun test_create_account_twice(account: signer) acquires AccountCreatedEvents {
+        create_account(&account);
+        create_account(&account);
+    }
+
+    #[test]
+    public fun test_create_account_if_not_exists() acquires AccountCreatedEvents {
+        create_account_if_not_exists(@0xb0b);
+        create_account_if_not_exists(@0xb0b);
+        assert!(exists_at(@0xb0b), 0);
+    }
+
+    #[test(source = @0xb0b)]
+    public fun test_create_resource_account(source: signer) acquires AccountCreatedEvents {
+        let (resource, capability) = create_resource_account(&source, b"seed");
+        let resource_addr = signer::address_of(&resource);
+        assert!(resource_addr == create_resource_address(&@0xb0b, b"seed"), 0);
+        assert!(is_resource_account(resource_addr), 1);
+        assert!(exists_at(resource_addr), 4);
+        assert!(get_signer_capability_address(&capability) == resource_addr, 2);
+
+        let resource_signer = create_signer_with_capability(&capability);
+        assert!(signer::address_of(&resource_signer) == resource_addr, 3);
+    }
+
+    #[test(source = @0xb0b)]
+    #[expected_failure(abort_code = 0x80003)]
+    public fun test_create_resource_account_twice(source: signer) acquires AccountCreatedEvents {
+        create_resource_account(&source, b"seed");
+        create_resource_account(&source, b"seed");
+    }
 }
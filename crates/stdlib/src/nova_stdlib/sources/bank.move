@@ -0,0 +1,52 @@
+/// Bridge to the chain's bank module. Coins mapped to a bank denom keep
+/// their balances in the bank; a `Coin` value taken out of an account is
+/// backed by the funds held in the bridge escrow until it is deposited again.
+module nova_std::bank {
+    use std::string::{Self, String};
+
+    friend nova_std::coin;
+
+    /// Move `amount` of `denom` from the bank balance of `addr` into the escrow.
+    /// A zero amount is a no-op and never reaches the bank.
+    public(friend) fun withdraw(denom: &String, addr: address, amount: u64) {
+        withdraw_internal(*string::bytes(denom), addr, amount)
+    }
+
+    /// Move `amount` of `denom` from the escrow into the bank balance of `addr`.
+    /// A zero amount is a no-op and never reaches the bank.
+    public(friend) fun deposit(denom: &String, addr: address, amount: u64) {
+        deposit_internal(*string::bytes(denom), addr, amount)
+    }
+
+    /// Returns the bank balance of `addr` in `denom`.
+    public fun balance(denom: &String, addr: address): u64 {
+        balance_internal(*string::bytes(denom), addr)
+    }
+
+    /// Returns the total supply of `denom` known to the bank.
+    public fun supply(denom: &String): u64 {
+        supply_internal(*string::bytes(denom))
+    }
+
+    native fun withdraw_internal(denom: vector<u8>, addr: address, amount: u64);
+    native fun deposit_internal(denom: vector<u8>, addr: address, amount: u64);
+    native fun balance_internal(denom: vector<u8>, addr: address): u64;
+    native fun supply_internal(denom: vector<u8>): u64;
+
+    #[test]
+    fun test_empty_bank() {
+        let denom = string::utf8(b"uinit");
+        assert!(balance(&denom, @0x2) == 0, 0);
+        assert!(supply(&denom) == 0, 1);
+
+        // zero amounts are no-ops, even without any balance
+        withdraw(&denom, @0x2, 0);
+        deposit(&denom, @0x2, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x5)]
+    fun test_withdraw_insufficient_funds() {
+        withdraw(&string::utf8(b"uinit"), @0x2, 1);
+    }
+}
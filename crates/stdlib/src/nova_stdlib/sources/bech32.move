@@ -0,0 +1,38 @@
+/// Conversion between `address` and bech32 encoded account addresses like `init1...`.
+module nova_std::bech32 {
+    use std::string::{Self, String};
+
+    /// Encode `addr` to a bech32 string with the human readable part `hrp`.
+    public fun encode(hrp: String, addr: address): String {
+        string::utf8(encode_internal(*string::bytes(&hrp), addr))
+    }
+
+    /// Decode a bech32 string to its human readable part and address.
+    public fun decode(bech32_addr: String): (String, address) {
+        let (hrp, addr) = decode_internal(*string::bytes(&bech32_addr));
+        (string::utf8(hrp), addr)
+    }
+
+    native fun encode_internal(hrp: vector<u8>, addr: address): vector<u8>;
+    native fun decode_internal(bech32_addr: vector<u8>): (vector<u8>, address);
+
+    #[test]
+    fun test_encode_decode() {
+        let encoded = encode(string::utf8(b"init"), @0x1);
+        assert!(encoded == string::utf8(b"init1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqr5e3d"), 0);
+
+        let (hrp, addr) = decode(encoded);
+        assert!(hrp == string::utf8(b"init"), 1);
+        assert!(addr == @0x1, 2);
+
+        let (hrp, addr) = decode(string::utf8(b"cosmos15xev84897cr3s2f6fdwx6l50jqq3yge572xqpp"));
+        assert!(hrp == string::utf8(b"cosmos"), 3);
+        assert!(addr == @0xa1b2c3d4e5f60718293a4b5c6d7e8f9001122334, 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x4)]
+    fun test_decode_invalid_checksum() {
+        decode(string::utf8(b"init1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqr5e3e"));
+    }
+}
@@ -1,12 +1,29 @@
 module nova_std::block {
+    use std::string::String;
+
     native fun get_block_info_internal(): (u64, u64);
 
     public fun get_block_info(): (u64, u64) {
         get_block_info_internal()
     }
 
+    /// Return the chain id of the current chain.
+    native public fun get_chain_id(): String;
+
+    /// Return the hash of the current block.
+    native public fun get_block_hash(): vector<u8>;
+
+    /// Return the address of the current block proposer.
+    native public fun get_proposer(): address;
+
+    /// Return the timestamp of the current block in nanoseconds.
+    native public fun get_timestamp_nanos(): u64;
+
     #[test_only]
-    use std::unit_test::set_block_info_for_testing;
+    use std::unit_test::{set_block_info_for_testing, set_block_context_for_testing};
+
+    #[test_only]
+    use std::string;
 
     #[test]
     public fun test_get_block_info_internal(){
@@ -16,4 +33,19 @@ module nova_std::block {
         assert!(height == 12321u64, 0);
         assert!(timestamp == 9999999u64, 1);
     }
+
+    #[test]
+    public fun test_get_block_context() {
+        set_block_context_for_testing(
+            b"novum-1",
+            x"0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+            @0x123,
+            9999999123456789u64
+        );
+
+        assert!(get_chain_id() == string::utf8(b"novum-1"), 0);
+        assert!(get_block_hash() == x"0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20", 1);
+        assert!(get_proposer() == @0x123, 2);
+        assert!(get_timestamp_nanos() == 9999999123456789u64, 3);
+    }
 }
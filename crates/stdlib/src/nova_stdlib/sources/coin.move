@@ -6,7 +6,9 @@ module nova_std::coin {
     use std::event::{Self, EventHandle};
 
     use nova_std::type_info;
-    use nova_std::account::create_signer_for_friend;
+    use nova_std::account::{Self, create_signer_for_friend};
+    use nova_std::bank;
+    use nova_std::table::{Self, Table};
 
     //
     // Errors.
@@ -51,6 +53,21 @@ module nova_std::coin {
     /// unauthorized
     const ECOIN_UNAUTHORIZED: u64 = 14;
 
+    /// `CoinType` is already mapped to a bank denom
+    const ECOIN_DENOM_ALREADY_MAPPED: u64 = 15;
+
+    /// The bank denom is already mapped to another coin type
+    const EDENOM_ALREADY_MAPPED: u64 = 16;
+
+    /// The bank denom isn't mapped to any coin type
+    const EDENOM_NOT_MAPPED: u64 = 17;
+
+    /// Supply of a mapped coin is managed by the bank, so it cannot be minted or burned in Move
+    const ECOIN_MAPPED_TO_DENOM: u64 = 18;
+
+    /// A coin with outstanding supply cannot be mapped to a bank denom
+    const ECOIN_SUPPLY_NOT_ZERO: u64 = 19;
+
     //
     // Constants
     //
@@ -91,6 +108,17 @@ module nova_std::coin {
         supply: u128,
     }
 
+    /// Bank denom of a `CoinType`. Stored on @nova_std for the mapped coins,
+    /// whose balances and supply are kept by the bank instead of `CoinStore`.
+    struct CoinDenom<phantom CoinType> has key {
+        denom: string::String,
+    }
+
+    /// Reverse mapping from a bank denom to the type name of its coin. Stored on @nova_std.
+    struct DenomRegistry has key {
+        coin_types: Table<string::String, string::String>,
+    }
+
     /// Event emitted when some amount of a coin is deposited into an account.
     struct DepositEvent has drop, store {
         coin_type: string::String,
@@ -123,7 +151,11 @@ module nova_std::coin {
     }
 
     /// Returns the balance of `owner` for provided `CoinType`.
-    public entry fun balance<CoinType>(owner: address): u64 acquires CoinStore {
+    public entry fun balance<CoinType>(owner: address): u64 acquires CoinStore, CoinDenom {
+        if (is_mapped<CoinType>()) {
+            return bank::balance(&denom<CoinType>(), owner)
+        };
+
         assert!(
             is_account_registered<CoinType>(owner),
             error::not_found(ECOIN_STORE_NOT_PUBLISHED),
@@ -159,10 +191,34 @@ module nova_std::coin {
     }
 
     /// Returns the amount of coin in existence.
-    public entry fun supply<CoinType>(): u128 acquires CoinInfo {
+    public entry fun supply<CoinType>(): u128 acquires CoinInfo, CoinDenom {
+        if (is_mapped<CoinType>()) {
+            return (bank::supply(&denom<CoinType>()) as u128)
+        };
+
         borrow_global<CoinInfo<CoinType>>(coin_address<CoinType>()).supply
     }
 
+    /// Returns `true` if `CoinType` is mapped to a bank denom.
+    public entry fun is_mapped<CoinType>(): bool {
+        exists<CoinDenom<CoinType>>(@nova_std)
+    }
+
+    /// Returns the bank denom of a mapped `CoinType`.
+    public entry fun denom<CoinType>(): string::String acquires CoinDenom {
+        assert!(is_mapped<CoinType>(), error::not_found(EDENOM_NOT_MAPPED));
+        borrow_global<CoinDenom<CoinType>>(@nova_std).denom
+    }
+
+    /// Returns the type name of the coin mapped to the bank `denom`.
+    public entry fun coin_type_of_denom(denom: string::String): string::String acquires DenomRegistry {
+        assert!(exists<DenomRegistry>(@nova_std), error::not_found(EDENOM_NOT_MAPPED));
+
+        let coin_types = &borrow_global<DenomRegistry>(@nova_std).coin_types;
+        assert!(table::contains(coin_types, denom), error::not_found(EDENOM_NOT_MAPPED));
+        *table::borrow(coin_types, denom)
+    }
+
     // Chain functions
     /// Creates a new Coin with given `CoinType`
     public entry fun native_initialize<CoinType>(
@@ -186,10 +242,33 @@ module nova_std::coin {
         move_to(chain, coin_info);
     }
 
+    /// Map `CoinType` to the bank `denom`. From then on the balances and the supply
+    /// of the coin are kept by the bank, and deposits and withdrawals go through it.
+    public entry fun native_register_denom<CoinType>(chain: &signer, denom: string::String) acquires CoinInfo, DenomRegistry {
+        assert!(signer::address_of(chain) == @nova_std, error::invalid_argument(ECOIN_UNAUTHORIZED));
+        assert!(is_coin_initialized<CoinType>(), error::not_found(ECOIN_INFO_NOT_PUBLISHED));
+        assert!(!is_mapped<CoinType>(), error::already_exists(ECOIN_DENOM_ALREADY_MAPPED));
+
+        // the bank must stay the only ledger of a mapped coin
+        let coin_info = borrow_global<CoinInfo<CoinType>>(coin_address<CoinType>());
+        assert!(coin_info.supply == 0, error::invalid_state(ECOIN_SUPPLY_NOT_ZERO));
+
+        if (!exists<DenomRegistry>(@nova_std)) {
+            move_to(chain, DenomRegistry { coin_types: table::new(chain) });
+        };
+
+        let coin_types = &mut borrow_global_mut<DenomRegistry>(@nova_std).coin_types;
+        assert!(!table::contains(coin_types, denom), error::already_exists(EDENOM_ALREADY_MAPPED));
+        table::add(coin_types, denom, type_info::type_name<CoinType>());
+
+        move_to(chain, CoinDenom<CoinType> { denom });
+    }
+
     /// Burn native coins to the recipient
-    public entry fun native_burn_from<CoinType>(chain: &signer, account_addr: address, amount: u64) acquires CoinStore, CoinInfo {
+    public entry fun native_burn_from<CoinType>(chain: &signer, account_addr: address, amount: u64) acquires CoinStore, CoinInfo, CoinDenom {
         assert!(signer::address_of(chain) == @nova_std, ECOIN_UNAUTHORIZED);
         assert!(amount > 0, error::invalid_argument(EZERO_COIN_AMOUNT));
+        assert!(!is_mapped<CoinType>(), error::invalid_state(ECOIN_MAPPED_TO_DENOM));
 
         let coin_info = borrow_global_mut<CoinInfo<CoinType>>(coin_address<CoinType>());
         coin_info.supply = coin_info.supply - (amount as u128);
@@ -198,9 +277,10 @@ module nova_std::coin {
     }
 
     /// Mint native coins to the recipient
-    public entry fun native_mint_to<CoinType>(chain: &signer, account_addr: address, amount: u64) acquires CoinStore, CoinInfo {
+    public entry fun native_mint_to<CoinType>(chain: &signer, account_addr: address, amount: u64) acquires CoinStore, CoinInfo, CoinDenom {
         assert!(signer::address_of(chain) == @nova_std, ECOIN_UNAUTHORIZED);
         assert!(amount > 0, error::invalid_argument(EZERO_COIN_AMOUNT));
+        assert!(!is_mapped<CoinType>(), error::invalid_state(ECOIN_MAPPED_TO_DENOM));
 
         if (!is_account_registered<CoinType>(account_addr)) {
             let account = create_signer_for_friend(account_addr);
@@ -222,6 +302,7 @@ module nova_std::coin {
     ) acquires CoinInfo {
         let Coin { value: amount } = coin;
         assert!(amount > 0, error::invalid_argument(EZERO_COIN_AMOUNT));
+        assert!(!is_mapped<CoinType>(), error::invalid_state(ECOIN_MAPPED_TO_DENOM));
 
         let coin_info = borrow_global_mut<CoinInfo<CoinType>>(coin_address<CoinType>());
         coin_info.supply = coin_info.supply - (amount as u128);
@@ -248,12 +329,21 @@ module nova_std::coin {
     }
 
     /// Deposit the coin balance into the recipient's account and emit an event.
-    public fun deposit<CoinType>(account_addr: address, coin: Coin<CoinType>) acquires CoinStore {
+    /// Mapped coins are deposited into the recipient's bank balance instead.
+    public fun deposit<CoinType>(account_addr: address, coin: Coin<CoinType>) acquires CoinStore, CoinDenom {
+        let mapped = is_mapped<CoinType>();
+        if (mapped) {
+            register_mapped_if_not_exists<CoinType>(account_addr);
+        };
+
         assert!(
             is_account_registered<CoinType>(account_addr),
             error::not_found(ECOIN_STORE_NOT_PUBLISHED),
         );
 
+        // the first deposit makes the account known to Move
+        account::create_account_if_not_exists(account_addr);
+
         let coin_store = borrow_global_mut<CoinStore<CoinType>>(account_addr);
         assert!(
             !coin_store.frozen,
@@ -268,7 +358,21 @@ module nova_std::coin {
             },
         );
 
-        merge(&mut coin_store.coin, coin);
+        if (mapped) {
+            let Coin { value: amount } = coin;
+            bank::deposit(&denom<CoinType>(), account_addr, amount);
+        } else {
+            merge(&mut coin_store.coin, coin);
+        }
+    }
+
+    /// Mapped coins keep an empty `CoinStore` for the frozen flag and the events,
+    /// so it is registered on the first deposit or withdrawal.
+    fun register_mapped_if_not_exists<CoinType>(account_addr: address) {
+        if (!is_account_registered<CoinType>(account_addr)) {
+            let account = create_signer_for_friend(account_addr);
+            register<CoinType>(&account);
+        };
     }
 
     /// Destroys a zero-value coin. Calls will fail if the `value` in the passed-in `token` is non-zero
@@ -367,6 +471,8 @@ module nova_std::coin {
             return zero<CoinType>()
         };
 
+        assert!(!is_mapped<CoinType>(), error::invalid_state(ECOIN_MAPPED_TO_DENOM));
+
         let coin_info = borrow_global_mut<CoinInfo<CoinType>>(coin_address<CoinType>());
         coin_info.supply = coin_info.supply + (amount as u128);
 
@@ -394,7 +500,7 @@ module nova_std::coin {
         from: &signer,
         to: address,
         amount: u64,
-    ) acquires CoinStore {
+    ) acquires CoinStore, CoinDenom {
         let coin = withdraw<CoinType>(from, amount);
         deposit(to, coin);
     }
@@ -405,17 +511,23 @@ module nova_std::coin {
     }
 
     /// Withdraw specifed `amount` of coin `CoinType` from the signing account.
+    /// Mapped coins are withdrawn from the signer's bank balance instead.
     public fun withdraw<CoinType>(
         account: &signer,
         amount: u64,
-    ): Coin<CoinType> acquires CoinStore {
+    ): Coin<CoinType> acquires CoinStore, CoinDenom {
         withdraw_internal<CoinType>(signer::address_of(account), amount)
     }
 
     fun withdraw_internal<CoinType>(
         account_addr: address,
         amount: u64,
-    ): Coin<CoinType> acquires CoinStore {
+    ): Coin<CoinType> acquires CoinStore, CoinDenom {
+        let mapped = is_mapped<CoinType>();
+        if (mapped) {
+            register_mapped_if_not_exists<CoinType>(account_addr);
+        };
+
         assert!(
             is_account_registered<CoinType>(account_addr),
             error::not_found(ECOIN_STORE_NOT_PUBLISHED),
@@ -435,7 +547,12 @@ module nova_std::coin {
             },
         );
 
-        extract(&mut coin_store.coin, amount)
+        if (mapped) {
+            bank::withdraw(&denom<CoinType>(), account_addr, amount);
+            Coin<CoinType> { value: amount }
+        } else {
+            extract(&mut coin_store.coin, amount)
+        }
     }
 
     /// Create a new `Coin<CoinType>` with a value of `0`.
@@ -501,7 +618,7 @@ module nova_std::coin {
         source: &signer,
         destination: &signer,
         amount: u64
-    ) acquires CoinInfo, CoinStore {
+    ) acquires CoinInfo, CoinStore, CoinDenom {
         let (burn_cap, freeze_cap, mint_cap) = initialize_and_register_fake_money(source, 18);
 
         register<FakeMoney>(destination);
@@ -518,7 +635,7 @@ module nova_std::coin {
     public entry fun end_to_end_native_coins(
         source: signer,
         destination: signer,
-    ) acquires CoinInfo, CoinStore {
+    ) acquires CoinInfo, CoinStore, CoinDenom {
         let source_addr = signer::address_of(&source);
         let destination_addr = signer::address_of(&destination);
 
@@ -560,7 +677,7 @@ module nova_std::coin {
     public entry fun end_to_end(
         source: signer,
         destination: signer,
-    ) acquires CoinInfo, CoinStore {
+    ) acquires CoinInfo, CoinStore, CoinDenom {
         let source_addr = signer::address_of(&source);
         let destination_addr = signer::address_of(&destination);
 
@@ -581,10 +698,15 @@ module nova_std::coin {
         assert!(symbol<FakeMoney>() == symbol, 2);
         assert!(decimals<FakeMoney>() == 18, 3);
 
+        assert!(!account::exists_at(destination_addr), 9);
+
         let coins_minted = mint<FakeMoney>(100, &mint_cap);
         deposit(source_addr, coins_minted);
         transfer<FakeMoney>(&source, destination_addr, 50);
 
+        // the first deposit creates the account
+        assert!(account::exists_at(destination_addr), 10);
+
         assert!(balance<FakeMoney>(source_addr) == 50, 4);
         assert!(balance<FakeMoney>(destination_addr) == 50, 5);
         assert!(supply<FakeMoney>() == 100, 6);
@@ -606,7 +728,7 @@ module nova_std::coin {
     public entry fun fail_native_mint_to(
         source: signer,
         destination: signer,
-    ) acquires CoinInfo, CoinStore {
+    ) acquires CoinInfo, CoinStore, CoinDenom {
         let destination_addr = signer::address_of(&destination);
 
         let name = string::utf8(b"Fake money");
@@ -639,7 +761,7 @@ module nova_std::coin {
     public entry fun fail_native_burn_from(
         source: signer,
         destination: signer,
-    ) acquires CoinInfo, CoinStore {
+    ) acquires CoinInfo, CoinStore, CoinDenom {
         let destination_addr = signer::address_of(&destination);
 
         let name = string::utf8(b"Fake money");
@@ -690,7 +812,7 @@ module nova_std::coin {
     public entry fun fail_transfer(
         source: signer,
         destination: signer,
-    ) acquires CoinInfo, CoinStore {
+    ) acquires CoinInfo, CoinStore, CoinDenom {
         let source_addr = signer::address_of(&source);
         let destination_addr = signer::address_of(&destination);
 
@@ -711,7 +833,7 @@ module nova_std::coin {
     #[test(source = @0x1, destination = @0x2)]
     public entry fun test_burn_from_with_capability(
         source: signer,
-    ) acquires CoinInfo, CoinStore {
+    ) acquires CoinInfo, CoinStore, CoinDenom {
         let source_addr = signer::address_of(&source);
         let (burn_cap, freeze_cap, mint_cap) = initialize_and_register_fake_money(&source, 1);
         let coins_minted = mint<FakeMoney>(100, &mint_cap);
@@ -749,7 +871,7 @@ module nova_std::coin {
     #[test(source = @0x1)]
     public entry fun test_extract(
         source: signer,
-    ) acquires CoinInfo, CoinStore {
+    ) acquires CoinInfo, CoinStore, CoinDenom {
         let source_addr = signer::address_of(&source);
         let (burn_cap, freeze_cap, mint_cap) = initialize_and_register_fake_money(&source, 1);
 
@@ -793,7 +915,7 @@ module nova_std::coin {
     }
 
     #[test(account = @0x1)]
-    public entry fun burn_frozen(account: signer) acquires CoinInfo, CoinStore {
+    public entry fun burn_frozen(account: signer) acquires CoinInfo, CoinStore, CoinDenom {
         let account_addr = signer::address_of(&account);
         let (burn_cap, freeze_cap, mint_cap) = initialize_and_register_fake_money(&account, 18);
 
@@ -812,7 +934,7 @@ module nova_std::coin {
 
     #[test(account = @0x1)]
     #[expected_failure(abort_code = 0x5000A)]
-    public entry fun withdraw_frozen(account: signer) acquires CoinStore {
+    public entry fun withdraw_frozen(account: signer) acquires CoinStore, CoinDenom {
         let account_addr = signer::address_of(&account);
         let (burn_cap, freeze_cap, mint_cap) = initialize_and_register_fake_money(&account, 18);
 
@@ -829,7 +951,7 @@ module nova_std::coin {
 
     #[test(account = @0x1)]
     #[expected_failure(abort_code = 0x5000A)]
-    public entry fun deposit_frozen(account: signer) acquires CoinInfo, CoinStore {
+    public entry fun deposit_frozen(account: signer) acquires CoinInfo, CoinStore, CoinDenom {
         let account_addr = signer::address_of(&account);
         let (burn_cap, freeze_cap, mint_cap) = initialize_and_register_fake_money(&account, 18);
 
@@ -858,4 +980,91 @@ module nova_std::coin {
             mint_cap,
         });
     }
+
+    #[test(chain = @0x1, destination = @0x2)]
+    public entry fun end_to_end_mapped_coin(
+        chain: signer,
+        destination: signer,
+    ) acquires CoinInfo, CoinStore, CoinDenom, DenomRegistry {
+        let destination_addr = signer::address_of(&destination);
+        let denom = string::utf8(b"ufake");
+
+        let (burn_cap, freeze_cap, mint_cap) = initialize_fake_money(&chain, 6);
+        assert!(!is_mapped<FakeMoney>(), 0);
+
+        native_register_denom<FakeMoney>(&chain, denom);
+        assert!(is_mapped<FakeMoney>(), 1);
+        assert!(denom<FakeMoney>() == denom, 2);
+        assert!(coin_type_of_denom(denom) == type_info::type_name<FakeMoney>(), 3);
+
+        // balances and supply are read from the bank
+        assert!(balance<FakeMoney>(destination_addr) == 0, 4);
+        assert!(supply<FakeMoney>() == 0, 5);
+
+        // no CoinStore is needed for a mapped coin
+        transfer<FakeMoney>(&chain, destination_addr, 0);
+        assert!(account::exists_at(destination_addr), 6);
+
+        move_to(&chain, FakeMoneyCapabilities {
+            burn_cap,
+            freeze_cap,
+            mint_cap,
+        });
+    }
+
+    #[test(chain = @0x1)]
+    #[expected_failure(abort_code = 0x8000F)]
+    public entry fun fail_register_denom_twice(chain: signer) acquires CoinInfo, DenomRegistry {
+        initialize_with_integer(&chain);
+        native_register_denom<FakeMoney>(&chain, string::utf8(b"ufake"));
+        native_register_denom<FakeMoney>(&chain, string::utf8(b"ufake2"));
+    }
+
+    #[test(chain = @0x1)]
+    #[expected_failure(abort_code = 0x30013)]
+    public entry fun fail_register_denom_with_supply(chain: signer) acquires CoinInfo, DenomRegistry, CoinStore, CoinDenom {
+        let (burn_cap, freeze_cap, mint_cap) = initialize_and_register_fake_money(&chain, 6);
+        let coins_minted = mint<FakeMoney>(100, &mint_cap);
+        deposit(signer::address_of(&chain), coins_minted);
+
+        native_register_denom<FakeMoney>(&chain, string::utf8(b"ufake"));
+
+        move_to(&chain, FakeMoneyCapabilities {
+            burn_cap,
+            freeze_cap,
+            mint_cap,
+        });
+    }
+
+    #[test(chain = @0x1)]
+    #[expected_failure(abort_code = 0x30012)]
+    public entry fun fail_mint_mapped_coin(chain: signer) acquires CoinInfo, DenomRegistry {
+        let (burn_cap, freeze_cap, mint_cap) = initialize_fake_money(&chain, 6);
+        native_register_denom<FakeMoney>(&chain, string::utf8(b"ufake"));
+
+        let coins_minted = mint<FakeMoney>(100, &mint_cap);
+        burn(coins_minted, &burn_cap);
+
+        move_to(&chain, FakeMoneyCapabilities {
+            burn_cap,
+            freeze_cap,
+            mint_cap,
+        });
+    }
+
+    #[test(chain = @0x1)]
+    #[expected_failure(abort_code = 0x5)]
+    public entry fun fail_withdraw_mapped_coin(chain: signer) acquires CoinInfo, CoinStore, CoinDenom, DenomRegistry {
+        initialize_with_integer(&chain);
+        native_register_denom<FakeMoney>(&chain, string::utf8(b"ufake"));
+
+        // the bank balance of the chain is empty
+        transfer<FakeMoney>(&chain, @0x2, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x60011)]
+    public entry fun fail_coin_type_of_unknown_denom() acquires DenomRegistry {
+        coin_type_of_denom(string::utf8(b"ufake"));
+    }
 }
@@ -0,0 +1,677 @@
+/// Fungible assets which are created at runtime from a metadata record instead of a
+/// published `CoinType`. An asset is identified by the address of its metadata, which is
+/// a resource account derived from the creator and the symbol of the asset.
+module nova_std::fungible_asset {
+    use std::error;
+    use std::event::{Self, EventHandle};
+    use std::option::{Self, Option};
+    use std::signer;
+    use std::string::{Self, String};
+    use std::vector;
+
+    use nova_std::account::{Self, create_signer_for_friend};
+    use nova_std::coin::{Self, Coin};
+    use nova_std::table::{Self, Table};
+    use nova_std::type_info;
+
+    //
+    // Errors.
+    //
+
+    /// Metadata of the fungible asset doesn't exist
+    const EMETADATA_NOT_FOUND: u64 = 1;
+
+    /// Minting would exceed the maximum supply of the fungible asset
+    const EMAX_SUPPLY_EXCEEDED: u64 = 2;
+
+    /// Not enough balance to complete the operation
+    const EINSUFFICIENT_BALANCE: u64 = 3;
+
+    /// The fungible asset or the ref belongs to another metadata
+    const EMETADATA_MISMATCH: u64 = 4;
+
+    /// The primary store is frozen. Assets cannot be deposited or withdrawn
+    const ESTORE_FROZEN: u64 = 5;
+
+    /// The account doesn't have a primary store of the fungible asset
+    const ESTORE_NOT_FOUND: u64 = 6;
+
+    /// Name of the fungible asset is too long
+    const ENAME_TOO_LONG: u64 = 7;
+
+    /// Symbol of the fungible asset is too long
+    const ESYMBOL_TOO_LONG: u64 = 8;
+
+    /// URI of the fungible asset is too long
+    const EURI_TOO_LONG: u64 = 9;
+
+    /// Decimals of the fungible asset is too large
+    const EDECIMALS_TOO_LARGE: u64 = 10;
+
+    /// Cannot destroy a non-zero fungible asset
+    const EDESTRUCTION_OF_NONZERO_ASSET: u64 = 11;
+
+    /// `CoinType` hasn't been converted to a fungible asset yet
+    const ECOIN_NOT_PAIRED: u64 = 12;
+
+    //
+    // Constants
+    //
+
+    const MAX_NAME_LENGTH: u64 = 32;
+    const MAX_SYMBOL_LENGTH: u64 = 10;
+    const MAX_URI_LENGTH: u64 = 512;
+    const MAX_DECIMALS: u8 = 32;
+
+    /// Seed prefix of the metadata paired with a coin type. It is longer than
+    /// `MAX_SYMBOL_LENGTH`, so it never collides with the seed of a regular asset.
+    const PAIRED_COIN_SEED_PREFIX: vector<u8> = b"fungible_asset::paired_coin::";
+
+    /// Core data structures
+
+    /// Metadata of a fungible asset. Stored on the metadata account.
+    struct Metadata has key {
+        name: String,
+        /// Symbol of the asset, usually a shorter version of the name.
+        symbol: String,
+        /// Number of decimals used to get its user representation.
+        decimals: u8,
+        icon_uri: String,
+        project_uri: String,
+    }
+
+    /// Supply of a fungible asset. Stored on the metadata account.
+    struct Supply has key {
+        current: u128,
+        /// No limit if `none`.
+        maximum: Option<u128>,
+    }
+
+    /// An amount of a fungible asset in flight. It has no abilities, so it must
+    /// be deposited into a store or burned before the transaction ends.
+    struct FungibleAsset {
+        metadata: address,
+        amount: u64,
+    }
+
+    /// Balance of one fungible asset in an account.
+    struct FungibleStore has store {
+        balance: u64,
+        frozen: bool,
+    }
+
+    /// The primary stores of an account, keyed by metadata address, and the associated event handles.
+    struct PrimaryStores has key {
+        stores: Table<address, FungibleStore>,
+        deposit_events: EventHandle<DepositEvent>,
+        withdraw_events: EventHandle<WithdrawEvent>,
+    }
+
+    /// Event emitted when some amount of a fungible asset is deposited into an account.
+    struct DepositEvent has drop, store {
+        metadata: address,
+        amount: u64,
+    }
+
+    /// Event emitted when some amount of a fungible asset is withdrawn from an account.
+    struct WithdrawEvent has drop, store {
+        metadata: address,
+        amount: u64,
+    }
+
+    /// Ref required to mint the fungible asset.
+    struct MintRef has drop, store {
+        metadata: address,
+    }
+
+    /// Ref required to move the fungible asset regardless of the frozen flag, and to freeze stores.
+    struct TransferRef has drop, store {
+        metadata: address,
+    }
+
+    /// Ref required to burn the fungible asset.
+    struct BurnRef has drop, store {
+        metadata: address,
+    }
+
+    /// Coins converted into the paired fungible asset. Stored on the paired metadata account.
+    struct CoinEscrow<phantom CoinType> has key {
+        coin: Coin<CoinType>,
+    }
+
+    //
+    // Metadata
+    //
+
+    /// Create a new fungible asset and return the refs which allow to mint, transfer
+    /// and burn it. The metadata is published at `metadata_address(creator, symbol)`.
+    public fun create(
+        creator: &signer,
+        maximum_supply: Option<u128>,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        icon_uri: String,
+        project_uri: String,
+    ): (MintRef, TransferRef, BurnRef) {
+        let (metadata_signer, _) = account::create_resource_account(creator, *string::bytes(&symbol));
+        publish_metadata(&metadata_signer, maximum_supply, name, symbol, decimals, icon_uri, project_uri);
+
+        let metadata = signer::address_of(&metadata_signer);
+        (MintRef { metadata }, TransferRef { metadata }, BurnRef { metadata })
+    }
+
+    fun publish_metadata(
+        metadata_signer: &signer,
+        maximum_supply: Option<u128>,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        icon_uri: String,
+        project_uri: String,
+    ) {
+        assert!(string::length(&name) <= MAX_NAME_LENGTH, error::invalid_argument(ENAME_TOO_LONG));
+        assert!(string::length(&symbol) <= MAX_SYMBOL_LENGTH, error::invalid_argument(ESYMBOL_TOO_LONG));
+        assert!(decimals <= MAX_DECIMALS, error::invalid_argument(EDECIMALS_TOO_LARGE));
+        assert!(string::length(&icon_uri) <= MAX_URI_LENGTH, error::invalid_argument(EURI_TOO_LONG));
+        assert!(string::length(&project_uri) <= MAX_URI_LENGTH, error::invalid_argument(EURI_TOO_LONG));
+
+        move_to(metadata_signer, Metadata {
+            name,
+            symbol,
+            decimals,
+            icon_uri,
+            project_uri,
+        });
+        move_to(metadata_signer, Supply {
+            current: 0,
+            maximum: maximum_supply,
+        });
+    }
+
+    /// Return the metadata address of the asset created by `creator` with `symbol`.
+    public fun metadata_address(creator: address, symbol: String): address {
+        account::create_resource_address(&creator, *string::bytes(&symbol))
+    }
+
+    /// Returns `true` if `metadata` is the metadata address of a fungible asset.
+    public fun is_metadata(metadata: address): bool {
+        exists<Metadata>(metadata)
+    }
+
+    fun borrow_metadata(metadata: address): &Metadata acquires Metadata {
+        assert!(is_metadata(metadata), error::not_found(EMETADATA_NOT_FOUND));
+        borrow_global<Metadata>(metadata)
+    }
+
+    /// Returns the name of the fungible asset.
+    public fun name(metadata: address): String acquires Metadata {
+        borrow_metadata(metadata).name
+    }
+
+    /// Returns the symbol of the fungible asset.
+    public fun symbol(metadata: address): String acquires Metadata {
+        borrow_metadata(metadata).symbol
+    }
+
+    /// Returns the number of decimals used to get its user representation.
+    public fun decimals(metadata: address): u8 acquires Metadata {
+        borrow_metadata(metadata).decimals
+    }
+
+    /// Returns the icon URI of the fungible asset.
+    public fun icon_uri(metadata: address): String acquires Metadata {
+        borrow_metadata(metadata).icon_uri
+    }
+
+    /// Returns the project URI of the fungible asset.
+    public fun project_uri(metadata: address): String acquires Metadata {
+        borrow_metadata(metadata).project_uri
+    }
+
+    /// Returns the amount of the fungible asset in existence.
+    public fun supply(metadata: address): u128 acquires Supply {
+        assert!(is_metadata(metadata), error::not_found(EMETADATA_NOT_FOUND));
+        borrow_global<Supply>(metadata).current
+    }
+
+    /// Returns the maximum supply of the fungible asset, `none` if unlimited.
+    public fun maximum(metadata: address): Option<u128> acquires Supply {
+        assert!(is_metadata(metadata), error::not_found(EMETADATA_NOT_FOUND));
+        borrow_global<Supply>(metadata).maximum
+    }
+
+    //
+    // Fungible asset values
+    //
+
+    /// Returns the metadata address of `fa`.
+    public fun metadata_from_asset(fa: &FungibleAsset): address {
+        fa.metadata
+    }
+
+    /// Returns the amount of `fa`.
+    public fun amount(fa: &FungibleAsset): u64 {
+        fa.amount
+    }
+
+    /// Create a zero-amount fungible asset of `metadata`.
+    public fun zero(metadata: address): FungibleAsset {
+        assert!(is_metadata(metadata), error::not_found(EMETADATA_NOT_FOUND));
+        FungibleAsset { metadata, amount: 0 }
+    }
+
+    /// Extracts `amount` from `fa`, where the original asset is modified in place.
+    public fun extract(fa: &mut FungibleAsset, amount: u64): FungibleAsset {
+        assert!(fa.amount >= amount, error::invalid_argument(EINSUFFICIENT_BALANCE));
+        fa.amount = fa.amount - amount;
+        FungibleAsset { metadata: fa.metadata, amount }
+    }
+
+    /// "Merges" `src` into `dst`. Both must be of the same metadata.
+    public fun merge(dst: &mut FungibleAsset, src: FungibleAsset) {
+        let FungibleAsset { metadata, amount } = src;
+        assert!(dst.metadata == metadata, error::invalid_argument(EMETADATA_MISMATCH));
+        dst.amount = dst.amount + amount;
+    }
+
+    /// Destroys a zero-amount fungible asset.
+    public fun destroy_zero(fa: FungibleAsset) {
+        let FungibleAsset { metadata: _, amount } = fa;
+        assert!(amount == 0, error::invalid_argument(EDESTRUCTION_OF_NONZERO_ASSET));
+    }
+
+    //
+    // Refs
+    //
+
+    /// Returns the metadata address the mint ref belongs to.
+    public fun mint_ref_metadata(ref: &MintRef): address {
+        ref.metadata
+    }
+
+    /// Returns the metadata address the transfer ref belongs to.
+    public fun transfer_ref_metadata(ref: &TransferRef): address {
+        ref.metadata
+    }
+
+    /// Returns the metadata address the burn ref belongs to.
+    public fun burn_ref_metadata(ref: &BurnRef): address {
+        ref.metadata
+    }
+
+    /// Mint `amount` of the fungible asset with the mint ref.
+    public fun mint(ref: &MintRef, amount: u64): FungibleAsset acquires Supply {
+        mint_internal(ref.metadata, amount)
+    }
+
+    /// Mint `amount` of the fungible asset into the primary store of `owner`.
+    public fun mint_to(ref: &MintRef, owner: address, amount: u64) acquires Supply, PrimaryStores {
+        deposit_internal(owner, mint(ref, amount), true);
+    }
+
+    /// Burn `fa` with the burn ref.
+    public fun burn(ref: &BurnRef, fa: FungibleAsset) acquires Supply {
+        assert!(ref.metadata == fa.metadata, error::invalid_argument(EMETADATA_MISMATCH));
+        burn_internal(fa);
+    }
+
+    /// Burn `amount` of the fungible asset from the primary store of `owner`.
+    /// This bypasses the frozen flag of the store.
+    public fun burn_from(ref: &BurnRef, owner: address, amount: u64) acquires Supply, PrimaryStores {
+        let fa = withdraw_internal(owner, ref.metadata, amount, false);
+        burn(ref, fa);
+    }
+
+    /// Freeze or unfreeze the primary store of `owner`.
+    public fun set_frozen_flag(ref: &TransferRef, owner: address, frozen: bool) acquires PrimaryStores {
+        ensure_primary_store(owner, ref.metadata);
+
+        let stores = &mut borrow_global_mut<PrimaryStores>(owner).stores;
+        table::borrow_mut(stores, ref.metadata).frozen = frozen;
+    }
+
+    /// Withdraw `amount` from the primary store of `owner`, ignoring the frozen flag.
+    public fun withdraw_with_ref(ref: &TransferRef, owner: address, amount: u64): FungibleAsset acquires PrimaryStores {
+        withdraw_internal(owner, ref.metadata, amount, false)
+    }
+
+    /// Deposit `fa` into the primary store of `owner`, ignoring the frozen flag.
+    public fun deposit_with_ref(ref: &TransferRef, owner: address, fa: FungibleAsset) acquires PrimaryStores {
+        assert!(ref.metadata == fa.metadata, error::invalid_argument(EMETADATA_MISMATCH));
+        deposit_internal(owner, fa, false);
+    }
+
+    /// Transfer `amount` from `from` to `to`, ignoring the frozen flags.
+    public fun transfer_with_ref(ref: &TransferRef, from: address, to: address, amount: u64) acquires PrimaryStores {
+        let fa = withdraw_with_ref(ref, from, amount);
+        deposit_with_ref(ref, to, fa);
+    }
+
+    fun mint_internal(metadata: address, amount: u64): FungibleAsset acquires Supply {
+        assert!(is_metadata(metadata), error::not_found(EMETADATA_NOT_FOUND));
+
+        let supply = borrow_global_mut<Supply>(metadata);
+        if (option::is_some(&supply.maximum)) {
+            let maximum = *option::borrow(&supply.maximum);
+            assert!(maximum - supply.current >= (amount as u128), error::out_of_range(EMAX_SUPPLY_EXCEEDED));
+        };
+        supply.current = supply.current + (amount as u128);
+
+        FungibleAsset { metadata, amount }
+    }
+
+    fun burn_internal(fa: FungibleAsset) acquires Supply {
+        let FungibleAsset { metadata, amount } = fa;
+
+        let supply = borrow_global_mut<Supply>(metadata);
+        supply.current = supply.current - (amount as u128);
+    }
+
+    //
+    // Primary stores
+    //
+
+    /// Returns `true` if `owner` has a primary store of `metadata`.
+    public fun primary_store_exists(owner: address, metadata: address): bool acquires PrimaryStores {
+        exists<PrimaryStores>(owner) && table::contains(&borrow_global<PrimaryStores>(owner).stores, metadata)
+    }
+
+    /// Returns the balance of `owner` for `metadata`, `0` if the store doesn't exist.
+    public fun balance(owner: address, metadata: address): u64 acquires PrimaryStores {
+        if (!primary_store_exists(owner, metadata)) {
+            return 0
+        };
+
+        table::borrow(&borrow_global<PrimaryStores>(owner).stores, metadata).balance
+    }
+
+    /// Returns `true` if the primary store of `owner` for `metadata` is frozen.
+    public fun is_frozen(owner: address, metadata: address): bool acquires PrimaryStores {
+        primary_store_exists(owner, metadata)
+            && table::borrow(&borrow_global<PrimaryStores>(owner).stores, metadata).frozen
+    }
+
+    /// Withdraw `amount` of `metadata` from the primary store of the signing account.
+    public fun withdraw(owner: &signer, metadata: address, amount: u64): FungibleAsset acquires PrimaryStores {
+        withdraw_internal(signer::address_of(owner), metadata, amount, true)
+    }
+
+    /// Deposit `fa` into the primary store of `owner`, creating the store if needed.
+    public fun deposit(owner: address, fa: FungibleAsset) acquires PrimaryStores {
+        deposit_internal(owner, fa, true);
+    }
+
+    /// Transfers `amount` of `metadata` from `sender` to `recipient`.
+    public entry fun transfer(
+        sender: &signer,
+        metadata: address,
+        recipient: address,
+        amount: u64,
+    ) acquires PrimaryStores {
+        let fa = withdraw(sender, metadata, amount);
+        deposit(recipient, fa);
+    }
+
+    fun ensure_primary_store(owner: address, metadata: address) acquires PrimaryStores {
+        assert!(is_metadata(metadata), error::not_found(EMETADATA_NOT_FOUND));
+
+        if (!exists<PrimaryStores>(owner)) {
+            // the first deposit makes the account known to Move
+            account::create_account_if_not_exists(owner);
+
+            let owner_signer = create_signer_for_friend(owner);
+            move_to(&owner_signer, PrimaryStores {
+                stores: table::new(&owner_signer),
+                deposit_events: event::new_event_handle<DepositEvent>(&owner_signer),
+                withdraw_events: event::new_event_handle<WithdrawEvent>(&owner_signer),
+            });
+        };
+
+        let stores = &mut borrow_global_mut<PrimaryStores>(owner).stores;
+        if (!table::contains(stores, metadata)) {
+            table::add(stores, metadata, FungibleStore { balance: 0, frozen: false });
+        };
+    }
+
+    fun withdraw_internal(
+        owner: address,
+        metadata: address,
+        amount: u64,
+        check_frozen: bool,
+    ): FungibleAsset acquires PrimaryStores {
+        assert!(primary_store_exists(owner, metadata), error::not_found(ESTORE_NOT_FOUND));
+
+        let primary_stores = borrow_global_mut<PrimaryStores>(owner);
+        let store = table::borrow_mut(&mut primary_stores.stores, metadata);
+        assert!(!check_frozen || !store.frozen, error::permission_denied(ESTORE_FROZEN));
+        assert!(store.balance >= amount, error::invalid_argument(EINSUFFICIENT_BALANCE));
+        store.balance = store.balance - amount;
+
+        event::emit_event<WithdrawEvent>(
+            &mut primary_stores.withdraw_events,
+            WithdrawEvent { metadata, amount },
+        );
+
+        FungibleAsset { metadata, amount }
+    }
+
+    fun deposit_internal(owner: address, fa: FungibleAsset, check_frozen: bool) acquires PrimaryStores {
+        let FungibleAsset { metadata, amount } = fa;
+        ensure_primary_store(owner, metadata);
+
+        let primary_stores = borrow_global_mut<PrimaryStores>(owner);
+        let store = table::borrow_mut(&mut primary_stores.stores, metadata);
+        assert!(!check_frozen || !store.frozen, error::permission_denied(ESTORE_FROZEN));
+        store.balance = store.balance + amount;
+
+        event::emit_event<DepositEvent>(
+            &mut primary_stores.deposit_events,
+            DepositEvent { metadata, amount },
+        );
+    }
+
+    //
+    // Migration from coins
+    //
+
+    /// Return the metadata address of the fungible asset paired with `CoinType`.
+    public fun paired_metadata<CoinType>(): address {
+        account::create_resource_address(&@nova_std, paired_coin_seed<CoinType>())
+    }
+
+    /// Returns `true` if `CoinType` has been converted to a fungible asset before.
+    public fun is_coin_paired<CoinType>(): bool {
+        exists<CoinEscrow<CoinType>>(paired_metadata<CoinType>())
+    }
+
+    /// Convert `coin` into the fungible asset paired with `CoinType`, creating the paired
+    /// metadata on first use. The coin is kept in escrow, so the conversion can be reverted.
+    public fun coin_to_fungible_asset<CoinType>(coin: Coin<CoinType>): FungibleAsset acquires Supply, CoinEscrow {
+        let metadata = ensure_paired_metadata<CoinType>();
+        let amount = coin::value(&coin);
+        coin::merge(&mut borrow_global_mut<CoinEscrow<CoinType>>(metadata).coin, coin);
+
+        mint_internal(metadata, amount)
+    }
+
+    /// Convert `fa` back into the coins held in escrow for its paired `CoinType`.
+    public fun fungible_asset_to_coin<CoinType>(fa: FungibleAsset): Coin<CoinType> acquires Supply, CoinEscrow {
+        assert!(is_coin_paired<CoinType>(), error::not_found(ECOIN_NOT_PAIRED));
+
+        let metadata = paired_metadata<CoinType>();
+        assert!(fa.metadata == metadata, error::invalid_argument(EMETADATA_MISMATCH));
+        let amount = fa.amount;
+        burn_internal(fa);
+
+        coin::extract(&mut borrow_global_mut<CoinEscrow<CoinType>>(metadata).coin, amount)
+    }
+
+    /// Move `amount` of `CoinType` from the coin store of the signing account into
+    /// its primary store of the paired fungible asset.
+    public entry fun migrate_from_coin<CoinType>(account: &signer, amount: u64) acquires Supply, CoinEscrow, PrimaryStores {
+        let coin = coin::withdraw<CoinType>(account, amount);
+        deposit(signer::address_of(account), coin_to_fungible_asset(coin));
+    }
+
+    /// Move `amount` of the fungible asset paired with `CoinType` from the primary store
+    /// of the signing account back into its coin store.
+    public entry fun migrate_to_coin<CoinType>(account: &signer, amount: u64) acquires Supply, CoinEscrow, PrimaryStores {
+        let fa = withdraw(account, paired_metadata<CoinType>(), amount);
+        coin::deposit(signer::address_of(account), fungible_asset_to_coin<CoinType>(fa));
+    }
+
+    fun paired_coin_seed<CoinType>(): vector<u8> {
+        let seed = PAIRED_COIN_SEED_PREFIX;
+        vector::append(&mut seed, *string::bytes(&type_info::type_name<CoinType>()));
+        seed
+    }
+
+    fun ensure_paired_metadata<CoinType>(): address {
+        let metadata = paired_metadata<CoinType>();
+        if (!exists<CoinEscrow<CoinType>>(metadata)) {
+            let nova_std = create_signer_for_friend(@nova_std);
+            let (metadata_signer, _) = account::create_resource_account(&nova_std, paired_coin_seed<CoinType>());
+            publish_metadata(
+                &metadata_signer,
+                option::none(),
+                coin::name<CoinType>(),
+                coin::symbol<CoinType>(),
+                coin::decimals<CoinType>(),
+                string::utf8(b""),
+                string::utf8(b""),
+            );
+            move_to(&metadata_signer, CoinEscrow<CoinType> { coin: coin::zero<CoinType>() });
+        };
+
+        metadata
+    }
+
+    #[test_only]
+    struct TestCoin { }
+
+    #[test_only]
+    struct TestRefs has key {
+        mint_ref: MintRef,
+        transfer_ref: TransferRef,
+        burn_ref: BurnRef,
+    }
+
+    #[test_only]
+    fun create_test_asset(creator: &signer, maximum_supply: Option<u128>): address {
+        let (mint_ref, transfer_ref, burn_ref) = create(
+            creator,
+            maximum_supply,
+            string::utf8(b"Test asset"),
+            string::utf8(b"TST"),
+            6,
+            string::utf8(b"https://example.com/icon.png"),
+            string::utf8(b"https://example.com"),
+        );
+        let metadata = mint_ref_metadata(&mint_ref);
+        move_to(creator, TestRefs { mint_ref, transfer_ref, burn_ref });
+        metadata
+    }
+
+    #[test(creator = @0x2, recipient = @0x3)]
+    fun end_to_end(creator: signer, recipient: signer) acquires Metadata, Supply, PrimaryStores, TestRefs {
+        let creator_addr = signer::address_of(&creator);
+        let recipient_addr = signer::address_of(&recipient);
+
+        let metadata = create_test_asset(&creator, option::none());
+        assert!(metadata == metadata_address(creator_addr, string::utf8(b"TST")), 0);
+        assert!(name(metadata) == string::utf8(b"Test asset"), 1);
+        assert!(symbol(metadata) == string::utf8(b"TST"), 2);
+        assert!(decimals(metadata) == 6, 3);
+        assert!(supply(metadata) == 0, 4);
+
+        let refs = borrow_global<TestRefs>(creator_addr);
+        mint_to(&refs.mint_ref, creator_addr, 100);
+        assert!(supply(metadata) == 100, 5);
+
+        transfer(&creator, metadata, recipient_addr, 40);
+        assert!(balance(creator_addr, metadata) == 60, 6);
+        assert!(balance(recipient_addr, metadata) == 40, 7);
+        assert!(account::exists_at(recipient_addr), 8);
+
+        burn_from(&refs.burn_ref, recipient_addr, 10);
+        assert!(balance(recipient_addr, metadata) == 30, 9);
+        assert!(supply(metadata) == 90, 10);
+
+        let fa = withdraw(&recipient, metadata, 30);
+        let half = extract(&mut fa, 15);
+        merge(&mut fa, half);
+        assert!(amount(&fa) == 30, 11);
+        burn(&refs.burn_ref, fa);
+        assert!(supply(metadata) == 60, 12);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x20002)]
+    fun fail_mint_over_maximum(creator: signer) acquires Supply, PrimaryStores, TestRefs {
+        create_test_asset(&creator, option::some(100));
+
+        let refs = borrow_global<TestRefs>(signer::address_of(&creator));
+        mint_to(&refs.mint_ref, @0x3, 100);
+        mint_to(&refs.mint_ref, @0x3, 1);
+    }
+
+    #[test(creator = @0x2, recipient = @0x3)]
+    #[expected_failure(abort_code = 0x50005)]
+    fun fail_withdraw_frozen(creator: signer, recipient: signer) acquires Supply, PrimaryStores, TestRefs {
+        let metadata = create_test_asset(&creator, option::none());
+        let recipient_addr = signer::address_of(&recipient);
+
+        let refs = borrow_global<TestRefs>(signer::address_of(&creator));
+        mint_to(&refs.mint_ref, recipient_addr, 100);
+        set_frozen_flag(&refs.transfer_ref, recipient_addr, true);
+        assert!(is_frozen(recipient_addr, metadata), 0);
+
+        // the transfer ref ignores the frozen flag
+        transfer_with_ref(&refs.transfer_ref, recipient_addr, @0x4, 10);
+        assert!(balance(@0x4, metadata) == 10, 1);
+
+        transfer(&recipient, metadata, @0x4, 10);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x80003)]
+    fun fail_create_same_symbol_twice(creator: signer) acquires TestRefs {
+        create_test_asset(&creator, option::none());
+        let TestRefs { mint_ref: _, transfer_ref: _, burn_ref: _ } = move_from<TestRefs>(signer::address_of(&creator));
+        create_test_asset(&creator, option::none());
+    }
+
+    #[test(nova_std = @nova_std, account = @0x2)]
+    fun test_migrate_coin(nova_std: signer, account: signer) acquires Metadata, Supply, CoinEscrow, PrimaryStores {
+        let account_addr = signer::address_of(&account);
+
+        let (burn_cap, freeze_cap, mint_cap) = coin::initialize<TestCoin>(
+            &nova_std,
+            string::utf8(b"Test coin"),
+            string::utf8(b"TCN"),
+            8,
+        );
+        coin::register<TestCoin>(&account);
+        coin::deposit(account_addr, coin::mint<TestCoin>(100, &mint_cap));
+        assert!(!is_coin_paired<TestCoin>(), 0);
+
+        migrate_from_coin<TestCoin>(&account, 70);
+        let metadata = paired_metadata<TestCoin>();
+        assert!(is_coin_paired<TestCoin>(), 1);
+        assert!(symbol(metadata) == string::utf8(b"TCN"), 2);
+        assert!(decimals(metadata) == 8, 3);
+        assert!(coin::balance<TestCoin>(account_addr) == 30, 4);
+        assert!(balance(account_addr, metadata) == 70, 5);
+        assert!(supply(metadata) == 70, 6);
+
+        migrate_to_coin<TestCoin>(&account, 20);
+        assert!(coin::balance<TestCoin>(account_addr) == 50, 7);
+        assert!(balance(account_addr, metadata) == 50, 8);
+        assert!(supply(metadata) == 50, 9);
+
+        coin::destroy_burn_cap(burn_cap);
+        coin::destroy_freeze_cap(freeze_cap);
+        coin::destroy_mint_cap(mint_cap);
+    }
+}
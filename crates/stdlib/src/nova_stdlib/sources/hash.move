@@ -0,0 +1,35 @@
+/// Cryptographic hashes which are not provided by `std::hash`.
+module nova_std::hash {
+    /// Returns the keccak256 (Ethereum flavoured sha3) digest of `data`.
+    native public fun keccak256(data: vector<u8>): vector<u8>;
+
+    /// Returns the sha2-512 digest of `data`.
+    native public fun sha2_512(data: vector<u8>): vector<u8>;
+
+    /// Returns the ripemd160 digest of `data`.
+    native public fun ripemd160(data: vector<u8>): vector<u8>;
+
+    /// Returns the 32 bytes blake2b digest of `data`.
+    native public fun blake2b_256(data: vector<u8>): vector<u8>;
+
+    #[test]
+    fun test_keccak256() {
+        assert!(keccak256(b"") == x"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470", 0);
+        assert!(keccak256(b"abc") == x"4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45", 1);
+    }
+
+    #[test]
+    fun test_sha2_512() {
+        assert!(sha2_512(b"abc") == x"ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f", 0);
+    }
+
+    #[test]
+    fun test_ripemd160() {
+        assert!(ripemd160(b"abc") == x"8eb208f7e05d987a9b044a8e98c6b087f15a0bfc", 0);
+    }
+
+    #[test]
+    fun test_blake2b_256() {
+        assert!(blake2b_256(b"abc") == x"bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319", 0);
+    }
+}
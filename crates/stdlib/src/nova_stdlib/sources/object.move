@@ -0,0 +1,489 @@
+/// Objects are address-backed containers of resources. An object is created with a
+/// `ConstructorRef`, from which the refs controlling its lifetime are generated, and it
+/// is owned by an account or by another object.
+///
+/// `Object<T>` always points to an object holding a `T`: it is only built by
+/// `address_to_object`, which is also used by the VM to build `Object<T>` transaction arguments.
+module nova_std::object {
+    use std::error;
+    use std::event::{Self, EventHandle};
+    use std::signer;
+
+    use nova_std::account::{Self, create_signer_for_friend};
+    use nova_std::transaction_context;
+
+    //
+    // Errors.
+    //
+
+    /// An object already exists at the address
+    const EOBJECT_EXISTS: u64 = 1;
+
+    /// An object doesn't exist at the address
+    const EOBJECT_DOES_NOT_EXIST: u64 = 2;
+
+    /// The object was not created with a deletable constructor
+    const ECANNOT_DELETE: u64 = 3;
+
+    /// The signer doesn't own the object
+    const ENOT_OBJECT_OWNER: u64 = 4;
+
+    /// Ungated transfers are disabled for the object
+    const ENO_UNGATED_TRANSFERS: u64 = 5;
+
+    /// The object is nested deeper than `MAXIMUM_OBJECT_NESTING`
+    const EMAXIMUM_NESTING: u64 = 6;
+
+    /// The owner of the object changed since the linear transfer ref was generated
+    const EOWNER_CHANGED: u64 = 7;
+
+    /// The object doesn't hold a resource of the requested type
+    const ERESOURCE_DOES_NOT_EXIST: u64 = 8;
+
+    /// The new owner is the object itself or one of the objects it owns
+    const EOWNERSHIP_CYCLE: u64 = 9;
+
+    //
+    // Constants
+    //
+
+    /// Scheme identifier appended to the preimage of named object addresses,
+    /// which keeps them apart from resource account addresses.
+    const DERIVE_OBJECT_ADDRESS_SCHEME: u8 = 254;
+
+    /// Maximum depth of objects owning objects followed by the ownership checks.
+    const MAXIMUM_OBJECT_NESTING: u8 = 8;
+
+    /// Core data structures
+
+    /// The core of every object, stored at its address.
+    struct ObjectCore has key {
+        /// An account or another object.
+        owner: address,
+        /// If `false`, the object can only be transferred with a `TransferRef`.
+        allow_ungated_transfer: bool,
+        transfer_events: EventHandle<TransferEvent>,
+    }
+
+    /// A typed pointer to an object.
+    struct Object<phantom T> has copy, drop, store {
+        inner: address,
+    }
+
+    /// Returned once, when the object is created. Used to generate the other refs and the signer.
+    struct ConstructorRef has drop {
+        self: address,
+        /// Named objects cannot be deleted, as their address could be reused.
+        can_delete: bool,
+    }
+
+    /// Ref used to remove the object from storage.
+    struct DeleteRef has drop, store {
+        self: address,
+    }
+
+    /// Ref used to create the signer of the object to add resources later.
+    struct ExtendRef has drop, store {
+        self: address,
+    }
+
+    /// Ref used to control the transfers of the object.
+    struct TransferRef has drop, store {
+        self: address,
+    }
+
+    /// One-time ref to transfer the object regardless of the ungated transfer flag.
+    /// Bound to the owner at generation time.
+    struct LinearTransferRef has drop {
+        self: address,
+        owner: address,
+    }
+
+    /// Event emitted when the object changes owner.
+    struct TransferEvent has drop, store {
+        object: address,
+        from: address,
+        to: address,
+    }
+
+    //
+    // Getter functions
+    //
+
+    /// Returns `true` if an object exists at `object`.
+    public fun is_object(object: address): bool {
+        exists<ObjectCore>(object)
+    }
+
+    /// Return the typed pointer to the object at `object`, which must hold a `T`.
+    public fun address_to_object<T: key>(object: address): Object<T> {
+        assert!(is_object(object), error::not_found(EOBJECT_DOES_NOT_EXIST));
+        assert!(exists_at<T>(object), error::not_found(ERESOURCE_DOES_NOT_EXIST));
+        Object<T> { inner: object }
+    }
+
+    /// Returns `true` if a resource of type `T` is stored at `object`.
+    native fun exists_at<T: key>(object: address): bool;
+
+    /// Returns the address of the object.
+    public fun object_address<T>(object: &Object<T>): address {
+        object.inner
+    }
+
+    /// Convert the pointer to another type of resource stored in the same object.
+    public fun convert<X: key, Y: key>(object: Object<X>): Object<Y> {
+        address_to_object<Y>(object.inner)
+    }
+
+    /// Return the address of the named object created by `creator` with `seed`.
+    public fun create_object_address(creator: &address, seed: vector<u8>): address {
+        account::derive_address(creator, seed, DERIVE_OBJECT_ADDRESS_SCHEME)
+    }
+
+    /// Returns the direct owner of the object.
+    public entry fun owner<T>(object: Object<T>): address acquires ObjectCore {
+        assert!(is_object(object.inner), error::not_found(EOBJECT_DOES_NOT_EXIST));
+        borrow_global<ObjectCore>(object.inner).owner
+    }
+
+    /// Returns `true` if `owner` is the direct owner of the object.
+    public entry fun is_owner<T>(object: Object<T>, owner: address): bool acquires ObjectCore {
+        owner(object) == owner
+    }
+
+    /// Returns `true` if `owner` owns the object directly or through other objects.
+    public entry fun owns<T>(object: Object<T>, owner: address): bool acquires ObjectCore {
+        let current = object.inner;
+        if (current == owner) {
+            return true
+        };
+
+        assert!(is_object(current), error::not_found(EOBJECT_DOES_NOT_EXIST));
+        let depth = 0;
+        while (depth < MAXIMUM_OBJECT_NESTING) {
+            let current_owner = borrow_global<ObjectCore>(current).owner;
+            if (current_owner == owner) {
+                return true
+            };
+            if (!is_object(current_owner)) {
+                return false
+            };
+
+            current = current_owner;
+            depth = depth + 1;
+        };
+
+        abort error::out_of_range(EMAXIMUM_NESTING)
+    }
+
+    /// Returns `true` if the object can be transferred by its owner without a `TransferRef`.
+    public entry fun ungated_transfer_allowed<T>(object: Object<T>): bool acquires ObjectCore {
+        assert!(is_object(object.inner), error::not_found(EOBJECT_DOES_NOT_EXIST));
+        borrow_global<ObjectCore>(object.inner).allow_ungated_transfer
+    }
+
+    //
+    // Constructors
+    //
+
+    /// Create an object at the address derived from `creator` and `seed`, owned by the creator.
+    /// Named objects cannot be deleted.
+    public fun create_named_object(creator: &signer, seed: vector<u8>): ConstructorRef {
+        let creator_addr = signer::address_of(creator);
+        let object = create_object_address(&creator_addr, seed);
+        create_object_internal(creator_addr, object, false)
+    }
+
+    /// Create an object at a unique address, owned by `owner`.
+    public fun create_object(owner: address): ConstructorRef {
+        let object = transaction_context::generate_unique_address();
+        create_object_internal(owner, object, true)
+    }
+
+    /// Create an object at a unique address, owned by the signer.
+    public fun create_object_from_account(creator: &signer): ConstructorRef {
+        create_object(signer::address_of(creator))
+    }
+
+    fun create_object_internal(owner: address, object: address, can_delete: bool): ConstructorRef {
+        assert!(!is_object(object), error::already_exists(EOBJECT_EXISTS));
+
+        let object_signer = create_signer_for_friend(object);
+        move_to(&object_signer, ObjectCore {
+            owner,
+            allow_ungated_transfer: true,
+            transfer_events: event::new_event_handle<TransferEvent>(&object_signer),
+        });
+
+        ConstructorRef { self: object, can_delete }
+    }
+
+    //
+    // Refs
+    //
+
+    /// Generate the signer of the object, to move resources into it.
+    public fun generate_signer(ref: &ConstructorRef): signer {
+        create_signer_for_friend(ref.self)
+    }
+
+    /// Returns the address of the object being constructed.
+    public fun address_from_constructor_ref(ref: &ConstructorRef): address {
+        ref.self
+    }
+
+    /// Returns the typed pointer to the object being constructed.
+    public fun object_from_constructor_ref<T: key>(ref: &ConstructorRef): Object<T> {
+        address_to_object<T>(ref.self)
+    }
+
+    /// Returns `true` if the object can be deleted.
+    public fun can_generate_delete_ref(ref: &ConstructorRef): bool {
+        ref.can_delete
+    }
+
+    /// Generate the ref which deletes the object.
+    public fun generate_delete_ref(ref: &ConstructorRef): DeleteRef {
+        assert!(ref.can_delete, error::permission_denied(ECANNOT_DELETE));
+        DeleteRef { self: ref.self }
+    }
+
+    /// Generate the ref which recreates the signer of the object.
+    public fun generate_extend_ref(ref: &ConstructorRef): ExtendRef {
+        ExtendRef { self: ref.self }
+    }
+
+    /// Generate the ref which controls the transfers of the object.
+    public fun generate_transfer_ref(ref: &ConstructorRef): TransferRef {
+        TransferRef { self: ref.self }
+    }
+
+    /// Returns the address of the object the delete ref belongs to.
+    public fun address_from_delete_ref(ref: &DeleteRef): address {
+        ref.self
+    }
+
+    /// Returns the address of the object the extend ref belongs to.
+    public fun address_from_extend_ref(ref: &ExtendRef): address {
+        ref.self
+    }
+
+    /// Create the signer of the object to add resources to it.
+    public fun generate_signer_for_extending(ref: &ExtendRef): signer {
+        create_signer_for_friend(ref.self)
+    }
+
+    /// Remove the object from storage. Resources left in the object are not removed,
+    /// so the modules owning them should move them out first.
+    public fun delete(ref: DeleteRef) acquires ObjectCore {
+        let ObjectCore {
+            owner: _,
+            allow_ungated_transfer: _,
+            transfer_events,
+        } = move_from<ObjectCore>(ref.self);
+        event::destroy_handle(transfer_events);
+    }
+
+    //
+    // Transfers
+    //
+
+    /// Disable the transfers of the object by its owner.
+    public fun disable_ungated_transfer(ref: &TransferRef) acquires ObjectCore {
+        borrow_global_mut<ObjectCore>(ref.self).allow_ungated_transfer = false;
+    }
+
+    /// Enable the transfers of the object by its owner.
+    public fun enable_ungated_transfer(ref: &TransferRef) acquires ObjectCore {
+        borrow_global_mut<ObjectCore>(ref.self).allow_ungated_transfer = true;
+    }
+
+    /// Generate a one-time ref to transfer the object, bound to its current owner.
+    public fun generate_linear_transfer_ref(ref: &TransferRef): LinearTransferRef acquires ObjectCore {
+        let owner = borrow_global<ObjectCore>(ref.self).owner;
+        LinearTransferRef { self: ref.self, owner }
+    }
+
+    /// Transfer the object with a linear transfer ref, regardless of the ungated transfer flag.
+    public fun transfer_with_ref(ref: LinearTransferRef, to: address) acquires ObjectCore {
+        assert_no_ownership_cycle(ref.self, to);
+        let object = borrow_global_mut<ObjectCore>(ref.self);
+        assert!(object.owner == ref.owner, error::permission_denied(EOWNER_CHANGED));
+        transfer_internal(ref.self, object, to);
+    }
+
+    /// Transfer the object owned by the signer to `to`, which is an account or an object.
+    public entry fun transfer<T>(owner: &signer, object: Object<T>, to: address) acquires ObjectCore {
+        transfer_raw(owner, object.inner, to)
+    }
+
+    /// Transfer the object owned by the signer to the object `to`.
+    public entry fun transfer_to_object<O, T>(owner: &signer, object: Object<O>, to: Object<T>) acquires ObjectCore {
+        transfer_raw(owner, object.inner, to.inner)
+    }
+
+    /// Transfer the object at `object` owned by the signer to `to`.
+    public fun transfer_raw(owner: &signer, object: address, to: address) acquires ObjectCore {
+        assert!(is_object(object), error::not_found(EOBJECT_DOES_NOT_EXIST));
+        assert_no_ownership_cycle(object, to);
+
+        let object_core = borrow_global_mut<ObjectCore>(object);
+        assert!(object_core.owner == signer::address_of(owner), error::permission_denied(ENOT_OBJECT_OWNER));
+        assert!(object_core.allow_ungated_transfer, error::permission_denied(ENO_UNGATED_TRANSFERS));
+        transfer_internal(object, object_core, to);
+    }
+
+    /// Abort if `to` is `object` or owned by it directly or through other objects,
+    /// as the transfer would detach them from any account.
+    fun assert_no_ownership_cycle(object: address, to: address) acquires ObjectCore {
+        let current = to;
+        let depth = 0;
+        while (is_object(current)) {
+            assert!(current != object, error::invalid_argument(EOWNERSHIP_CYCLE));
+            assert!(depth < MAXIMUM_OBJECT_NESTING, error::out_of_range(EMAXIMUM_NESTING));
+
+            current = borrow_global<ObjectCore>(current).owner;
+            depth = depth + 1;
+        };
+    }
+
+    fun transfer_internal(object: address, object_core: &mut ObjectCore, to: address) {
+        if (object_core.owner == to) {
+            return
+        };
+
+        event::emit_event<TransferEvent>(
+            &mut object_core.transfer_events,
+            TransferEvent {
+                object,
+                from: object_core.owner,
+                to,
+            },
+        );
+        object_core.owner = to;
+    }
+
+    #[test_only]
+    use std::bcs;
+
+    #[test_only]
+    struct Hero has key {
+        name: vector<u8>,
+    }
+
+    #[test_only]
+    fun create_hero(creator: &signer, name: vector<u8>): (ConstructorRef, Object<Hero>) {
+        let constructor_ref = create_named_object(creator, name);
+        let object_signer = generate_signer(&constructor_ref);
+        move_to(&object_signer, Hero { name });
+        let hero = object_from_constructor_ref<Hero>(&constructor_ref);
+        (constructor_ref, hero)
+    }
+
+    #[test(creator = @0x2)]
+    fun test_create_named_object(creator: signer) acquires ObjectCore, Hero {
+        let (constructor_ref, hero) = create_hero(&creator, b"hero");
+        let hero_addr = object_address(&hero);
+        assert!(hero_addr == create_object_address(&@0x2, b"hero"), 0);
+        assert!(address_from_constructor_ref(&constructor_ref) == hero_addr, 1);
+        assert!(!can_generate_delete_ref(&constructor_ref), 2);
+        assert!(owner(hero) == @0x2, 3);
+        assert!(borrow_global<Hero>(hero_addr).name == b"hero", 4);
+
+        let bytes = bcs::to_bytes(&hero);
+        assert!(bytes == bcs::to_bytes(&hero_addr), 5);
+    }
+
+    #[test(creator = @0x2)]
+    fun test_transfer_and_nesting(creator: signer) acquires ObjectCore {
+        let (_, hero) = create_hero(&creator, b"hero");
+        let (_, weapon) = create_hero(&creator, b"weapon");
+
+        // the weapon is owned by the hero, which is owned by the creator
+        transfer_to_object(&creator, weapon, hero);
+        assert!(is_owner(weapon, object_address(&hero)), 0);
+        assert!(owns(weapon, @0x2), 1);
+        assert!(!owns(weapon, @0x3), 2);
+
+        transfer(&creator, hero, @0x3);
+        assert!(owns(weapon, @0x3), 3);
+        assert!(!owns(weapon, @0x2), 4);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x10009)]
+    fun fail_transfer_to_itself(creator: signer) acquires ObjectCore {
+        let (_, hero) = create_hero(&creator, b"hero");
+        transfer_to_object(&creator, hero, hero);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x10009)]
+    fun fail_transfer_to_descendant(creator: signer) acquires ObjectCore {
+        let (_, hero) = create_hero(&creator, b"hero");
+        let (_, weapon) = create_hero(&creator, b"weapon");
+        let (_, gem) = create_hero(&creator, b"gem");
+        transfer_to_object(&creator, weapon, hero);
+        transfer_to_object(&creator, gem, weapon);
+
+        transfer_to_object(&creator, hero, gem);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x10009)]
+    fun fail_transfer_with_ref_to_descendant(creator: signer) acquires ObjectCore {
+        let (constructor_ref, hero) = create_hero(&creator, b"hero");
+        let (_, weapon) = create_hero(&creator, b"weapon");
+        transfer_to_object(&creator, weapon, hero);
+
+        let transfer_ref = generate_transfer_ref(&constructor_ref);
+        transfer_with_ref(generate_linear_transfer_ref(&transfer_ref), object_address(&weapon));
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x50005)]
+    fun fail_ungated_transfer_disabled(creator: signer) acquires ObjectCore {
+        let (constructor_ref, hero) = create_hero(&creator, b"hero");
+        let transfer_ref = generate_transfer_ref(&constructor_ref);
+        disable_ungated_transfer(&transfer_ref);
+        assert!(!ungated_transfer_allowed(hero), 0);
+
+        // the transfer ref still moves the object
+        transfer_with_ref(generate_linear_transfer_ref(&transfer_ref), @0x3);
+        assert!(owner(hero) == @0x3, 1);
+
+        transfer(&creator, hero, @0x4);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x50004)]
+    fun fail_transfer_not_owner(creator: signer) acquires ObjectCore {
+        let (_, hero) = create_hero(&creator, b"hero");
+        transfer(&creator, hero, @0x3);
+        transfer(&creator, hero, @0x4);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x50003)]
+    fun fail_delete_named_object(creator: signer) {
+        let (constructor_ref, _) = create_hero(&creator, b"hero");
+        generate_delete_ref(&constructor_ref);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x60008)]
+    fun fail_address_to_object_without_resource(creator: signer) {
+        let constructor_ref = create_object_from_account(&creator);
+        address_to_object<Hero>(address_from_constructor_ref(&constructor_ref));
+    }
+
+    #[test(creator = @0x2)]
+    fun test_delete_object(creator: signer) acquires ObjectCore {
+        let constructor_ref = create_object_from_account(&creator);
+        let object = address_from_constructor_ref(&constructor_ref);
+        assert!(is_object(object), 0);
+
+        delete(generate_delete_ref(&constructor_ref));
+        assert!(!is_object(object), 1);
+    }
+}
@@ -0,0 +1,106 @@
+/// Deterministic pseudo random numbers for the current transaction.
+///
+/// Every value is derived from the transaction hash, the block hash and a per-transaction
+/// counter, so the outcome is PREDICTABLE BY VALIDATORS (and by anyone who learns the block
+/// hash before the transaction is executed). Do not use it when a validator could profit
+/// from predicting or biasing the result.
+///
+/// A `public` function which returns a random outcome can be wrapped by another module that
+/// aborts whenever it dislikes the outcome (test-and-abort attack). A module can opt-in to
+/// a publish time check by declaring the marker struct `RandomnessEntryOnly`; the VM then
+/// rejects the module if any of its `public` or `public(friend)` non-`entry` functions uses
+/// this module.
+module nova_std::randomness {
+    use std::error;
+    use std::vector;
+
+    /// `min` should be smaller than `max`
+    const EINVALID_RANGE: u64 = 1;
+
+    /// Return a fresh 32 bytes seed.
+    native fun next_seed(): vector<u8>;
+
+    /// Return `n` pseudo random bytes.
+    public fun bytes(n: u64): vector<u8> {
+        let result = vector::empty<u8>();
+        while (vector::length(&result) < n) {
+            let seed = next_seed();
+            let i = 0;
+            let len = vector::length(&seed);
+            while (i < len && vector::length(&result) < n) {
+                vector::push_back(&mut result, *vector::borrow(&seed, i));
+                i = i + 1;
+            };
+        };
+
+        result
+    }
+
+    /// Return a pseudo random u64.
+    public fun u64_integer(): u64 {
+        let seed = next_seed();
+        let result = 0u64;
+        let i = 0;
+        while (i < 8) {
+            result = (result << 8) | (*vector::borrow(&seed, i) as u64);
+            i = i + 1;
+        };
+
+        result
+    }
+
+    /// Return a pseudo random u128.
+    public fun u128_integer(): u128 {
+        let seed = next_seed();
+        let result = 0u128;
+        let i = 0;
+        while (i < 16) {
+            result = (result << 8) | (*vector::borrow(&seed, i) as u128);
+            i = i + 1;
+        };
+
+        result
+    }
+
+    /// Return a pseudo random u64 in the range [min, max).
+    /// The modulo is taken on a random u128, so the bias toward the lower values of
+    /// the range is at most 2^-64.
+    public fun u64_range(min: u64, max: u64): u64 {
+        assert!(min < max, error::invalid_argument(EINVALID_RANGE));
+        let range = ((max - min) as u128);
+        min + ((u128_integer() % range) as u64)
+    }
+
+    #[test]
+    fun test_bytes() {
+        assert!(vector::length(&bytes(0)) == 0, 0);
+        assert!(vector::length(&bytes(10)) == 10, 1);
+        assert!(vector::length(&bytes(70)) == 70, 2);
+    }
+
+    #[test]
+    fun test_integers_differ() {
+        assert!(u64_integer() != u64_integer(), 0);
+        assert!(u128_integer() != u128_integer(), 1);
+    }
+
+    #[test]
+    fun test_u64_range() {
+        let i = 0;
+        while (i < 20) {
+            let v = u64_range(10, 20);
+            assert!(v >= 10 && v < 20, 0);
+            i = i + 1;
+        };
+
+        let max = 18446744073709551615;
+        assert!(u64_range(max - 1, max) == max - 1, 1);
+        assert!(u64_range(0, max) < max, 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun test_u64_range_invalid() {
+        u64_range(10, 10);
+    }
+}
@@ -12,6 +12,18 @@ module nova_std::table {
     // native code raises this with Errors::invalid_arguments()
     const ENOT_FOUND: u64 = 101;
     const ENOT_EMPTY: u64 = 102;
+    // native code raises this with Errors::invalid_arguments()
+    const EUNSUPPORTED_KEY_TYPE: u64 = 103;
+    const EINVALID_ORDER: u64 = 104;
+    // native code raises this with Errors::resource_exhausted()
+    const ETOO_MANY_OPEN_ITERATORS: u64 = 105;
+    // native code raises this with Errors::invalid_arguments()
+    const EITERATOR_NOT_FOUND: u64 = 106;
+
+    /// Iterate keys from the smallest to the largest
+    const ORDER_ASCENDING: u8 = 1;
+    /// Iterate keys from the largest to the smallest
+    const ORDER_DESCENDING: u8 = 2;
 
     /// Type of tables
     struct Table<phantom K: copy + drop, phantom V> has store {
@@ -19,15 +31,26 @@ module nova_std::table {
         length: u64,
     }
 
-    /// Type of table iterators
-    struct TableIter has drop {
+    /// Type of table iterators. Every iterator has to be closed with `destroy`.
+    struct TableIter {
         iterator_id: u64,
     }
 
     /// Create a new Table.
     public fun new<K: copy + drop, V: store>(payer: &signer): Table<K, V> {
         Table{
-            handle: new_table_handle<K, V>(signer::address_of(payer)),
+            handle: new_table_handle<K, V>(signer::address_of(payer), false),
+            length: 0,
+        }
+    }
+
+    /// Create a new Table which stores its keys with an order-preserving encoding,
+    /// so `iter_range` visits the entries in the natural order of the keys.
+    /// Supported key types are `bool`, `u8`, `u64`, `u128`, `address`,
+    /// `0x1::string::String` and vectors of them.
+    public fun new_with_ordered_keys<K: copy + drop, V: store>(payer: &signer): Table<K, V> {
+        Table{
+            handle: new_table_handle<K, V>(signer::address_of(payer), true),
             length: 0,
         }
     }
@@ -102,6 +125,12 @@ module nova_std::table {
         drop_unchecked_box<K, V, Box<V>>(table)
     }
 
+    #[test_only]
+    /// Testing only: creates an iterator handle which is not backed by an open iterator.
+    public fun unchecked_iter(iterator_id: u64): TableIter {
+        TableIter { iterator_id }
+    }
+
     /// Create iterator for `table`.
     /// A user has to check `prepare` before calling `next` to prevent abort
     /// 
@@ -112,13 +141,32 @@ module nova_std::table {
     ///     }
     /// 
     ///     let (key, value) = table::next<K, V>(&mut iter);
-    /// } 
+    /// };
+    /// table::destroy(iter);
     public fun iter<K: copy + drop, V>(
         table: &Table<K, V>, 
         start: Option<K>, /* inclusive */
         end: Option<K>, /* exclusive */
         order: u8 /* 1: Ascending, 2: Descending */,
     ): TableIter {
+        iter_range(table, start, end, order)
+    }
+
+    /// Create iterator for the entries of `table` with keys in [`start`, `end`).
+    /// Entries are visited in the order of the stored keys, which is the natural
+    /// order of the keys for tables created with `new_with_ordered_keys` and the
+    /// byte order of their BCS encoding otherwise.
+    public fun iter_range<K: copy + drop, V>(
+        table: &Table<K, V>,
+        start: Option<K>, /* inclusive */
+        end: Option<K>, /* exclusive */
+        order: u8 /* ORDER_ASCENDING or ORDER_DESCENDING */,
+    ): TableIter {
+        assert!(
+            order == ORDER_ASCENDING || order == ORDER_DESCENDING,
+            error::invalid_argument(EINVALID_ORDER),
+        );
+
         let start_bytes: vector<u8> = if (option::is_some(&start)) {
             bcs::to_bytes<K>(&option::extract(&mut start))
         } else {
@@ -137,6 +185,16 @@ module nova_std::table {
         }
     }
 
+    /// Returns the order for iterating keys from the smallest to the largest.
+    public fun order_ascending(): u8 {
+        ORDER_ASCENDING
+    }
+
+    /// Returns the order for iterating keys from the largest to the smallest.
+    public fun order_descending(): u8 {
+        ORDER_DESCENDING
+    }
+
     public fun prepare<K: copy + drop, V>(table_iter: &mut TableIter): bool {
         prepare_box<K, V, Box<V>>(table_iter)
     }
@@ -146,6 +204,14 @@ module nova_std::table {
         (key, &box.val)
     }
 
+    /// Close the iterator and release the storage resources held by it.
+    /// A session can keep only a limited number of iterators open at the same time,
+    /// so every iterator has to be destroyed once it is no longer needed.
+    public fun destroy(table_iter: TableIter) {
+        destroy_table_iter(&table_iter);
+        let TableIter { iterator_id: _ } = table_iter;
+    }
+
     // ======================================================================================================
     // Internal API
 
@@ -156,7 +222,7 @@ module nova_std::table {
 
     // Primitives which take as an additional type parameter `Box<V>`, so the implementation
     // can use this to determine serialization layout.
-    native fun new_table_handle<K, V>(payer: address): address;
+    native fun new_table_handle<K, V>(payer: address, ordered_keys: bool): address;
     native fun set_table_payer<K: copy + drop, V>(table: &Table<K, V>, payer: address);
     native fun add_box<K: copy + drop, V, B>(table: &mut Table<K, V>, key: K, val: Box<V>);
     native fun borrow_box<K: copy + drop, V, B>(table: &Table<K, V>, key: K): &Box<V>;
@@ -168,4 +234,5 @@ module nova_std::table {
     native fun new_table_iter<K: copy + drop, V, B>(table: &Table<K, V>, start: vector<u8>, end: vector<u8>, order: u8): u64;
     native fun next_box<K: copy + drop, V, B>(table_iter: &mut TableIter): (K, &Box<V>);
     native fun prepare_box<K: copy + drop, V, B>(table_iter: &mut TableIter): bool;
+    native fun destroy_table_iter(table_iter: &TableIter);
 }
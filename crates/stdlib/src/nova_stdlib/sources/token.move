@@ -0,0 +1,587 @@
+/// Non-fungible tokens grouped in collections. A creator keeps all of its collections
+/// in a `Collections` resource, and a token is identified by its creator, collection
+/// and name. Tokens of a collection are enumerable by index.
+module nova_std::token {
+    use std::error;
+    use std::event::{Self, EventHandle};
+    use std::signer;
+    use std::string::{Self, String};
+    use std::vector;
+
+    use nova_std::simple_map::{Self, SimpleMap};
+    use nova_std::table::{Self, Table};
+
+    //
+    // Errors.
+    //
+
+    /// The creator already has a collection with the name
+    const ECOLLECTION_ALREADY_EXISTS: u64 = 1;
+
+    /// The collection doesn't exist
+    const ECOLLECTION_NOT_FOUND: u64 = 2;
+
+    /// The collection already has a token with the name
+    const ETOKEN_ALREADY_EXISTS: u64 = 3;
+
+    /// The token doesn't exist
+    const ETOKEN_NOT_FOUND: u64 = 4;
+
+    /// Minting would exceed the maximum supply of the collection
+    const EMAX_SUPPLY_EXCEEDED: u64 = 5;
+
+    /// The signer doesn't own the token
+    const ENOT_OWNER: u64 = 6;
+
+    /// Royalty numerator is larger than the denominator, or the denominator is zero
+    const EINVALID_ROYALTY: u64 = 7;
+
+    /// The property is immutable
+    const EPROPERTY_NOT_MUTABLE: u64 = 8;
+
+    /// The property keys and values have different lengths
+    const EPROPERTY_LENGTH_MISMATCH: u64 = 9;
+
+    /// The property doesn't exist
+    const EPROPERTY_NOT_FOUND: u64 = 10;
+
+    /// Name of the collection or the token is too long
+    const ENAME_TOO_LONG: u64 = 11;
+
+    /// URI of the collection or the token is too long
+    const EURI_TOO_LONG: u64 = 12;
+
+    /// The token index is out of range
+    const EINDEX_OUT_OF_RANGE: u64 = 13;
+
+    //
+    // Constants
+    //
+
+    const MAX_NAME_LENGTH: u64 = 128;
+    const MAX_URI_LENGTH: u64 = 512;
+
+    /// Core data structures
+
+    /// Globally unique identifier of a token.
+    struct TokenId has copy, drop, store {
+        creator: address,
+        collection: String,
+        name: String,
+    }
+
+    /// Royalty paid to `payee_address` on secondary sales, `numerator / denominator` of the price.
+    struct Royalty has copy, drop, store {
+        numerator: u64,
+        denominator: u64,
+        payee_address: address,
+    }
+
+    struct Collection has store {
+        description: String,
+        uri: String,
+        /// Maximum number of tokens in the collection, `0` if unlimited.
+        maximum: u64,
+        royalty: Royalty,
+        tokens: Table<String, TokenData>,
+        /// Token names by index. Burning moves the last token into the freed index.
+        token_names: Table<u64, String>,
+        supply: u64,
+    }
+
+    struct TokenData has store {
+        /// Position of the token in `Collection::token_names`.
+        index: u64,
+        owner: address,
+        description: String,
+        uri: String,
+        immutable_properties: SimpleMap<String, vector<u8>>,
+        mutable_properties: SimpleMap<String, vector<u8>>,
+    }
+
+    /// The collections of a creator and the associated event handles.
+    struct Collections has key {
+        collections: Table<String, Collection>,
+        create_collection_events: EventHandle<CreateCollectionEvent>,
+        mint_events: EventHandle<MintEvent>,
+        burn_events: EventHandle<BurnEvent>,
+        transfer_events: EventHandle<TransferEvent>,
+    }
+
+    /// Event emitted when a collection is created.
+    struct CreateCollectionEvent has drop, store {
+        creator: address,
+        collection: String,
+        maximum: u64,
+    }
+
+    /// Event emitted when a token is minted.
+    struct MintEvent has drop, store {
+        id: TokenId,
+        owner: address,
+    }
+
+    /// Event emitted when a token is burned.
+    struct BurnEvent has drop, store {
+        id: TokenId,
+        owner: address,
+    }
+
+    /// Event emitted when a token changes owner.
+    struct TransferEvent has drop, store {
+        id: TokenId,
+        from: address,
+        to: address,
+    }
+
+    //
+    // Getter functions
+    //
+
+    /// Create the identifier of a token.
+    public fun create_token_id(creator: address, collection: String, name: String): TokenId {
+        TokenId { creator, collection, name }
+    }
+
+    /// Returns the creator, collection and name of the token.
+    public fun token_id_fields(id: &TokenId): (address, String, String) {
+        (id.creator, id.collection, id.name)
+    }
+
+    /// Returns `true` if `creator` has a collection named `collection`.
+    public entry fun collection_exists(creator: address, collection: String): bool acquires Collections {
+        exists<Collections>(creator) && table::contains(&borrow_global<Collections>(creator).collections, collection)
+    }
+
+    /// Returns `true` if the token exists.
+    public entry fun token_exists(creator: address, collection: String, name: String): bool acquires Collections {
+        collection_exists(creator, collection)
+            && table::contains(&borrow_collection(creator, collection).tokens, name)
+    }
+
+    /// Returns the description of the collection.
+    public entry fun collection_description(creator: address, collection: String): String acquires Collections {
+        borrow_collection(creator, collection).description
+    }
+
+    /// Returns the URI of the collection.
+    public entry fun collection_uri(creator: address, collection: String): String acquires Collections {
+        borrow_collection(creator, collection).uri
+    }
+
+    /// Returns the maximum number of tokens in the collection, `0` if unlimited.
+    public entry fun collection_maximum(creator: address, collection: String): u64 acquires Collections {
+        borrow_collection(creator, collection).maximum
+    }
+
+    /// Returns the number of tokens in the collection.
+    public entry fun collection_supply(creator: address, collection: String): u64 acquires Collections {
+        borrow_collection(creator, collection).supply
+    }
+
+    /// Returns the royalty of the collection as `(numerator, denominator, payee_address)`.
+    public fun royalty(creator: address, collection: String): (u64, u64, address) acquires Collections {
+        let royalty = &borrow_collection(creator, collection).royalty;
+        (royalty.numerator, royalty.denominator, royalty.payee_address)
+    }
+
+    /// Returns the name of the token at `index` of the collection, for enumeration.
+    public entry fun token_name_by_index(creator: address, collection: String, index: u64): String acquires Collections {
+        let collection_data = borrow_collection(creator, collection);
+        assert!(index < collection_data.supply, error::out_of_range(EINDEX_OUT_OF_RANGE));
+        *table::borrow(&collection_data.token_names, index)
+    }
+
+    /// Returns the owner of the token.
+    public entry fun owner_of(creator: address, collection: String, name: String): address acquires Collections {
+        borrow_token_data(creator, collection, name).owner
+    }
+
+    /// Returns the description of the token.
+    public entry fun token_description(creator: address, collection: String, name: String): String acquires Collections {
+        borrow_token_data(creator, collection, name).description
+    }
+
+    /// Returns the URI of the token.
+    public entry fun token_uri(creator: address, collection: String, name: String): String acquires Collections {
+        borrow_token_data(creator, collection, name).uri
+    }
+
+    /// Returns the value of an immutable or mutable property of the token.
+    public entry fun property(creator: address, collection: String, name: String, key: String): vector<u8> acquires Collections {
+        let token_data = borrow_token_data(creator, collection, name);
+        if (simple_map::contains_key(&token_data.immutable_properties, &key)) {
+            return *simple_map::borrow(&token_data.immutable_properties, &key)
+        };
+
+        assert!(
+            simple_map::contains_key(&token_data.mutable_properties, &key),
+            error::not_found(EPROPERTY_NOT_FOUND),
+        );
+        *simple_map::borrow(&token_data.mutable_properties, &key)
+    }
+
+    /// Returns `true` if the property of the token can be updated by the creator.
+    public entry fun is_property_mutable(creator: address, collection: String, name: String, key: String): bool acquires Collections {
+        simple_map::contains_key(&borrow_token_data(creator, collection, name).mutable_properties, &key)
+    }
+
+    fun borrow_collection(creator: address, collection: String): &Collection acquires Collections {
+        assert!(collection_exists(creator, collection), error::not_found(ECOLLECTION_NOT_FOUND));
+        table::borrow(&borrow_global<Collections>(creator).collections, collection)
+    }
+
+    fun borrow_token_data(creator: address, collection: String, name: String): &TokenData acquires Collections {
+        let tokens = &borrow_collection(creator, collection).tokens;
+        assert!(table::contains(tokens, name), error::not_found(ETOKEN_NOT_FOUND));
+        table::borrow(tokens, name)
+    }
+
+    //
+    // Collections
+    //
+
+    /// Create a collection owned by `creator`. `maximum` limits the number of tokens
+    /// in the collection, `0` for unlimited.
+    public entry fun create_collection(
+        creator: &signer,
+        name: String,
+        description: String,
+        uri: String,
+        maximum: u64,
+        royalty_numerator: u64,
+        royalty_denominator: u64,
+        royalty_payee_address: address,
+    ) acquires Collections {
+        assert!(string::length(&name) <= MAX_NAME_LENGTH, error::invalid_argument(ENAME_TOO_LONG));
+        assert!(string::length(&uri) <= MAX_URI_LENGTH, error::invalid_argument(EURI_TOO_LONG));
+        assert!(
+            royalty_denominator > 0 && royalty_numerator <= royalty_denominator,
+            error::invalid_argument(EINVALID_ROYALTY),
+        );
+
+        let creator_addr = signer::address_of(creator);
+        if (!exists<Collections>(creator_addr)) {
+            move_to(creator, Collections {
+                collections: table::new(creator),
+                create_collection_events: event::new_event_handle<CreateCollectionEvent>(creator),
+                mint_events: event::new_event_handle<MintEvent>(creator),
+                burn_events: event::new_event_handle<BurnEvent>(creator),
+                transfer_events: event::new_event_handle<TransferEvent>(creator),
+            });
+        };
+
+        let collections = borrow_global_mut<Collections>(creator_addr);
+        assert!(
+            !table::contains(&collections.collections, name),
+            error::already_exists(ECOLLECTION_ALREADY_EXISTS),
+        );
+
+        table::add(&mut collections.collections, name, Collection {
+            description,
+            uri,
+            maximum,
+            royalty: Royalty {
+                numerator: royalty_numerator,
+                denominator: royalty_denominator,
+                payee_address: royalty_payee_address,
+            },
+            tokens: table::new(creator),
+            token_names: table::new(creator),
+            supply: 0,
+        });
+
+        event::emit_event<CreateCollectionEvent>(
+            &mut collections.create_collection_events,
+            CreateCollectionEvent {
+                creator: creator_addr,
+                collection: name,
+                maximum,
+            },
+        );
+    }
+
+    //
+    // Tokens
+    //
+
+    /// Mint a token into the collection of `creator` and give it to `to`.
+    /// Properties are given as parallel vectors of keys and values.
+    public entry fun mint(
+        creator: &signer,
+        collection: String,
+        name: String,
+        description: String,
+        uri: String,
+        to: address,
+        immutable_keys: vector<String>,
+        immutable_values: vector<vector<u8>>,
+        mutable_keys: vector<String>,
+        mutable_values: vector<vector<u8>>,
+    ) acquires Collections {
+        assert!(string::length(&name) <= MAX_NAME_LENGTH, error::invalid_argument(ENAME_TOO_LONG));
+        assert!(string::length(&uri) <= MAX_URI_LENGTH, error::invalid_argument(EURI_TOO_LONG));
+
+        let immutable_properties = build_properties(immutable_keys, immutable_values);
+        let mutable_properties = build_properties(mutable_keys, mutable_values);
+        assert_disjoint(&immutable_properties, &mutable_keys);
+
+        let creator_addr = signer::address_of(creator);
+        assert!(collection_exists(creator_addr, collection), error::not_found(ECOLLECTION_NOT_FOUND));
+
+        let collections = borrow_global_mut<Collections>(creator_addr);
+        let collection_data = table::borrow_mut(&mut collections.collections, collection);
+        assert!(
+            !table::contains(&collection_data.tokens, name),
+            error::already_exists(ETOKEN_ALREADY_EXISTS),
+        );
+        assert!(
+            collection_data.maximum == 0 || collection_data.supply < collection_data.maximum,
+            error::out_of_range(EMAX_SUPPLY_EXCEEDED),
+        );
+
+        let index = collection_data.supply;
+        table::add(&mut collection_data.token_names, index, name);
+        table::add(&mut collection_data.tokens, name, TokenData {
+            index,
+            owner: to,
+            description,
+            uri,
+            immutable_properties,
+            mutable_properties,
+        });
+        collection_data.supply = index + 1;
+
+        event::emit_event<MintEvent>(
+            &mut collections.mint_events,
+            MintEvent {
+                id: create_token_id(creator_addr, collection, name),
+                owner: to,
+            },
+        );
+    }
+
+    /// Burn a token owned by the signer.
+    public entry fun burn(
+        owner: &signer,
+        creator: address,
+        collection: String,
+        name: String,
+    ) acquires Collections {
+        let owner_addr = signer::address_of(owner);
+        assert!(owner_of(creator, collection, name) == owner_addr, error::permission_denied(ENOT_OWNER));
+
+        let collections = borrow_global_mut<Collections>(creator);
+        let collection_data = table::borrow_mut(&mut collections.collections, collection);
+        let TokenData {
+            index,
+            owner: _,
+            description: _,
+            uri: _,
+            immutable_properties: _,
+            mutable_properties: _,
+        } = table::remove(&mut collection_data.tokens, name);
+
+        // keep the indices dense by moving the last token into the freed index
+        let last = collection_data.supply - 1;
+        let last_name = table::remove(&mut collection_data.token_names, last);
+        if (index != last) {
+            table::borrow_mut(&mut collection_data.tokens, last_name).index = index;
+            *table::borrow_mut(&mut collection_data.token_names, index) = last_name;
+        };
+        collection_data.supply = last;
+
+        event::emit_event<BurnEvent>(
+            &mut collections.burn_events,
+            BurnEvent {
+                id: create_token_id(creator, collection, name),
+                owner: owner_addr,
+            },
+        );
+    }
+
+    /// Transfer a token owned by the signer to `to`.
+    public entry fun transfer(
+        owner: &signer,
+        creator: address,
+        collection: String,
+        name: String,
+        to: address,
+    ) acquires Collections {
+        let owner_addr = signer::address_of(owner);
+        assert!(owner_of(creator, collection, name) == owner_addr, error::permission_denied(ENOT_OWNER));
+
+        let collections = borrow_global_mut<Collections>(creator);
+        let collection_data = table::borrow_mut(&mut collections.collections, collection);
+        table::borrow_mut(&mut collection_data.tokens, name).owner = to;
+
+        event::emit_event<TransferEvent>(
+            &mut collections.transfer_events,
+            TransferEvent {
+                id: create_token_id(creator, collection, name),
+                from: owner_addr,
+                to,
+            },
+        );
+    }
+
+    /// Add or update a mutable property of a token in the collection of `creator`.
+    public entry fun update_property(
+        creator: &signer,
+        collection: String,
+        name: String,
+        key: String,
+        value: vector<u8>,
+    ) acquires Collections {
+        let creator_addr = signer::address_of(creator);
+        assert!(token_exists(creator_addr, collection, name), error::not_found(ETOKEN_NOT_FOUND));
+
+        let collections = borrow_global_mut<Collections>(creator_addr);
+        let collection_data = table::borrow_mut(&mut collections.collections, collection);
+        let token_data = table::borrow_mut(&mut collection_data.tokens, name);
+        assert!(
+            !simple_map::contains_key(&token_data.immutable_properties, &key),
+            error::permission_denied(EPROPERTY_NOT_MUTABLE),
+        );
+
+        if (simple_map::contains_key(&token_data.mutable_properties, &key)) {
+            *simple_map::borrow_mut(&mut token_data.mutable_properties, &key) = value;
+        } else {
+            simple_map::add(&mut token_data.mutable_properties, key, value);
+        };
+    }
+
+    fun build_properties(keys: vector<String>, values: vector<vector<u8>>): SimpleMap<String, vector<u8>> {
+        assert!(
+            vector::length(&keys) == vector::length(&values),
+            error::invalid_argument(EPROPERTY_LENGTH_MISMATCH),
+        );
+
+        let properties = simple_map::create<String, vector<u8>>();
+        while (!vector::is_empty(&keys)) {
+            simple_map::add(&mut properties, vector::pop_back(&mut keys), vector::pop_back(&mut values));
+        };
+
+        properties
+    }
+
+    fun assert_disjoint(immutable_properties: &SimpleMap<String, vector<u8>>, mutable_keys: &vector<String>) {
+        let i = 0;
+        while (i < vector::length(mutable_keys)) {
+            assert!(
+                !simple_map::contains_key(immutable_properties, vector::borrow(mutable_keys, i)),
+                error::invalid_argument(EPROPERTY_NOT_MUTABLE),
+            );
+            i = i + 1;
+        };
+    }
+
+    #[test_only]
+    fun create_test_collection(creator: &signer, maximum: u64) acquires Collections {
+        create_collection(
+            creator,
+            string::utf8(b"collection"),
+            string::utf8(b"test collection"),
+            string::utf8(b"https://example.com/collection"),
+            maximum,
+            5,
+            100,
+            signer::address_of(creator),
+        );
+    }
+
+    #[test_only]
+    fun mint_test_token(creator: &signer, name: vector<u8>, to: address) acquires Collections {
+        mint(
+            creator,
+            string::utf8(b"collection"),
+            string::utf8(name),
+            string::utf8(b"test token"),
+            string::utf8(b"https://example.com/token"),
+            to,
+            vector::singleton(string::utf8(b"rarity")),
+            vector::singleton(b"legendary"),
+            vector::singleton(string::utf8(b"level")),
+            vector::singleton(x"01"),
+        );
+    }
+
+    #[test(creator = @0x2, owner = @0x3)]
+    fun end_to_end(creator: signer, owner: signer) acquires Collections {
+        let creator_addr = signer::address_of(&creator);
+        let owner_addr = signer::address_of(&owner);
+        let collection = string::utf8(b"collection");
+
+        create_test_collection(&creator, 0);
+        assert!(collection_exists(creator_addr, collection), 0);
+        assert!(collection_uri(creator_addr, collection) == string::utf8(b"https://example.com/collection"), 1);
+        let (numerator, denominator, payee) = royalty(creator_addr, collection);
+        assert!(numerator == 5 && denominator == 100 && payee == creator_addr, 2);
+
+        mint_test_token(&creator, b"token 1", owner_addr);
+        mint_test_token(&creator, b"token 2", owner_addr);
+        mint_test_token(&creator, b"token 3", creator_addr);
+        assert!(collection_supply(creator_addr, collection) == 3, 3);
+        assert!(owner_of(creator_addr, collection, string::utf8(b"token 1")) == owner_addr, 4);
+        assert!(property(creator_addr, collection, string::utf8(b"token 1"), string::utf8(b"rarity")) == b"legendary", 5);
+
+        update_property(&creator, collection, string::utf8(b"token 1"), string::utf8(b"level"), x"02");
+        assert!(property(creator_addr, collection, string::utf8(b"token 1"), string::utf8(b"level")) == x"02", 6);
+
+        transfer(&owner, creator_addr, collection, string::utf8(b"token 2"), creator_addr);
+        assert!(owner_of(creator_addr, collection, string::utf8(b"token 2")) == creator_addr, 7);
+
+        // burning the first token moves the last one into its index
+        burn(&owner, creator_addr, collection, string::utf8(b"token 1"));
+        assert!(!token_exists(creator_addr, collection, string::utf8(b"token 1")), 8);
+        assert!(collection_supply(creator_addr, collection) == 2, 9);
+        assert!(token_name_by_index(creator_addr, collection, 0) == string::utf8(b"token 3"), 10);
+        assert!(token_name_by_index(creator_addr, collection, 1) == string::utf8(b"token 2"), 11);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x20005)]
+    fun fail_mint_over_maximum(creator: signer) acquires Collections {
+        create_test_collection(&creator, 1);
+        mint_test_token(&creator, b"token 1", @0x3);
+        mint_test_token(&creator, b"token 2", @0x3);
+    }
+
+    #[test(creator = @0x2, other = @0x4)]
+    #[expected_failure(abort_code = 0x50006)]
+    fun fail_transfer_not_owner(creator: signer, other: signer) acquires Collections {
+        let creator_addr = signer::address_of(&creator);
+        create_test_collection(&creator, 0);
+        mint_test_token(&creator, b"token 1", @0x3);
+        transfer(&other, creator_addr, string::utf8(b"collection"), string::utf8(b"token 1"), @0x4);
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x50008)]
+    fun fail_update_immutable_property(creator: signer) acquires Collections {
+        create_test_collection(&creator, 0);
+        mint_test_token(&creator, b"token 1", @0x3);
+        update_property(
+            &creator,
+            string::utf8(b"collection"),
+            string::utf8(b"token 1"),
+            string::utf8(b"rarity"),
+            b"common",
+        );
+    }
+
+    #[test(creator = @0x2)]
+    #[expected_failure(abort_code = 0x10007)]
+    fun fail_invalid_royalty(creator: signer) acquires Collections {
+        create_collection(
+            &creator,
+            string::utf8(b"collection"),
+            string::utf8(b""),
+            string::utf8(b""),
+            0,
+            101,
+            100,
+            @0x2,
+        );
+    }
+}
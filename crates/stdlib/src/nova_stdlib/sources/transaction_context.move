@@ -0,0 +1,32 @@
+/// Information about the transaction which is currently executed.
+module nova_std::transaction_context {
+    /// Return the hash of the current transaction, which is also used as the session id.
+    native public fun get_txn_hash(): vector<u8>;
+
+    /// Return the senders of the current transaction.
+    native public fun get_senders(): vector<address>;
+
+    /// Return an address which is unique within the whole chain. It is derived from the
+    /// transaction hash and a per-transaction counter, in the same way as table handles.
+    native public fun generate_unique_address(): address;
+
+    /// Return the remaining gas of the current transaction.
+    native public fun get_remaining_gas(): u64;
+
+    #[test]
+    fun test_get_txn_hash() {
+        assert!(get_txn_hash() == x"0000000000000000000000000000000000000000000000000000000000000000", 0);
+    }
+
+    #[test]
+    fun test_get_senders() {
+        assert!(std::vector::is_empty(&get_senders()), 0);
+    }
+
+    #[test]
+    fun test_generate_unique_address() {
+        let addr1 = generate_unique_address();
+        let addr2 = generate_unique_address();
+        assert!(addr1 != addr2, 0);
+    }
+}
@@ -0,0 +1,43 @@
+/// This module stores the configuration of the vm chosen at genesis, so the vm
+/// can load it back from the state when the chain restarts.
+module nova_std::vm_config {
+    use std::error;
+    use std::signer;
+    use std::string::String;
+    use std::vector;
+
+    /// The signer is not `@nova_std`.
+    const EUNAUTHORIZED: u64 = 1;
+    /// The names and the values of the gas schedule have different lengths.
+    const EGAS_SCHEDULE_LENGTH_MISMATCH: u64 = 2;
+
+    /// The full gas schedule, replacing the initial gas parameters of the vm.
+    struct GasSchedule has key {
+        names: vector<String>,
+        values: vector<u64>,
+    }
+
+    /// The accounts allowed to publish modules. Everyone can publish when it
+    /// does not exist.
+    struct ModulePublishers has key {
+        publishers: vector<address>,
+    }
+
+    /// Only called by the vm at genesis.
+    fun store_gas_schedule(nova_std: &signer, names: vector<String>, values: vector<u64>) {
+        assert!(signer::address_of(nova_std) == @nova_std, error::permission_denied(EUNAUTHORIZED));
+        assert!(
+            vector::length(&names) == vector::length(&values),
+            error::invalid_argument(EGAS_SCHEDULE_LENGTH_MISMATCH),
+        );
+
+        move_to(nova_std, GasSchedule { names, values });
+    }
+
+    /// Only called by the vm at genesis.
+    fun store_module_publishers(nova_std: &signer, publishers: vector<address>) {
+        assert!(signer::address_of(nova_std) == @nova_std, error::permission_denied(EUNAUTHORIZED));
+
+        move_to(nova_std, ModulePublishers { publishers });
+    }
+}
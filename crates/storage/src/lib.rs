@@ -1,6 +1,7 @@
 pub mod size;
 pub mod state_view;
 pub mod state_view_impl;
+pub mod storage_usage;
 pub mod table_meta;
 pub mod table_view;
 pub mod table_view_impl;
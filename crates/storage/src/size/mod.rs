@@ -48,13 +48,80 @@ pub fn compute_size_changes<S: SizeResolver>(
         .map_err(|_| VMStatus::Error(StatusCode::LOOKUP_FAILED))
 }
 
+/// The number of bytes a key-value pair is charged for in the storage.
+///
+/// It is the canonical stored size of resources, modules and table items, used
+/// for both the previous and the new size of every `SizeDelta`.
+pub fn stored_size(k: &AccessPath, v: &[u8]) -> usize {
+    let ap_size = k.to_string().as_bytes().len();
+    let op_size = v.len();
+    ap_size + op_size
+}
+
 pub fn get_kv_stored_size(k: &AccessPath, v: &Op<Vec<u8>>) -> usize {
     match v.as_ref().ok() {
-        Some(data) => {
-            let ap_size = k.to_string().as_bytes().len();
-            let op_size = data.len();
-            ap_size + op_size
-        }
+        Some(data) => stored_size(k, data),
         None => 0,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state_view::StateView;
+    use crate::state_view_impl::StateViewImpl;
+
+    use move_deps::move_core_types::{
+        identifier::Identifier,
+        language_storage::{StructTag, TypeTag},
+    };
+
+    struct MapView(BTreeMap<AccessPath, Vec<u8>>);
+
+    impl StateView for MapView {
+        fn get(&self, access_path: &AccessPath) -> anyhow::Result<Option<Vec<u8>>> {
+            Ok(self.0.get(access_path).cloned())
+        }
+    }
+
+    fn struct_tag(address: AccountAddress) -> StructTag {
+        StructTag {
+            address,
+            module: Identifier::new("TableTestData").unwrap(),
+            name: Identifier::new("S").unwrap(),
+            type_params: vec![TypeTag::U64, TypeTag::U64],
+        }
+    }
+
+    #[test]
+    fn test_removal_releases_the_added_size() {
+        let two = AccountAddress::from_hex_literal("0x2").unwrap();
+        let ap = AccessPath::resource_access_path(two, struct_tag(two));
+        let data = vec![1; 28];
+
+        // add the resource to an empty state
+        let mut change_set = ChangeSet::new();
+        change_set
+            .add_resource_op(two, struct_tag(two), Op::New(data.clone()))
+            .unwrap();
+        let changes =
+            compute_size_changes(&StateViewImpl::new(&MapView(BTreeMap::new())), &change_set)
+                .unwrap();
+        assert_eq!(
+            changes[&two],
+            SizeDelta::increasing(stored_size(&ap, &data))
+        );
+
+        // the stored size read back for the removal is the size charged for the addition
+        let mut change_set = ChangeSet::new();
+        change_set
+            .add_resource_op(two, struct_tag(two), Op::Delete)
+            .unwrap();
+        let state = MapView([(ap.clone(), data.clone())].into());
+        let changes = compute_size_changes(&StateViewImpl::new(&state), &change_set).unwrap();
+        assert_eq!(
+            changes[&two],
+            SizeDelta::decreasing(stored_size(&ap, &data))
+        );
+    }
+}
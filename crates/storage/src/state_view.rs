@@ -5,7 +5,7 @@
 
 //! This crate defines [`trait StateView`](StateView).
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use nova_types::access_path::AccessPath;
 
 /// `StateView` is a trait that defines a read-only snapshot of the global state. It is passed to
@@ -14,4 +14,13 @@ use nova_types::access_path::AccessPath;
 pub trait StateView {
     /// Gets the state for a single access path.
     fn get(&self, access_path: &AccessPath) -> Result<Option<Vec<u8>>>;
+
+    /// Gets all the states whose storage key starts with `prefix`, in ascending order of the
+    /// storage key.
+    ///
+    /// Prefix iteration is optional; state views which can only serve point lookups keep the
+    /// default implementation, which returns an error.
+    fn get_prefix(&self, _prefix: &[u8]) -> Result<Vec<(AccessPath, Vec<u8>)>> {
+        bail!("prefix iteration is not supported by this state view")
+    }
 }
@@ -1,6 +1,7 @@
 #![forbid(unsafe_code)]
 
-use crate::size::size_resolver::SizeResolver;
+use crate::size::{size_resolver::SizeResolver, stored_size};
+use crate::storage_usage::storage_usage_resolver::StorageUsageResolver;
 use crate::table_meta::table_meta_resolver::TableMetaResolver;
 
 use super::state_view::StateView;
@@ -14,6 +15,7 @@ use move_deps::move_core_types::{
 };
 
 use nova_types::access_path::AccessPath;
+use nova_types::storage_usage::StorageUsage;
 use nova_types::table::TableHandle;
 use nova_types::table_meta::TableMeta;
 
@@ -58,7 +60,7 @@ impl<'block, S: StateView> ResourceResolver for StateViewImpl<'block, S> {
 impl<'block, S: StateView> SizeResolver for StateViewImpl<'block, S> {
     fn get_size(&self, access_path: &AccessPath) -> anyhow::Result<usize> {
         match self.get(access_path)? {
-            Some(v) => Ok(access_path.to_bytes()?.len() + v.len()),
+            Some(v) => Ok(stored_size(access_path, &v)),
             None => Ok(0),
         }
     }
@@ -74,3 +76,17 @@ impl<'block, S: StateView> TableMetaResolver for StateViewImpl<'block, S> {
         Ok(table_meta)
     }
 }
+
+impl<'block, S: StateView> StorageUsageResolver for StateViewImpl<'block, S> {
+    fn get_storage_usage(
+        &self,
+        address: &AccountAddress,
+    ) -> Result<Option<StorageUsage>, anyhow::Error> {
+        let ap = AccessPath::storage_usage_access_path(*address);
+        let storage_usage = match self.get(&ap)? {
+            Some(v) => Some(StorageUsage::deserialize(&v)?),
+            None => None,
+        };
+        Ok(storage_usage)
+    }
+}
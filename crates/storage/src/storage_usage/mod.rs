@@ -0,0 +1,188 @@
+use move_deps::move_core_types::{
+    account_address::AccountAddress,
+    effects::Op,
+    vm_status::{StatusCode, VMStatus},
+};
+
+use nova_types::size_delta::SizeDelta;
+use nova_types::storage_usage::{StorageDeposit, StorageDepositPrice, StorageUsage};
+
+use std::collections::BTreeMap;
+
+use self::storage_usage_resolver::StorageUsageResolver;
+
+pub mod storage_usage_resolver;
+
+/// Apply the size changes of the accounts to their storage usage, and compute
+/// the storage deposit to charge for them and the refunds of the accounts.
+/// The usages of the accounts without a stored usage are returned as `Op::New`.
+pub fn compute_storage_usage_changes<S: StorageUsageResolver>(
+    remote: &S,
+    account_size_changes: &BTreeMap<AccountAddress, SizeDelta>,
+    deposit_price: &StorageDepositPrice,
+) -> Result<(BTreeMap<AccountAddress, Op<StorageUsage>>, StorageDeposit), VMStatus> {
+    let mut storage_usage_changes = BTreeMap::new();
+    let mut storage_deposit = StorageDeposit::default();
+    for (addr, size_delta) in account_size_changes.iter() {
+        if size_delta.amount == 0 {
+            continue;
+        }
+
+        let stored_usage = remote
+            .get_storage_usage(addr)
+            .map_err(|_| VMStatus::Error(StatusCode::LOOKUP_FAILED))?;
+        let is_new = stored_usage.is_none();
+        let mut storage_usage = stored_usage.unwrap_or_else(|| StorageUsage::new());
+        let (charge, refund) = storage_usage.apply(size_delta, deposit_price);
+
+        storage_deposit.charge += charge;
+        if refund != 0 {
+            storage_deposit.refunds.insert(*addr, refund);
+        }
+        storage_usage_changes.insert(
+            *addr,
+            match is_new {
+                true => Op::New(storage_usage),
+                false => Op::Modify(storage_usage),
+            },
+        );
+    }
+
+    Ok((storage_usage_changes, storage_deposit))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct MockUsages(BTreeMap<AccountAddress, StorageUsage>);
+
+    impl MockUsages {
+        fn from_changes(changes: BTreeMap<AccountAddress, Op<StorageUsage>>) -> Self {
+            Self(
+                changes
+                    .into_iter()
+                    .map(|(addr, op)| (addr, usage(&op)))
+                    .collect(),
+            )
+        }
+    }
+
+    fn usage(op: &Op<StorageUsage>) -> StorageUsage {
+        match op {
+            Op::New(usage) | Op::Modify(usage) => *usage,
+            Op::Delete => panic!("storage usages are never deleted"),
+        }
+    }
+
+    impl StorageUsageResolver for MockUsages {
+        fn get_storage_usage(
+            &self,
+            address: &AccountAddress,
+        ) -> anyhow::Result<Option<StorageUsage>> {
+            Ok(self.0.get(address).copied())
+        }
+    }
+
+    #[test]
+    fn test_storage_usage() {
+        let two = AccountAddress::from_hex_literal("0x2").unwrap();
+        let three = AccountAddress::from_hex_literal("0x3").unwrap();
+        let remote = MockUsages(
+            [(
+                two,
+                StorageUsage {
+                    size: 90,
+                    deposit: 0,
+                },
+            )]
+            .into(),
+        );
+
+        let changes = compute_storage_usage_changes(
+            &remote,
+            &[
+                (two, SizeDelta::increasing(10)),
+                (three, SizeDelta::increasing(100)),
+            ]
+            .into(),
+            &StorageDepositPrice::default(),
+        )
+        .unwrap()
+        .0;
+        assert_eq!(usage(&changes[&two]).size, 100);
+        assert_eq!(usage(&changes[&three]).size, 100);
+
+        // only the accounts without a stored usage get a new record
+        assert!(matches!(changes[&two], Op::Modify(_)));
+        assert!(matches!(changes[&three], Op::New(_)));
+
+        // the usage never goes below zero
+        let changes = compute_storage_usage_changes(
+            &remote,
+            &[(two, SizeDelta::decreasing(100))].into(),
+            &StorageDepositPrice::default(),
+        )
+        .unwrap()
+        .0;
+        assert_eq!(usage(&changes[&two]).size, 0);
+    }
+
+    #[test]
+    fn test_storage_deposit() {
+        let two = AccountAddress::from_hex_literal("0x2").unwrap();
+        let three = AccountAddress::from_hex_literal("0x3").unwrap();
+        let price = StorageDepositPrice {
+            per_byte: 10,
+            refund_percentage: 50,
+        };
+
+        // the deposit is charged for the bytes added and held by the account
+        let (changes, deposit) = compute_storage_usage_changes(
+            &MockUsages(BTreeMap::new()),
+            &[
+                (two, SizeDelta::increasing(100)),
+                (three, SizeDelta::increasing(10)),
+            ]
+            .into(),
+            &price,
+        )
+        .unwrap();
+        assert_eq!(deposit.charge, 1100);
+        assert!(deposit.refunds.is_empty());
+        assert_eq!(usage(&changes[&two]).deposit, 1000);
+
+        // the price doubles, the deposit held by 0x2 is refunded pro rata
+        let price = StorageDepositPrice {
+            per_byte: 20,
+            refund_percentage: 50,
+        };
+        let (changes, deposit) = compute_storage_usage_changes(
+            &MockUsages::from_changes(changes),
+            &[
+                (two, SizeDelta::decreasing(40)),
+                (three, SizeDelta::increasing(10)),
+            ]
+            .into(),
+            &price,
+        )
+        .unwrap();
+        assert_eq!(deposit.charge, 200);
+        assert_eq!(deposit.refunds, [(two, 200)].into());
+        assert_eq!(usage(&changes[&two]).size, 60);
+        assert_eq!(usage(&changes[&two]).deposit, 600);
+        assert_eq!(usage(&changes[&three]).deposit, 300);
+
+        // removing more than the known usage never refunds more than the deposit
+        let (changes, deposit) = compute_storage_usage_changes(
+            &MockUsages::from_changes(changes),
+            &[(three, SizeDelta::decreasing(100))].into(),
+            &price,
+        )
+        .unwrap();
+        assert_eq!(deposit.charge, 0);
+        assert_eq!(deposit.refunds, [(three, 150)].into());
+        assert_eq!(usage(&changes[&three]).size, 0);
+        assert_eq!(usage(&changes[&three]).deposit, 0);
+    }
+}
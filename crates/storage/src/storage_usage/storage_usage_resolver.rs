@@ -0,0 +1,16 @@
+// Copyright (c) The Kernel Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! This crate defines [`trait StorageUsageResolver`](StorageUsageResolver).
+
+use anyhow::Result;
+use move_deps::move_core_types::account_address::AccountAddress;
+
+use nova_types::storage_usage::StorageUsage;
+
+/// `StorageUsageResolver` is a trait that defines a read-only snapshot of the global state.
+pub trait StorageUsageResolver {
+    fn get_storage_usage(&self, address: &AccountAddress) -> Result<Option<StorageUsage>>;
+}
@@ -26,9 +26,7 @@ pub fn compute_table_meta_changes<S: SizeResolver + TableMetaResolver>(
         .changes
         .iter()
         .map(|(handle, change)| {
-            let mut table_meta = remote
-                .get_table_meta(&handle)?
-                .unwrap_or_else(|| TableMeta::new());
+            let mut table_meta = get_or_new_table_meta(remote, handle, table_change_set)?;
             let mut table_delta = SizeDelta::zero();
             for (key, op) in &change.entries {
                 let ap = AccessPath::table_item_access_path(handle.0, key.to_vec());
@@ -36,6 +34,17 @@ pub fn compute_table_meta_changes<S: SizeResolver + TableMetaResolver>(
                 let new = get_kv_stored_size(&ap, op);
                 let delta = SizeDelta::new(prev, new);
 
+                // count entries being inserted into or removed from the table
+                if let Some(length) = table_meta.length.as_mut() {
+                    if prev == 0 && new != 0 {
+                        *length += 1;
+                    } else if prev != 0 && new == 0 {
+                        *length = length.checked_sub(1).ok_or_else(|| {
+                            anyhow::anyhow!("length of table {} underflows", handle)
+                        })?;
+                    }
+                }
+
                 table_delta.merge(delta);
             }
 
@@ -69,12 +78,11 @@ pub fn compute_table_meta_changes<S: SizeResolver + TableMetaResolver>(
                 table_meta.payer = *payer;
             }
             None => {
-                let table_meta = remote
-                    .get_table_meta(handle)
-                    .map_err(|_| VMStatus::Error(StatusCode::LOOKUP_FAILED))?
-                    .unwrap_or_else(|| TableMeta::new());
+                let mut table_meta = get_or_new_table_meta(remote, handle, table_change_set)
+                    .map_err(|_| VMStatus::Error(StatusCode::LOOKUP_FAILED))?;
 
                 transfer_table_storage_fee(payer, &table_meta, account_size_changes);
+                table_meta.payer = *payer;
                 table_meta_changes.insert(*handle, table_meta);
             }
         }
@@ -83,6 +91,26 @@ pub fn compute_table_meta_changes<S: SizeResolver + TableMetaResolver>(
     Ok(table_meta_changes)
 }
 
+/// Load the stored meta of the table, or build a fresh one from the key/value
+/// types if the table is created in this change set. The tables without meta
+/// get an untyped one, as they did before the types were stored.
+fn get_or_new_table_meta<S: TableMetaResolver>(
+    remote: &S,
+    handle: &TableHandle,
+    table_change_set: &TableChangeSet,
+) -> anyhow::Result<TableMeta> {
+    if let Some(table_meta) = remote.get_table_meta(handle)? {
+        return Ok(table_meta);
+    }
+
+    Ok(match table_change_set.new_tables.get(handle) {
+        Some(info) => TableMeta::new(info),
+        None => TableMeta::untyped(),
+    })
+}
+
+/// Move the size of the table from its previous payer to `payer`; the previous
+/// payer is refunded its deposit and the message pays the one of the new payer.
 fn transfer_table_storage_fee(
     payer: &AccountAddress,
     table_meta: &TableMeta,
@@ -6,7 +6,10 @@
 //! This crate defines [`trait TableView`](TableView).
 
 use anyhow::Result;
-use nova_types::{iterator::Order, table::TableHandle};
+use nova_types::{
+    iterator::Order,
+    table::{KeyEncoding, TableHandle},
+};
 
 /// `TableView` is a trait that defines a read-only snapshot of the global state for table extension. It is passed to
 /// the VM for transaction execution, during which the VM is guaranteed to read anything at the
@@ -18,6 +21,10 @@ pub trait TableView {
         key: &[u8],
     ) -> anyhow::Result<Option<Vec<u8>>>;
 
+    /// Returns the key encoding the table was created with, which is stored
+    /// in the table meta. Tables without a meta use `KeyEncoding::Bcs`.
+    fn resolve_key_encoding(&self, handle: &TableHandle) -> Result<KeyEncoding>;
+
     /// Allows iteration over a set of key/value pairs, either forwards or backwards.
     /// Returns an iterator go reference
     ///
@@ -34,4 +41,8 @@ pub trait TableView {
 
     /// Take mutable reference for iterator implementation
     fn next_key(&mut self, iterator_id: u32) -> Result<Option<Vec<u8>>>;
+
+    /// Release the iterator, so the storage can free the resources held by it.
+    /// The iterator id must not be used after this call.
+    fn destroy_iterator(&mut self, iterator_id: u32) -> Result<()>;
 }
@@ -4,7 +4,7 @@ use crate::table_view::TableView;
 
 use nova_natives::table::TableResolver;
 use nova_types::iterator::Order;
-use nova_types::table::TableHandle;
+use nova_types::table::{KeyEncoding, TableHandle};
 
 pub struct TableViewImpl<'block, S> {
     table_view: &'block mut S,
@@ -25,6 +25,10 @@ impl<'block, S: TableView> TableResolver for TableViewImpl<'block, S> {
         self.table_view.resolve_table_entry(handle, key)
     }
 
+    fn resolve_key_encoding(&self, handle: &TableHandle) -> anyhow::Result<KeyEncoding> {
+        self.table_view.resolve_key_encoding(handle)
+    }
+
     fn create_iterator(
         &mut self,
         handle: &TableHandle,
@@ -38,4 +42,8 @@ impl<'block, S: TableView> TableResolver for TableViewImpl<'block, S> {
     fn next_key(&mut self, iterator_id: u32) -> anyhow::Result<Option<Vec<u8>>> {
         self.table_view.next_key(iterator_id)
     }
+
+    fn destroy_iterator(&mut self, iterator_id: u32) -> anyhow::Result<()> {
+        self.table_view.destroy_iterator(iterator_id)
+    }
 }
@@ -81,6 +81,10 @@ impl AccessPath {
         AccessPath::new(address, Self::table_meta_data_path())
     }
 
+    pub fn storage_usage_access_path(address: AccountAddress) -> AccessPath {
+        AccessPath::new(address, Self::storage_usage_data_path())
+    }
+
     pub fn resource_data_path(tag: StructTag) -> DataPath {
         DataPath::Resource(tag)
     }
@@ -97,6 +101,10 @@ impl AccessPath {
         DataPath::TableMeta
     }
 
+    pub fn storage_usage_data_path() -> DataPath {
+        DataPath::StorageUsage
+    }
+
     pub fn into_inner(self) -> (AccountAddress, DataPath) {
         let address = self.address;
         let path = self.path;
@@ -117,6 +125,26 @@ impl AccessPath {
         out.extend(path_bytes);
         Ok(out)
     }
+
+    /// Recovers from the storage key made by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        if bytes.len() < AccountAddress::LENGTH {
+            bail!("access path bytes are shorter than an address");
+        }
+
+        let (address, path) = bytes.split_at(AccountAddress::LENGTH);
+        Ok(AccessPath::new(
+            AccountAddress::from_bytes(address)?,
+            DataPath::decode(path)?,
+        ))
+    }
+
+    /// Returns the storage key prefix shared by every data of `data_type` under `address`.
+    pub fn data_type_prefix(address: AccountAddress, data_type: DataType) -> Vec<u8> {
+        let mut out = address.to_vec();
+        out.push(data_type.type_index());
+        out
+    }
 }
 
 impl fmt::Debug for AccessPath {
@@ -154,6 +182,7 @@ pub enum DataType {
     RESOURCE,
     TABLE_ITEM,
     TABLE_META,
+    STORAGE_USAGE,
 }
 
 impl DataType {
@@ -190,6 +219,7 @@ pub enum DataPath {
     Resource(StructTag),
     TableItem(Vec<u8>),
     TableMeta,
+    StorageUsage,
 }
 
 impl DataPath {
@@ -218,6 +248,7 @@ impl DataPath {
             DataPath::Resource(_) => DataType::RESOURCE,
             DataPath::TableItem(_) => DataType::TABLE_ITEM,
             DataPath::TableMeta => DataType::TABLE_META,
+            DataPath::StorageUsage => DataType::STORAGE_USAGE,
         }
     }
 
@@ -230,7 +261,7 @@ impl DataPath {
             DataPath::Code(module_name) => bcs::to_bytes(module_name)?,
             DataPath::Resource(struct_tag) => bcs::to_bytes(struct_tag)?,
             DataPath::TableItem(key) => key.to_vec(),
-            DataPath::TableMeta => {
+            DataPath::TableMeta | DataPath::StorageUsage => {
                 vec![]
             }
         };
@@ -253,6 +284,7 @@ impl DataPath {
             DataType::RESOURCE => Ok(DataPath::Resource(bcs::from_bytes(&val[1..])?)),
             DataType::TABLE_ITEM => Ok(DataPath::TableItem(val[1..].to_vec())),
             DataType::TABLE_META => Ok(DataPath::TableMeta),
+            DataType::STORAGE_USAGE => Ok(DataPath::StorageUsage),
         }
     }
 }
@@ -270,7 +302,7 @@ impl fmt::Display for DataPath {
             DataPath::TableItem(key) => {
                 write!(f, "{}/{}", storage_index, encode_hex(key))
             }
-            DataPath::TableMeta => {
+            DataPath::TableMeta | DataPath::StorageUsage => {
                 // put zero for consistency
                 write!(f, "{}/0", storage_index)
             }
@@ -295,6 +327,7 @@ impl FromStr for AccessPath {
             DataType::RESOURCE => AccessPath::resource_data_path(parse_struct_tag(parts[2])?),
             DataType::TABLE_ITEM => AccessPath::table_item_data_path(decode_hex(parts[2])?),
             DataType::TABLE_META => AccessPath::table_meta_data_path(),
+            DataType::STORAGE_USAGE => AccessPath::storage_usage_data_path(),
         };
 
         Ok(AccessPath::new(address, data_path))
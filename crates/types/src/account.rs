@@ -0,0 +1,68 @@
+use move_deps::move_core_types::account_address::AccountAddress;
+use sha3::{Digest, Sha3_256};
+
+/// Scheme identifier appended to the preimage of resource account addresses.
+/// Must be kept in sync with `nova_std::account`.
+pub const DERIVE_RESOURCE_ACCOUNT_SCHEME: u8 = 255;
+
+/// Scheme identifier appended to the preimage of named object addresses.
+/// Must be kept in sync with `nova_std::object`.
+pub const DERIVE_OBJECT_ADDRESS_SCHEME: u8 = 254;
+
+/// The address is the leading `AccountAddress::LENGTH` bytes of
+/// `sha3_256(source | seed | scheme)`.
+fn derive_address(source: &AccountAddress, seed: &[u8], scheme: u8) -> AccountAddress {
+    let mut digest = Sha3_256::new();
+    Digest::update(&mut digest, source.as_ref());
+    Digest::update(&mut digest, seed);
+    Digest::update(&mut digest, [scheme]);
+    let bytes = digest.finalize().to_vec();
+
+    AccountAddress::from_bytes(&bytes[0..AccountAddress::LENGTH])
+        .expect("Unable to create an address from a hash")
+}
+
+/// Derive the address of a resource account from its source address and seed.
+pub fn create_resource_address(source: &AccountAddress, seed: &[u8]) -> AccountAddress {
+    derive_address(source, seed, DERIVE_RESOURCE_ACCOUNT_SCHEME)
+}
+
+/// Derive the address of a named object from its creator address and seed.
+pub fn create_object_address(creator: &AccountAddress, seed: &[u8]) -> AccountAddress {
+    derive_address(creator, seed, DERIVE_OBJECT_ADDRESS_SCHEME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_resource_address() {
+        let source = AccountAddress::from_hex_literal("0x1").unwrap();
+        assert_eq!(
+            create_resource_address(&source, b"seed"),
+            AccountAddress::from_hex_literal("0x4c1ca3ef7c48cb98ff7a2585612579277e8dce5b").unwrap()
+        );
+
+        let source = AccountAddress::from_hex_literal("0xb0b").unwrap();
+        assert_eq!(
+            create_resource_address(&source, b"seed"),
+            AccountAddress::from_hex_literal("0x565d44ea3e16425c53c9ee6eb567c32f10d6826d").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_create_object_address() {
+        let creator = AccountAddress::from_hex_literal("0x1").unwrap();
+        assert_eq!(
+            create_object_address(&creator, b"seed"),
+            AccountAddress::from_hex_literal("0x12da1d5d86382c277c861d48dcc9b40454123e72").unwrap()
+        );
+
+        let creator = AccountAddress::from_hex_literal("0xb0b").unwrap();
+        assert_eq!(
+            create_object_address(&creator, b"seed"),
+            AccountAddress::from_hex_literal("0xdd33ab91e23e277c6179e35db46f197b142b57d5").unwrap()
+        );
+    }
+}
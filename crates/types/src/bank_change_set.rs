@@ -0,0 +1,42 @@
+use move_deps::move_core_types::account_address::AccountAddress;
+
+/// A transfer of a mapped coin between an account's bank balance and the bridge escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BankOp {
+    /// Move `amount` of `denom` from the account's bank balance into the bridge escrow.
+    Withdraw {
+        denom: String,
+        address: AccountAddress,
+        amount: u64,
+    },
+    /// Move `amount` of `denom` from the bridge escrow into the account's bank balance.
+    Deposit {
+        denom: String,
+        address: AccountAddress,
+        amount: u64,
+    },
+}
+
+/// The bank operations of a message in execution order. They are buffered during
+/// the execution and have to be applied with the write set, only when the message
+/// succeeds, so the bank and the Move coins cannot diverge.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BankChangeSet(Vec<BankOp>);
+
+impl BankChangeSet {
+    pub fn new(ops: Vec<BankOp>) -> BankChangeSet {
+        Self(ops)
+    }
+
+    pub fn ops(&self) -> &[BankOp] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<BankOp> {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::{anyhow, Result};
+use bech32::{FromBase32, ToBase32, Variant};
+use move_deps::move_core_types::account_address::AccountAddress;
+
+/// Encode `addr` to a bech32 string with the given human readable part.
+pub fn encode_address(hrp: &str, addr: &AccountAddress) -> Result<String> {
+    bech32::encode(hrp, addr.to_vec().to_base32(), Variant::Bech32)
+        .map_err(|e| anyhow!("failed to encode bech32 address: {}", e))
+}
+
+/// Decode a bech32 string to its human readable part and the address.
+pub fn decode_address(bech32_addr: &str) -> Result<(String, AccountAddress)> {
+    let (hrp, data, variant) = bech32::decode(bech32_addr)
+        .map_err(|e| anyhow!("failed to decode bech32 address: {}", e))?;
+    if variant != Variant::Bech32 {
+        return Err(anyhow!("unsupported bech32 variant: {:?}", variant));
+    }
+
+    let bytes = Vec::<u8>::from_base32(&data)
+        .map_err(|e| anyhow!("failed to decode bech32 address: {}", e))?;
+    let addr =
+        AccountAddress::from_bytes(bytes).map_err(|e| anyhow!("invalid address length: {}", e))?;
+
+    Ok((hrp, addr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bech32_address() {
+        let addr = AccountAddress::from_hex_literal("0x1").unwrap();
+        let encoded = encode_address("init", &addr).unwrap();
+        assert_eq!(encoded, "init1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqr5e3d");
+        assert_eq!(
+            decode_address(&encoded).unwrap(),
+            ("init".to_string(), addr)
+        );
+
+        let addr =
+            AccountAddress::from_hex_literal("0xa1b2c3d4e5f60718293a4b5c6d7e8f9001122334").unwrap();
+        let encoded = encode_address("cosmos", &addr).unwrap();
+        assert_eq!(encoded, "cosmos15xev84897cr3s2f6fdwx6l50jqq3yge572xqpp");
+        assert_eq!(
+            decode_address(&encoded).unwrap(),
+            ("cosmos".to_string(), addr)
+        );
+
+        // checksum mismatch
+        assert!(decode_address("init1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqr5e3e").is_err());
+        // 32 bytes payload is not a valid address
+        assert!(decode_address(
+            &bech32::encode("init", [0u8; 32].to_base32(), Variant::Bech32).unwrap()
+        )
+        .is_err());
+    }
+}
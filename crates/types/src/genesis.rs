@@ -0,0 +1,105 @@
+use crate::entry_function::EntryFunction;
+use crate::module::ModuleBundle;
+
+use move_deps::move_core_types::{account_address::AccountAddress, language_storage::TypeTag};
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+
+/// Everything applied by the genesis session, in the order of the fields:
+/// the `stdlib` modules and `module_bundle` are published, `accounts` are created,
+/// `coins` are initialized and minted, and `init_functions` are executed.
+///
+/// `gas_schedule` and `module_publishers` are stored in `0x1::vm_config`, which has
+/// to be published, and configure the vm once the genesis succeeded. The vm loads
+/// them back from the state with `NovaVM::load_vm_config` on restart.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GenesisConfig {
+    #[serde(default)]
+    pub stdlib: StdlibConfig,
+    #[serde(default)]
+    pub module_bundle: Option<ModuleBundle>,
+    #[serde(default)]
+    pub accounts: Vec<AccountAddress>,
+    #[serde(default)]
+    pub coins: Vec<GenesisCoin>,
+    /// The full on-chain gas schedule, replacing the initial one
+    #[serde(default)]
+    pub gas_schedule: Option<BTreeMap<String, u64>>,
+    /// The accounts allowed to publish modules after genesis; everyone when not set
+    #[serde(default)]
+    pub module_publishers: Option<Vec<AccountAddress>>,
+    /// Entry functions executed with the `0x1` signer
+    #[serde(default)]
+    pub init_functions: Vec<EntryFunction>,
+}
+
+/// A native coin initialized at genesis with the initial balances.
+/// `coin_type` has to be declared by a module at `0x1`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisCoin {
+    pub coin_type: TypeTag,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    #[serde(default)]
+    pub balances: Vec<GenesisBalance>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisBalance {
+    pub address: AccountAddress,
+    pub amount: u64,
+}
+
+/// The bundles of the standard library.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StdlibBundle {
+    MoveStdlib,
+    MoveNursery,
+    NovaStdlib,
+}
+
+impl StdlibBundle {
+    pub const ALL: [StdlibBundle; 3] = [
+        StdlibBundle::MoveStdlib,
+        StdlibBundle::MoveNursery,
+        StdlibBundle::NovaStdlib,
+    ];
+}
+
+/// A single module of a standard library bundle.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StdlibModule {
+    pub bundle: StdlibBundle,
+    pub name: String,
+}
+
+/// The standard library modules published at genesis: every module of `bundles`
+/// and the single `modules` picked from the other bundles. The selected modules
+/// and the custom modules have to contain all of their dependencies. An empty
+/// config, like the zero value of the Go type, selects the whole standard library.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StdlibConfig {
+    #[serde(default)]
+    pub bundles: Vec<StdlibBundle>,
+    #[serde(default)]
+    pub modules: Vec<StdlibModule>,
+}
+
+impl StdlibConfig {
+    pub fn is_empty(&self) -> bool {
+        self.bundles.is_empty() && self.modules.is_empty()
+    }
+}
+
+impl Default for StdlibConfig {
+    /// Publish the whole standard library.
+    fn default() -> Self {
+        Self {
+            bundles: StdlibBundle::ALL.to_vec(),
+            modules: vec![],
+        }
+    }
+}
@@ -1,15 +1,22 @@
 pub mod access_path;
+pub mod account;
+pub mod bank_change_set;
+pub mod bech32;
 pub mod entry_function;
 pub mod errors;
+pub mod genesis;
 pub mod iterator;
 pub mod message;
 pub mod module;
+pub mod ordered_key;
 pub mod script;
 pub mod size_change_set;
 pub mod size_delta;
+pub mod storage_usage;
 pub mod table;
 pub mod table_meta;
 pub mod table_meta_change_set;
+pub mod vm_config;
 pub mod write_set;
 
 mod serde_helper;
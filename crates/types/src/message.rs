@@ -13,12 +13,13 @@ use move_deps::move_core_types::effects::Event;
 use move_deps::move_core_types::vm_status::*;
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
 
 use crate::entry_function::EntryFunction;
 use crate::module::ModuleBundle;
 use crate::script::Script;
-use crate::{size_change_set::SizeChangeSet, write_set::WriteSet};
+use crate::{bank_change_set::BankChangeSet, size_change_set::SizeChangeSet, write_set::WriteSet};
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Message {
@@ -29,6 +30,8 @@ pub struct Message {
     sender: Option<AccountAddress>,
     /// The message script to execute.
     payload: MessagePayload,
+    /// Address paying the gas, including the storage deposit, on behalf of the sender.
+    fee_payer: Option<AccountAddress>,
 }
 
 impl Message {
@@ -44,6 +47,7 @@ impl Message {
             session_id,
             sender,
             payload,
+            fee_payer: None,
         }
     }
 
@@ -56,6 +60,7 @@ impl Message {
             session_id,
             sender,
             payload,
+            fee_payer: None,
         }
     }
 
@@ -67,6 +72,7 @@ impl Message {
             session_id,
             sender,
             payload: MessagePayload::Script(script),
+            fee_payer: None,
         }
     }
 
@@ -82,6 +88,7 @@ impl Message {
             session_id,
             sender,
             payload: MessagePayload::EntryFunction(entry_function),
+            fee_payer: None,
         }
     }
 
@@ -97,9 +104,19 @@ impl Message {
             session_id,
             sender,
             payload: MessagePayload::ModuleBundle(modules),
+            fee_payer: None,
         }
     }
 
+    /// Set the account which pays the gas and the storage of this message.
+    ///
+    /// The fee payer is also passed as an additional signer to the scripts and
+    /// entry functions declaring one more signer than the number of senders.
+    pub fn with_fee_payer(mut self, fee_payer: AccountAddress) -> Self {
+        self.fee_payer = Some(fee_payer);
+        self
+    }
+
     pub fn into_payload(self) -> MessagePayload {
         self.payload
     }
@@ -118,6 +135,11 @@ impl Message {
         &self.payload
     }
 
+    /// Return the fee payer of this message, if sponsored.
+    pub fn fee_payer(&self) -> Option<AccountAddress> {
+        self.fee_payer
+    }
+
     pub fn mock() -> Self {
         Self::mock_by_sender(AccountAddress::random())
     }
@@ -145,6 +167,7 @@ impl Message {
             + bcs::to_bytes(&self.sender())
                 .expect("Unable to serialize sender")
                 .len()
+            + self.fee_payer().map_or(0, |_| AccountAddress::LENGTH)
     }
 }
 
@@ -226,11 +249,25 @@ pub struct MessageOutput {
     write_set: WriteSet,
     size_change_set: SizeChangeSet,
 
+    /// The bank operations to apply with the write set.
+    bank_change_set: BankChangeSet,
+
     /// The amount of gas used during execution.
     gas_used: u64,
 
+    /// The storage deposit charged for the bytes added in gas units,
+    /// included in `gas_used`.
+    storage_fee: u64,
+
+    /// The part of the storage deposit refunded to the accounts for the bytes
+    /// they removed, in gas units.
+    storage_refunds: BTreeMap<AccountAddress, u64>,
+
     /// The execution status.
     status: MessageStatus,
+
+    /// The account charged for `gas_used` when it is not the sender.
+    fee_payer: Option<AccountAddress>,
 }
 
 impl MessageOutput {
@@ -238,18 +275,32 @@ impl MessageOutput {
         events: Vec<Event>,
         write_set: WriteSet,
         size_change_set: SizeChangeSet,
+        bank_change_set: BankChangeSet,
         gas_used: u64,
+        storage_fee: u64,
+        storage_refunds: BTreeMap<AccountAddress, u64>,
         status: MessageStatus,
     ) -> Self {
         MessageOutput {
             events,
             write_set,
             size_change_set,
+            bank_change_set,
             gas_used,
+            storage_fee,
+            storage_refunds,
             status,
+            fee_payer: None,
         }
     }
 
+    /// Attribute the gas of the message, including the storage deposit, to `fee_payer`.
+    /// The size changes stay with the accounts owning the bytes.
+    pub fn charge_fee_payer(mut self, fee_payer: AccountAddress) -> Self {
+        self.fee_payer = Some(fee_payer);
+        self
+    }
+
     pub fn events(&self) -> &[Event] {
         &self.events
     }
@@ -262,14 +313,31 @@ impl MessageOutput {
         &self.size_change_set
     }
 
+    pub fn bank_change_set(&self) -> &BankChangeSet {
+        &self.bank_change_set
+    }
+
     pub fn gas_used(&self) -> u64 {
         self.gas_used
     }
 
+    pub fn storage_fee(&self) -> u64 {
+        self.storage_fee
+    }
+
+    pub fn storage_refunds(&self) -> &BTreeMap<AccountAddress, u64> {
+        &self.storage_refunds
+    }
+
     pub fn status(&self) -> &MessageStatus {
         &self.status
     }
 
+    /// Return the fee payer charged for this message, `None` if the sender pays.
+    pub fn fee_payer(&self) -> Option<AccountAddress> {
+        self.fee_payer
+    }
+
     pub fn into_inner(self) -> (Vec<Event>, WriteSet, SizeChangeSet, u64, MessageStatus) {
         (
             self.events,
@@ -0,0 +1,252 @@
+//! Order-preserving encoding of table keys.
+//!
+//! BCS encodes integers in little-endian and prefixes vectors with their length, so
+//! the byte order of BCS encoded keys does not match the natural order of the key
+//! values. Tables created with [`KeyEncoding::Ordered`](crate::table::KeyEncoding)
+//! store their keys with the encoding below instead, which keeps the byte order of
+//! the storage equal to the value order:
+//!
+//! - `bool`, `u8` and `address` are written as they are.
+//! - `u64` and `u128` are written in big-endian.
+//! - `vector<u8>` is written with every `0x00` escaped to `0x00 0xFF` and terminated
+//!   with `0x00 0x00`.
+//! - other vectors are written as elements prefixed with `0x01` and terminated with `0x00`.
+//! - structs are written as the concatenation of their fields.
+
+use anyhow::{bail, ensure, Result};
+use move_deps::move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{StructTag, TypeTag},
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+
+const ESCAPE: u8 = 0x00;
+const ESCAPED_ZERO: u8 = 0xFF;
+const BYTES_TERMINATOR: u8 = 0x00;
+const ELEMENT_PREFIX: u8 = 0x01;
+const VECTOR_TERMINATOR: u8 = 0x00;
+
+/// Returns the layout of `key_type` if the type can be used as an ordered table key.
+///
+/// Ordered keys are limited to primitive types, vectors of them and `0x1::string::String`,
+/// so the layout can be built from the type tag alone.
+pub fn ordered_key_layout(key_type: &TypeTag) -> Option<MoveTypeLayout> {
+    Some(match key_type {
+        TypeTag::Bool => MoveTypeLayout::Bool,
+        TypeTag::U8 => MoveTypeLayout::U8,
+        TypeTag::U64 => MoveTypeLayout::U64,
+        TypeTag::U128 => MoveTypeLayout::U128,
+        TypeTag::Address => MoveTypeLayout::Address,
+        TypeTag::Vector(elem) => MoveTypeLayout::Vector(Box::new(ordered_key_layout(elem)?)),
+        TypeTag::Struct(struct_tag) if is_utf8_string(struct_tag) => MoveTypeLayout::Struct(
+            MoveStructLayout::new(vec![MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8))]),
+        ),
+        _ => return None,
+    })
+}
+
+/// Converts BCS encoded key bytes into the order-preserving encoding.
+pub fn encode_ordered_key(layout: &MoveTypeLayout, bcs_bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut reader = Reader::new(bcs_bytes);
+    let mut out = Vec::with_capacity(bcs_bytes.len());
+    bcs_to_ordered(layout, &mut reader, &mut out)?;
+    ensure!(reader.is_empty(), "trailing bytes in the table key");
+    Ok(out)
+}
+
+/// Converts order-preserving encoded key bytes back into BCS.
+pub fn decode_ordered_key(layout: &MoveTypeLayout, ordered_bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut reader = Reader::new(ordered_bytes);
+    let mut out = Vec::with_capacity(ordered_bytes.len());
+    ordered_to_bcs(layout, &mut reader, &mut out)?;
+    ensure!(reader.is_empty(), "trailing bytes in the table key");
+    Ok(out)
+}
+
+fn is_utf8_string(struct_tag: &StructTag) -> bool {
+    struct_tag.address == AccountAddress::ONE
+        && struct_tag.module.as_str() == "string"
+        && struct_tag.name.as_str() == "String"
+        && struct_tag.type_params.is_empty()
+}
+
+fn bcs_to_ordered(layout: &MoveTypeLayout, reader: &mut Reader, out: &mut Vec<u8>) -> Result<()> {
+    match layout {
+        MoveTypeLayout::Bool | MoveTypeLayout::U8 => out.extend(reader.read(1)?),
+        MoveTypeLayout::U64 => out.extend(reader.read(8)?.iter().rev()),
+        MoveTypeLayout::U128 => out.extend(reader.read(16)?.iter().rev()),
+        MoveTypeLayout::Address | MoveTypeLayout::Signer => {
+            out.extend(reader.read(AccountAddress::LENGTH)?)
+        }
+        MoveTypeLayout::Vector(elem) => {
+            let len = reader.read_uleb128()?;
+            if let MoveTypeLayout::U8 = elem.as_ref() {
+                for byte in reader.read(len)? {
+                    out.push(*byte);
+                    if *byte == ESCAPE {
+                        out.push(ESCAPED_ZERO);
+                    }
+                }
+                out.extend([ESCAPE, BYTES_TERMINATOR]);
+            } else {
+                for _ in 0..len {
+                    out.push(ELEMENT_PREFIX);
+                    bcs_to_ordered(elem, reader, out)?;
+                }
+                out.push(VECTOR_TERMINATOR);
+            }
+        }
+        MoveTypeLayout::Struct(struct_layout) => {
+            for field in struct_layout.fields() {
+                bcs_to_ordered(field, reader, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn ordered_to_bcs(layout: &MoveTypeLayout, reader: &mut Reader, out: &mut Vec<u8>) -> Result<()> {
+    match layout {
+        MoveTypeLayout::Bool | MoveTypeLayout::U8 => out.extend(reader.read(1)?),
+        MoveTypeLayout::U64 => out.extend(reader.read(8)?.iter().rev()),
+        MoveTypeLayout::U128 => out.extend(reader.read(16)?.iter().rev()),
+        MoveTypeLayout::Address | MoveTypeLayout::Signer => {
+            out.extend(reader.read(AccountAddress::LENGTH)?)
+        }
+        MoveTypeLayout::Vector(elem) => {
+            let mut elems = Vec::new();
+            let mut len = 0;
+            if let MoveTypeLayout::U8 = elem.as_ref() {
+                loop {
+                    let byte = reader.read(1)?[0];
+                    if byte == ESCAPE {
+                        match reader.read(1)?[0] {
+                            ESCAPED_ZERO => {}
+                            BYTES_TERMINATOR => break,
+                            _ => bail!("invalid escape sequence in the table key"),
+                        }
+                    }
+                    elems.push(byte);
+                    len += 1;
+                }
+            } else {
+                loop {
+                    match reader.read(1)?[0] {
+                        ELEMENT_PREFIX => ordered_to_bcs(elem, reader, &mut elems)?,
+                        VECTOR_TERMINATOR => break,
+                        _ => bail!("invalid vector element prefix in the table key"),
+                    }
+                    len += 1;
+                }
+            }
+            write_uleb128(out, len);
+            out.extend(elems);
+        }
+        MoveTypeLayout::Struct(struct_layout) => {
+            for field in struct_layout.fields() {
+                ordered_to_bcs(field, reader, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_uleb128(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        out.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn read(&mut self, len: usize) -> Result<&'a [u8]> {
+        ensure!(self.bytes.len() >= len, "unexpected end of the table key");
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_uleb128(&mut self) -> Result<usize> {
+        let mut value: u64 = 0;
+        for shift in (0..32).step_by(7) {
+            let byte = self.read(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value as usize);
+            }
+        }
+        bail!("invalid length prefix in the table key")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_deps::move_core_types::identifier::Identifier;
+
+    fn ordered<T: serde::Serialize>(layout: &MoveTypeLayout, value: &T) -> Vec<u8> {
+        let bcs_bytes = bcs::to_bytes(value).unwrap();
+        let encoded = encode_ordered_key(layout, &bcs_bytes).unwrap();
+        assert_eq!(decode_ordered_key(layout, &encoded).unwrap(), bcs_bytes);
+        encoded
+    }
+
+    #[test]
+    fn test_u64_keys_keep_numeric_order() {
+        let layout = MoveTypeLayout::U64;
+        let keys = [0u64, 1, 255, 256, 65_536, u64::MAX];
+        for pair in keys.windows(2) {
+            assert!(ordered(&layout, &pair[0]) < ordered(&layout, &pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_string_keys_keep_lexicographic_order() {
+        let layout = ordered_key_layout(&TypeTag::Struct(StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("string").unwrap(),
+            name: Identifier::new("String").unwrap(),
+            type_params: vec![],
+        }))
+        .unwrap();
+        let long = "b".repeat(200);
+        let keys = ["", "a", "a\0", "a\0b", "ab", long.as_str()];
+        for pair in keys.windows(2) {
+            assert!(ordered(&layout, &pair[0]) < ordered(&layout, &pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_vector_keys_keep_element_order() {
+        let layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U64));
+        let keys: [Vec<u64>; 4] = [vec![], vec![1], vec![1, 2], vec![256]];
+        for pair in keys.windows(2) {
+            assert!(ordered(&layout, &pair[0]) < ordered(&layout, &pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_unsupported_key_types() {
+        assert!(ordered_key_layout(&TypeTag::Signer).is_none());
+        assert!(ordered_key_layout(&TypeTag::Struct(StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("object").unwrap(),
+            name: Identifier::new("ObjectCore").unwrap(),
+            type_params: vec![],
+        }))
+        .is_none());
+    }
+}
@@ -0,0 +1,134 @@
+use move_deps::move_core_types::{
+    account_address::AccountAddress,
+    effects::Op,
+    vm_status::{StatusCode, VMStatus},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::size_delta::SizeDelta;
+
+/// The status of the messages growing the storage of an account over the storage quota
+/// of the vm. It is an execution status, so the messages are kept and charged the gas used.
+pub const STORAGE_QUOTA_EXCEEDED: StatusCode = StatusCode::VM_EXTENSION_ERROR;
+
+/// The price of the storage deposit in internal gas units.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StorageDepositPrice {
+    pub per_byte: u64,
+    /// The percentage of the deposit refunded when the bytes are removed.
+    pub refund_percentage: u64,
+}
+
+/// The storage deposit of a message in internal gas units.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageDeposit {
+    /// The deposit for the bytes added, charged to the message.
+    pub charge: u64,
+    /// The part of the deposit refunded to the accounts for the bytes they removed.
+    pub refunds: BTreeMap<AccountAddress, u64>,
+}
+
+/// The number of bytes stored by an account, including the tables it pays for,
+/// and the deposit paid for them.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct StorageUsage {
+    pub size: u64,
+    /// The storage deposit held for `size` in internal gas units.
+    pub deposit: u64,
+}
+
+impl StorageUsage {
+    pub fn new() -> Self {
+        StorageUsage {
+            size: 0,
+            deposit: 0,
+        }
+    }
+
+    /// Apply the size change and return the deposit to charge for the bytes
+    /// added and the one to refund for the bytes removed.
+    ///
+    /// Removed bytes release the deposit pro rata of the size, so an account is
+    /// never refunded more than it deposited even if the price changed since.
+    pub fn apply(&mut self, delta: &SizeDelta, price: &StorageDepositPrice) -> (u64, u64) {
+        let amount = delta.amount as u64;
+        if !delta.is_decrease {
+            let charge = price.per_byte * amount;
+            self.size += amount;
+            self.deposit += charge;
+            return (charge, 0);
+        }
+
+        // usage of the accounts created before the tracking started is not known
+        let removed = amount.min(self.size);
+        let released = match self.size {
+            0 => 0,
+            size => (self.deposit as u128 * removed as u128 / size as u128) as u64,
+        };
+        self.size -= removed;
+        self.deposit -= released;
+
+        let percentage = price.refund_percentage.min(100);
+        (0, (released as u128 * percentage as u128 / 100) as u64)
+    }
+
+    pub fn serialize(&self) -> anyhow::Result<Vec<u8>> {
+        bcs::to_bytes(self).map_err(|_| anyhow::anyhow!("failed to serialize StorageUsage"))
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> anyhow::Result<Self> {
+        bcs::from_bytes(bytes).map_err(|_| anyhow::anyhow!("failed to deserialize StorageUsage"))
+    }
+}
+
+impl fmt::Display for StorageUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "StorageUsage {{\"size\": {}, \"deposit\": {}}}",
+            self.size, self.deposit
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct StorageUsageChangeSet(BTreeMap<AccountAddress, Op<Vec<u8>>>);
+
+impl Default for StorageUsageChangeSet {
+    fn default() -> Self {
+        Self(BTreeMap::default())
+    }
+}
+
+impl StorageUsageChangeSet {
+    pub fn new(
+        storage_usage_changes: BTreeMap<AccountAddress, Op<StorageUsage>>,
+    ) -> Result<StorageUsageChangeSet, VMStatus> {
+        let serialize = |storage_usage: &StorageUsage| {
+            StorageUsage::serialize(storage_usage)
+                .map_err(|_| VMStatus::Error(StatusCode::VALUE_SERIALIZATION_ERROR))
+        };
+
+        let mut change_set: BTreeMap<AccountAddress, Op<Vec<u8>>> = BTreeMap::new();
+        for (addr, op) in storage_usage_changes.into_iter() {
+            let blob_op = match op {
+                Op::New(storage_usage) => Op::New(serialize(&storage_usage)?),
+                Op::Modify(storage_usage) => Op::Modify(serialize(&storage_usage)?),
+                Op::Delete => Op::Delete,
+            };
+            change_set.insert(addr, blob_op);
+        }
+
+        Ok(Self(change_set))
+    }
+
+    pub fn changes(&self) -> &BTreeMap<AccountAddress, Op<Vec<u8>>> {
+        &self.0
+    }
+
+    pub fn into_changes(self) -> BTreeMap<AccountAddress, Op<Vec<u8>>> {
+        self.0
+    }
+}
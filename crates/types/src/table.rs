@@ -1,6 +1,7 @@
 use move_deps::move_core_types::{
     account_address::AccountAddress, effects::Op, language_storage::TypeTag, value::MoveTypeLayout,
 };
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, BTreeSet},
     fmt::Display,
@@ -21,17 +22,35 @@ impl Display for TableHandle {
     }
 }
 
+/// The encoding of the table keys in the storage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyEncoding {
+    /// Keys are stored BCS encoded, so iteration follows the byte order of BCS.
+    Bcs,
+    /// Keys are stored with the order-preserving encoding of [`crate::ordered_key`],
+    /// so iteration follows the natural order of the key values.
+    Ordered,
+}
+
+impl Default for KeyEncoding {
+    fn default() -> Self {
+        KeyEncoding::Bcs
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TableInfo {
     pub key_type: TypeTag,
     pub value_type: TypeTag,
+    pub key_encoding: KeyEncoding,
 }
 
 impl TableInfo {
-    pub fn new(key_type: TypeTag, value_type: TypeTag) -> Self {
+    pub fn new(key_type: TypeTag, value_type: TypeTag, key_encoding: KeyEncoding) -> Self {
         Self {
             key_type,
             value_type,
+            key_encoding,
         }
     }
 }
@@ -1,18 +1,49 @@
-use move_deps::move_core_types::account_address::AccountAddress;
+use move_deps::move_core_types::{account_address::AccountAddress, language_storage::TypeTag};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+use crate::table::{KeyEncoding, TableInfo};
+
+/// The types and the length are `None` for the tables created before they were
+/// stored in the meta, whose metas only hold the payer and the size.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TableMeta {
     pub payer: AccountAddress,
     pub size: usize,
+    pub key_type: Option<TypeTag>,
+    pub value_type: Option<TypeTag>,
+    pub key_encoding: KeyEncoding,
+    pub length: Option<u64>,
+}
+
+/// The layout of the metas stored before the types and the length were tracked.
+#[derive(Deserialize)]
+struct LegacyTableMeta {
+    payer: AccountAddress,
+    size: usize,
 }
 
 impl TableMeta {
-    pub fn new() -> Self {
+    pub fn new(info: &TableInfo) -> Self {
+        TableMeta {
+            payer: AccountAddress::ZERO,
+            size: 0,
+            key_type: Some(info.key_type.clone()),
+            value_type: Some(info.value_type.clone()),
+            key_encoding: info.key_encoding,
+            length: Some(0),
+        }
+    }
+
+    /// The meta of a table whose types and length are not known.
+    pub fn untyped() -> Self {
         TableMeta {
             payer: AccountAddress::ZERO,
             size: 0,
+            key_type: None,
+            value_type: None,
+            key_encoding: KeyEncoding::Bcs,
+            length: None,
         }
     }
 
@@ -20,17 +51,75 @@ impl TableMeta {
         bcs::to_bytes(self).map_err(|_| anyhow::anyhow!("failed to serialize TableMeta"))
     }
 
+    /// Deserialize the meta, falling back to the legacy `{payer, size}` layout.
     pub fn deserialize(bytes: &[u8]) -> anyhow::Result<Self> {
-        bcs::from_bytes(bytes).map_err(|_| anyhow::anyhow!("failed to deserialize TableMeta"))
+        bcs::from_bytes(bytes)
+            .or_else(|_| {
+                bcs::from_bytes::<LegacyTableMeta>(bytes).map(|legacy| TableMeta {
+                    payer: legacy.payer,
+                    size: legacy.size,
+                    ..TableMeta::untyped()
+                })
+            })
+            .map_err(|_| anyhow::anyhow!("failed to deserialize TableMeta"))
     }
 }
 
 impl fmt::Display for TableMeta {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn or_null<T: fmt::Display>(v: &Option<T>) -> String {
+            v.as_ref().map_or("null".to_string(), |v| v.to_string())
+        }
+
         write!(
             f,
-            "TableMeta {{\"payer\": {}, \"size\": {}}}",
-            self.payer, self.size
+            "TableMeta {{\"payer\": {}, \"size\": {}, \"key_type\": {}, \"value_type\": {}, \"key_encoding\": {:?}, \"length\": {}}}",
+            self.payer,
+            self.size,
+            or_null(&self.key_type),
+            or_null(&self.value_type),
+            self.key_encoding,
+            or_null(&self.length)
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_table_meta() {
+        #[derive(Serialize)]
+        struct Legacy {
+            payer: AccountAddress,
+            size: usize,
+        }
+
+        let bytes = bcs::to_bytes(&Legacy {
+            payer: AccountAddress::ONE,
+            size: 10,
+        })
+        .unwrap();
+        let table_meta = TableMeta::deserialize(&bytes).unwrap();
+        assert_eq!(table_meta.payer, AccountAddress::ONE);
+        assert_eq!(table_meta.size, 10);
+        assert!(table_meta.key_type.is_none());
+        assert_eq!(table_meta.key_encoding, KeyEncoding::Bcs);
+        assert_eq!(table_meta.length, None);
+
+        let table_meta = TableMeta {
+            length: Some(3),
+            ..TableMeta::new(&TableInfo::new(
+                TypeTag::U64,
+                TypeTag::Bool,
+                KeyEncoding::Ordered,
+            ))
+        };
+        let table_meta = TableMeta::deserialize(&table_meta.serialize().unwrap()).unwrap();
+        assert_eq!(table_meta.key_type, Some(TypeTag::U64));
+        assert_eq!(table_meta.value_type, Some(TypeTag::Bool));
+        assert_eq!(table_meta.key_encoding, KeyEncoding::Ordered);
+        assert_eq!(table_meta.length, Some(3));
+    }
+}
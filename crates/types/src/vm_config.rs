@@ -0,0 +1,61 @@
+use move_deps::move_core_types::{
+    account_address::AccountAddress, identifier::Identifier, language_storage::StructTag,
+};
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+
+const VM_CONFIG_MODULE: &str = "vm_config";
+
+fn vm_config_struct_tag(name: &str) -> StructTag {
+    StructTag {
+        address: AccountAddress::ONE,
+        module: Identifier::new(VM_CONFIG_MODULE).unwrap(),
+        name: Identifier::new(name).unwrap(),
+        type_params: vec![],
+    }
+}
+
+/// The resource `0x1::vm_config::GasSchedule` holding the gas schedule chosen at genesis.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GasSchedule {
+    pub names: Vec<String>,
+    pub values: Vec<u64>,
+}
+
+impl GasSchedule {
+    pub fn struct_tag() -> StructTag {
+        vm_config_struct_tag("GasSchedule")
+    }
+
+    pub fn into_map(self) -> BTreeMap<String, u64> {
+        self.names.into_iter().zip(self.values).collect()
+    }
+}
+
+impl From<&BTreeMap<String, u64>> for GasSchedule {
+    fn from(gas_schedule: &BTreeMap<String, u64>) -> Self {
+        Self {
+            names: gas_schedule.keys().cloned().collect(),
+            values: gas_schedule.values().copied().collect(),
+        }
+    }
+}
+
+/// The resource `0x1::vm_config::ModulePublishers` holding the accounts allowed
+/// to publish modules.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ModulePublishers {
+    pub publishers: Vec<AccountAddress>,
+}
+
+impl ModulePublishers {
+    pub fn struct_tag() -> StructTag {
+        vm_config_struct_tag("ModulePublishers")
+    }
+}
+
+/// The module of the resources and the functions storing them.
+pub fn vm_config_module_name() -> Identifier {
+    Identifier::new(VM_CONFIG_MODULE).unwrap()
+}
@@ -1,5 +1,6 @@
 use crate::{
-    access_path::AccessPath, table::TableChangeSet, table_meta_change_set::TableMetaChangeSet,
+    access_path::AccessPath, storage_usage::StorageUsageChangeSet, table::TableChangeSet,
+    table_meta_change_set::TableMetaChangeSet,
 };
 use move_deps::move_core_types::{
     effects::{ChangeSet, Op},
@@ -20,6 +21,7 @@ impl WriteSet {
         change_set: ChangeSet,
         table_change_set: TableChangeSet,
         table_meta_change_set: TableMetaChangeSet,
+        storage_usage_change_set: StorageUsageChangeSet,
     ) -> Self {
         let mut write_set: BTreeMap<AccessPath, WriteOp> = BTreeMap::new();
         for (addr, account_changeset) in change_set.into_inner() {
@@ -47,8 +49,17 @@ impl WriteSet {
             write_set.insert(ap, blob_opt);
         }
 
+        for (addr, blob_opt) in storage_usage_change_set.into_changes() {
+            let ap = AccessPath::storage_usage_access_path(addr);
+            write_set.insert(ap, blob_opt);
+        }
+
         Self(write_set)
     }
+
+    pub fn get(&self, access_path: &AccessPath) -> Option<&WriteOp> {
+        self.0.get(access_path)
+    }
 }
 
 impl ::std::iter::FromIterator<(AccessPath, WriteOp)> for WriteSet {
@@ -1,34 +1,138 @@
 use move_deps::{
     move_core_types::{
         account_address::AccountAddress,
+        identifier::Identifier,
+        language_storage::{ModuleId, CORE_CODE_ADDRESS},
+        parser::parse_struct_tag,
         resolver::MoveResolver,
-        value::MoveValue,
+        value::{MoveStruct, MoveTypeLayout, MoveValue},
         vm_status::{StatusCode, VMStatus},
     },
     move_vm_runtime::session::{LoadedFunctionInstantiation, Session},
-    move_vm_types::loaded_data::runtime_types::{CachedStructIndex, Type},
+    move_vm_types::{
+        gas::GasMeter,
+        loaded_data::runtime_types::{CachedStructIndex, Type},
+    },
 };
+use nova_types::errors::NovaVMError;
 use once_cell::sync::Lazy;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 static ALLOWED_STRUCTS: Lazy<BTreeSet<String>> = Lazy::new(|| {
-    ["0x1::string::String"]
-        .iter()
-        .map(|s| s.to_string())
-        .collect()
+    [
+        "0x1::string::String",
+        "0x1::ascii::String",
+        "0x1::fixed_point32::FixedPoint32",
+        "0x1::object::Object",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
 });
 static OPTION_TYPE_NAME: &str = "0x1::option::Option";
 
+/// Allowed structs whose arguments are built by calling a constructor function
+/// of their module with the fields of the given value, instead of taking the raw
+/// BCS bytes, so the invariants checked by the module hold for arguments too.
+static CONSTRUCTORS: Lazy<BTreeMap<String, (ModuleId, Identifier)>> = Lazy::new(|| {
+    [
+        ("0x1::string::String", "string", "utf8"),
+        ("0x1::ascii::String", "ascii", "string"),
+        ("0x1::object::Object", "object", "address_to_object"),
+    ]
+    .iter()
+    .map(|(full_name, module, function)| {
+        (
+            full_name.to_string(),
+            (
+                ModuleId::new(CORE_CODE_ADDRESS, Identifier::new(*module).unwrap()),
+                Identifier::new(*function).unwrap(),
+            ),
+        )
+    })
+    .collect()
+});
+
+/// Struct types allowed as transaction arguments on top of the builtin ones.
+///
+/// Registered structs are identified by their full name (`0x2::module::Name`)
+/// and their arguments are built by a constructor function of the same module,
+/// called with the fields of the given value in declaration order, so a struct
+/// can only be created from an argument the way its module allows it.
+#[derive(Clone, Debug, Default)]
+pub struct AllowedStructs {
+    registered: BTreeMap<String, (ModuleId, Identifier)>,
+}
+
+impl AllowedStructs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow `full_name` as a transaction argument, built with the function
+    /// `constructor` of the struct module. Structs of the framework and
+    /// generic structs cannot be registered.
+    pub fn register(&mut self, full_name: &str, constructor: &str) -> Result<(), NovaVMError> {
+        let tag = parse_struct_tag(full_name)
+            .map_err(|e| NovaVMError::generic_err(format!("invalid struct name: {}", e)))?;
+        if !tag.type_params.is_empty() {
+            return Err(NovaVMError::generic_err(
+                "generic structs cannot be registered",
+            ));
+        }
+        if tag.address == CORE_CODE_ADDRESS {
+            return Err(NovaVMError::generic_err(
+                "framework structs cannot be registered",
+            ));
+        }
+        let constructor = Identifier::new(constructor)
+            .map_err(|e| NovaVMError::generic_err(format!("invalid constructor: {}", e)))?;
+
+        let module = tag.module_id();
+        self.registered.insert(
+            format!("{}::{}", module.short_str_lossless(), tag.name),
+            (module, constructor),
+        );
+        Ok(())
+    }
+
+    /// Disallow a struct previously registered with `register`.
+    pub fn unregister(&mut self, full_name: &str) -> bool {
+        self.registered.remove(full_name).is_some()
+    }
+
+    pub fn contains(&self, full_name: &str) -> bool {
+        ALLOWED_STRUCTS.contains(full_name) || self.is_registered(full_name)
+    }
+
+    pub fn is_registered(&self, full_name: &str) -> bool {
+        self.registered.contains_key(full_name)
+    }
+
+    /// Returns the module and the name of the function building `full_name`
+    /// arguments, if any.
+    fn constructor(&self, full_name: &str) -> Option<&(ModuleId, Identifier)> {
+        CONSTRUCTORS
+            .get(full_name)
+            .or_else(|| self.registered.get(full_name))
+    }
+}
+
 /// Validate and generate args with senders and non-signer arguments
 ///
 /// validation includes:
-/// 1. number of signers is same as the number of senders
+/// 1. number of signers is same as the number of senders, or the number of
+///    senders plus one when the fee payer is given as the last signer
 /// 2. check arg types are allowed after signers
+/// 3. build args of struct types with a constructor through the constructor
 ///
 /// after validation, add senders and non-signer arguments to generate the final args
-pub(crate) fn validate_combine_signer_and_txn_args<S: MoveResolver>(
-    session: &Session<S>,
-    senders: Vec<AccountAddress>,
+pub(crate) fn validate_combine_signer_and_txn_args<S: MoveResolver, G: GasMeter>(
+    session: &mut Session<S>,
+    gas_meter: &mut G,
+    allowed_structs: &AllowedStructs,
+    mut senders: Vec<AccountAddress>,
+    fee_payer: Option<AccountAddress>,
     args: Vec<Vec<u8>>,
     func: &LoadedFunctionInstantiation,
 ) -> Result<Vec<Vec<u8>>, VMStatus> {
@@ -47,7 +151,7 @@ pub(crate) fn validate_combine_signer_and_txn_args<S: MoveResolver>(
     }
     // validate all non_signer params
     for ty in func.parameters[signer_param_cnt..].iter() {
-        if !is_valid_txn_arg(session, ty) {
+        if !is_valid_txn_arg(session, allowed_structs, ty) {
             return Err(VMStatus::Error(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE));
         }
     }
@@ -55,12 +159,26 @@ pub(crate) fn validate_combine_signer_and_txn_args<S: MoveResolver>(
     if (signer_param_cnt + args.len()) != func.parameters.len() {
         return Err(VMStatus::Error(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH));
     }
+
+    let args = func.parameters[signer_param_cnt..]
+        .iter()
+        .zip(args)
+        .map(|(ty, arg)| construct_arg(session, gas_meter, allowed_structs, ty, arg))
+        .collect::<Result<Vec<_>, _>>()?;
+
     // if function doesn't require signer, we reuse txn args
     // if the function require signer, we check senders number same as signers
     // and then combine senders with txn args.
     let combined_args = if signer_param_cnt == 0 {
         args
     } else {
+        // the fee payer is available to the functions asking for one more signer
+        if let Some(fee_payer) = fee_payer {
+            if senders.len() + 1 == signer_param_cnt {
+                senders.push(fee_payer);
+            }
+        }
+
         // the number of txn senders should be the same number of signers
         if senders.len() != signer_param_cnt {
             return Err(VMStatus::Error(
@@ -76,31 +194,174 @@ pub(crate) fn validate_combine_signer_and_txn_args<S: MoveResolver>(
     Ok(combined_args)
 }
 
+fn type_layout<S: MoveResolver>(
+    session: &Session<S>,
+    ty: &Type,
+) -> Result<MoveTypeLayout, VMStatus> {
+    session
+        .get_type_tag(ty)
+        .and_then(|tag| session.get_type_layout(&tag))
+        .map_err(|e| e.into_vm_status())
+}
+
+fn deserialize_arg<S: MoveResolver>(
+    session: &Session<S>,
+    ty: &Type,
+    arg: &[u8],
+) -> Result<MoveValue, VMStatus> {
+    let layout = type_layout(session, ty)?;
+    MoveValue::simple_deserialize(arg, &layout)
+        .map_err(|_| VMStatus::Error(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT))
+}
+
+/// Rebuild the arg of `ty` with the constructors of the structs it contains.
+fn construct_arg<S: MoveResolver, G: GasMeter>(
+    session: &mut Session<S>,
+    gas_meter: &mut G,
+    allowed_structs: &AllowedStructs,
+    ty: &Type,
+    arg: Vec<u8>,
+) -> Result<Vec<u8>, VMStatus> {
+    if !contains_struct(session, ty, &|full_name| {
+        allowed_structs.constructor(full_name).is_some()
+    }) {
+        return Ok(arg);
+    }
+
+    let value = deserialize_arg(session, ty, &arg)?;
+    construct_value(session, gas_meter, allowed_structs, ty, value)?
+        .simple_serialize()
+        .ok_or(VMStatus::Error(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT))
+}
+
+fn construct_value<S: MoveResolver, G: GasMeter>(
+    session: &mut Session<S>,
+    gas_meter: &mut G,
+    allowed_structs: &AllowedStructs,
+    ty: &Type,
+    value: MoveValue,
+) -> Result<MoveValue, VMStatus> {
+    let (idx, type_params, value) = match (ty, value) {
+        (Type::Vector(inner), MoveValue::Vector(values)) => {
+            return values
+                .into_iter()
+                .map(|v| construct_value(session, gas_meter, allowed_structs, inner, v))
+                .collect::<Result<Vec<_>, _>>()
+                .map(MoveValue::Vector);
+        }
+        (Type::Struct(idx), MoveValue::Struct(value)) => (idx, vec![], value),
+        (Type::StructInstantiation(idx, type_params), MoveValue::Struct(value)) => {
+            (idx, type_params.clone(), value)
+        }
+        (_, value) => return Ok(value),
+    };
+    let fields = match value {
+        MoveStruct::Runtime(fields) => fields,
+        _ => return Err(VMStatus::Error(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)),
+    };
+
+    let full_name = struct_full_name(session, idx).unwrap_or_default();
+    if full_name == OPTION_TYPE_NAME {
+        // `Option<T>` is a struct holding a `vector<T>`
+        let vec_type = Type::Vector(Box::new(type_params[0].clone()));
+        return fields
+            .into_iter()
+            .map(|v| construct_value(session, gas_meter, allowed_structs, &vec_type, v))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|fields| MoveValue::Struct(MoveStruct::Runtime(fields)));
+    }
+
+    let (module, function) = match allowed_structs.constructor(&full_name) {
+        Some(constructor) => constructor,
+        None => return Ok(MoveValue::Struct(MoveStruct::Runtime(fields))),
+    };
+    let ty_args = type_params
+        .iter()
+        .map(|ty| session.get_type_tag(ty))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.into_vm_status())?;
+    let args = fields
+        .iter()
+        .map(|field| {
+            field
+                .simple_serialize()
+                .ok_or(VMStatus::Error(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut res = session
+        .execute_function_bypass_visibility(module, function, ty_args, args, gas_meter)
+        .map_err(|e| e.into_vm_status())?;
+    match res.return_values.pop() {
+        Some((bytes, _)) => deserialize_arg(session, ty, &bytes),
+        None => Err(VMStatus::Error(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)),
+    }
+}
+
+fn struct_full_name<S: MoveResolver>(
+    session: &Session<S>,
+    idx: &CachedStructIndex,
+) -> Option<String> {
+    session
+        .get_struct_type(*idx)
+        .map(|st| format!("{}::{}", st.module.short_str_lossless(), st.name))
+}
+
 fn is_valid_struct_txn_arg<S: MoveResolver>(
     session: &Session<S>,
+    allowed_structs: &AllowedStructs,
     idx: &CachedStructIndex,
     type_params: &Vec<Type>,
 ) -> bool {
-    match session.get_struct_type(*idx) {
-        Some(st) => {
-            let full_name = format!("{}::{}", st.module.short_str_lossless(), st.name);
+    match struct_full_name(session, idx) {
+        Some(full_name) => {
             if full_name == OPTION_TYPE_NAME {
-                type_params.len() == 1 && is_valid_txn_arg(session, type_params.first().unwrap())
+                type_params.len() == 1
+                    && is_valid_txn_arg(session, allowed_structs, type_params.first().unwrap())
             } else {
-                ALLOWED_STRUCTS.contains(&full_name)
+                allowed_structs.contains(&full_name)
             }
         }
         None => false,
     }
 }
 
-fn is_valid_txn_arg<S: MoveResolver>(session: &Session<S>, typ: &Type) -> bool {
+fn is_valid_txn_arg<S: MoveResolver>(
+    session: &Session<S>,
+    allowed_structs: &AllowedStructs,
+    typ: &Type,
+) -> bool {
     use move_deps::move_vm_types::loaded_data::runtime_types::Type::*;
     match typ {
         Bool | U8 | U64 | U128 | Address => true,
-        Vector(inner) => is_valid_txn_arg(session, inner),
-        Struct(idx) => is_valid_struct_txn_arg(session, idx, &Vec::default()),
-        StructInstantiation(idx, inner_types) => is_valid_struct_txn_arg(session, idx, inner_types),
+        Vector(inner) => is_valid_txn_arg(session, allowed_structs, inner),
+        Struct(idx) => is_valid_struct_txn_arg(session, allowed_structs, idx, &Vec::default()),
+        StructInstantiation(idx, inner_types) => {
+            is_valid_struct_txn_arg(session, allowed_structs, idx, inner_types)
+        }
         Signer | Reference(_) | MutableReference(_) | TyParam(_) => false,
     }
 }
+
+/// Returns `true` if `typ` is, or is built from, a struct matching `pred`.
+fn contains_struct<S: MoveResolver>(
+    session: &Session<S>,
+    typ: &Type,
+    pred: &dyn Fn(&str) -> bool,
+) -> bool {
+    use move_deps::move_vm_types::loaded_data::runtime_types::Type::*;
+    match typ {
+        Vector(inner) => contains_struct(session, inner, pred),
+        Struct(idx) => struct_full_name(session, idx)
+            .map(|full_name| pred(&full_name))
+            .unwrap_or(false),
+        StructInstantiation(idx, inner_types) => {
+            struct_full_name(session, idx)
+                .map(|full_name| pred(&full_name))
+                .unwrap_or(false)
+                || inner_types
+                    .iter()
+                    .any(|ty| contains_struct(session, ty, pred))
+        }
+        _ => false,
+    }
+}
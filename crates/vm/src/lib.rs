@@ -2,12 +2,14 @@
 
 mod session;
 
+pub use crate::arguments::AllowedStructs;
 pub use crate::backend::*;
 pub use crate::nova_vm::NovaVM;
 pub mod backend;
 
 mod arguments;
 mod nova_vm;
+mod verifier;
 
 #[cfg(test)]
 pub mod test_utils;
@@ -1,7 +1,11 @@
 use anyhow::Result;
 use log::info;
 use move_deps::move_core_types::{
-    resolver::MoveResolver,
+    effects::Op,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag},
+    resolver::{MoveResolver, ResourceResolver},
+    value::MoveValue,
     vm_status::{KeptVMStatus, VMStatus},
 };
 use move_deps::{
@@ -22,36 +26,50 @@ use move_deps::{
 use nova_stdlib::{
     compile_move_nursery_modules, compile_move_stdlib_modules, compile_nova_stdlib_modules,
 };
+use serde::de::DeserializeOwned;
 use std::{
     collections::{BTreeMap, BTreeSet},
     sync::Arc,
 };
 
 use nova_gas::AbstractValueSizeGasParameters;
-use nova_gas::{Gas, InitialGasSchedule, NativeGasParameters, NovaGasMeter, NovaGasParameters};
+use nova_gas::{
+    FromOnChainGasSchedule, Gas, GasBalance, InitialGasSchedule, NativeGasParameters, NovaGasMeter,
+    NovaGasParameters,
+};
 use nova_natives::all_natives;
 use nova_natives::{
+    bank::{BankBridge, NativeBankContext},
     block::BlockInfoResolver,
     block::NativeBlockContext,
     code::{NativeCodeContext, PublishRequest},
     table::{NativeTableContext, TableResolver},
+    transaction_context::NativeTransactionContext,
 };
 use nova_storage::{
     size::size_resolver::SizeResolver, state_view::StateView, state_view_impl::StateViewImpl,
+    storage_usage::storage_usage_resolver::StorageUsageResolver,
     table_meta::table_meta_resolver::TableMetaResolver, table_view::TableView,
     table_view_impl::TableViewImpl,
 };
 use nova_types::{
+    access_path::AccessPath,
+    bank_change_set::BankChangeSet,
+    entry_function::EntryFunction,
     errors::NovaVMError,
+    genesis::{GenesisConfig, StdlibBundle, StdlibConfig},
     message::{Message, MessageOutput, MessagePayload, MessageStatus},
     module::ModuleBundle,
     size_change_set::SizeChangeSet,
+    storage_usage::{StorageDepositPrice, StorageUsage, STORAGE_QUOTA_EXCEEDED},
+    vm_config::{vm_config_module_name, GasSchedule, ModulePublishers},
     write_set::WriteSet,
 };
 
 use crate::{
-    arguments::validate_combine_signer_and_txn_args,
+    arguments::{validate_combine_signer_and_txn_args, AllowedStructs},
     session::{empty_session_output, SessionExt, SessionOutput},
+    verifier::verify_randomness_usage,
 };
 
 #[derive(Clone)]
@@ -59,6 +77,9 @@ use crate::{
 pub struct NovaVM {
     move_vm: Arc<MoveVM>,
     gas_params: NovaGasParameters,
+    allowed_structs: AllowedStructs,
+    storage_quota: Option<u64>,
+    module_publishers: Option<BTreeSet<AccountAddress>>,
 }
 
 impl NovaVM {
@@ -76,14 +97,118 @@ impl NovaVM {
         Self {
             move_vm: Arc::new(inner),
             gas_params: NovaGasParameters::initial(),
+            allowed_structs: AllowedStructs::new(),
+            storage_quota: None,
+            module_publishers: None,
+        }
+    }
+
+    /// Limit the number of bytes stored by an account, including the tables it pays for.
+    /// Messages growing the storage of an account over the quota fail with the
+    /// `STORAGE_QUOTA_EXCEEDED` status, which charges the gas used and discards the writes.
+    pub fn set_storage_quota(&mut self, storage_quota: Option<u64>) {
+        self.storage_quota = storage_quota;
+    }
+
+    pub fn storage_quota(&self) -> Option<u64> {
+        self.storage_quota
+    }
+
+    /// Allow the struct `full_name` (`0x2::module::Name`) as an argument of
+    /// scripts and entry functions executed by this vm. The arguments are built
+    /// by the function `constructor` of the struct module.
+    pub fn register_txn_arg_struct(
+        &mut self,
+        full_name: &str,
+        constructor: &str,
+    ) -> Result<(), NovaVMError> {
+        self.allowed_structs.register(full_name, constructor)
+    }
+
+    /// Disallow a struct registered with `register_txn_arg_struct`.
+    pub fn unregister_txn_arg_struct(&mut self, full_name: &str) -> bool {
+        self.allowed_structs.unregister(full_name)
+    }
+
+    pub fn allowed_txn_arg_structs(&self) -> &AllowedStructs {
+        &self.allowed_structs
+    }
+
+    /// Limit the accounts which can publish modules, by a message or by `0x1::code`.
+    /// `None` allows every account to publish.
+    pub fn set_module_publishers(&mut self, module_publishers: Option<BTreeSet<AccountAddress>>) {
+        self.module_publishers = module_publishers;
+    }
+
+    pub fn module_publishers(&self) -> Option<&BTreeSet<AccountAddress>> {
+        self.module_publishers.as_ref()
+    }
+
+    /// Replace the gas parameters of the instructions and the natives with the ones of
+    /// `gas_schedule`, which has to contain every parameter of the on-chain gas schedule.
+    pub fn set_gas_schedule(
+        &mut self,
+        gas_schedule: &BTreeMap<String, u64>,
+    ) -> Result<(), NovaVMError> {
+        let gas_params = gas_params_from_schedule(gas_schedule)?;
+        self.set_gas_params(gas_params);
+        Ok(())
+    }
+
+    fn set_gas_params(&mut self, gas_params: NovaGasParameters) {
+        let inner = MoveVM::new(all_natives(
+            gas_params.natives.move_stdlib.clone(),
+            gas_params.natives.nova_stdlib.clone(),
+            gas_params.natives.table.clone(),
+            gas_params.misc.abs_val.clone(),
+        ))
+        .expect("should be able to create Move VM; check if there are duplicated natives");
+
+        self.move_vm = Arc::new(inner);
+        self.gas_params = gas_params;
+    }
+
+    /// Configure the vm again with the gas schedule and the module publishers stored
+    /// in `0x1::vm_config` by `initialize_with_config`, when the chain restarts.
+    /// The initial gas schedule is kept and everyone can publish modules when they
+    /// are not stored.
+    pub fn load_vm_config<S: StateView>(
+        &mut self,
+        state_view_impl: &StateViewImpl<'_, S>,
+    ) -> Result<(), NovaVMError> {
+        let gas_params = match load_vm_config_resource::<S, GasSchedule>(
+            state_view_impl,
+            GasSchedule::struct_tag(),
+        )? {
+            Some(gas_schedule) => Some(gas_params_from_schedule(&gas_schedule.into_map())?),
+            None => None,
+        };
+        let module_publishers = load_vm_config_resource::<S, ModulePublishers>(
+            state_view_impl,
+            ModulePublishers::struct_tag(),
+        )?;
+
+        if let Some(gas_params) = gas_params {
+            self.set_gas_params(gas_params);
         }
+        self.set_module_publishers(
+            module_publishers
+                .map(|module_publishers| module_publishers.publishers.into_iter().collect()),
+        );
+        Ok(())
     }
 
-    fn create_session<'r, S: MoveResolver + SizeResolver + TableMetaResolver, T: TableResolver>(
+    fn create_session<
+        'r,
+        S: MoveResolver + SizeResolver + TableMetaResolver + StorageUsageResolver,
+        T: TableResolver,
+    >(
         &self,
         resolver: &'r S,
         table_resolver: &'r mut T,
         session_id: Vec<u8>,
+        senders: Vec<AccountAddress>,
+        gas_balance: Option<GasBalance>,
     ) -> SessionExt<'r, '_, S> {
         let mut extensions = NativeContextExtensions::default();
         let txn_hash: [u8; 32] = session_id
@@ -91,31 +216,40 @@ impl NovaVM {
             .expect("HashValue should convert to [u8; 32]");
         extensions.add(NativeTableContext::new(txn_hash, table_resolver));
         extensions.add(NativeCodeContext::default());
+        extensions.add(NativeTransactionContext::new(
+            txn_hash,
+            senders,
+            gas_balance,
+        ));
 
         self.move_vm.flush_loader_cache_if_invalidated();
         SessionExt::new(
             self.move_vm
                 .new_session_with_extensions(resolver, extensions),
             resolver,
+            self.gas_params.txn.storage_deposit_price(),
         )
     }
 
     fn create_session_with_api<
         'r,
-        S: MoveResolver + SizeResolver + TableMetaResolver,
+        S: MoveResolver + SizeResolver + TableMetaResolver + StorageUsageResolver,
         T: TableResolver,
-        A: BlockInfoResolver,
+        A: BlockInfoResolver + BankBridge,
     >(
         &self,
         resolver: &'r S,
         table_resolver: &'r mut T,
         api: &'r A,
         session_id: Vec<u8>,
+        senders: Vec<AccountAddress>,
+        gas_balance: Option<GasBalance>,
     ) -> SessionExt<'r, '_, S> {
-        let mut session = self.create_session(resolver, table_resolver, session_id);
-        session
-            .get_native_extensions()
-            .add(NativeBlockContext::new(api));
+        let mut session =
+            self.create_session(resolver, table_resolver, session_id, senders, gas_balance);
+        let extensions = session.get_native_extensions();
+        extensions.add(NativeBlockContext::new(api));
+        session.add_bank_context(NativeBankContext::new(api));
         session
     }
 
@@ -123,12 +257,198 @@ impl NovaVM {
         &mut self,
         state_view_impl: &StateViewImpl<'_, S>,
         table_view_impl: &mut TableViewImpl<'_, T>,
+        stdlib_config: &StdlibConfig,
         custom_module_bundle: Option<ModuleBundle>,
     ) -> Result<(VMStatus, MessageOutput, Option<SerializedReturnValues>), NovaVMError> {
-        // publish move_stdlib and nova_stdlib modules
-        let mut compiled_modules = compile_move_stdlib_modules();
-        compiled_modules.append(&mut compile_move_nursery_modules());
-        compiled_modules.append(&mut compile_nova_stdlib_modules());
+        let mut session =
+            self.create_session(state_view_impl, table_view_impl, vec![0; 32], vec![], None);
+        // genesis modules are not subject to the storage deposit
+        session.set_storage_deposit_price(StorageDepositPrice::default());
+
+        self.publish_genesis_modules(&mut session, stdlib_config, custom_module_bundle)?;
+
+        let session_output = session.finish()?;
+
+        let output = get_message_output(
+            session_output,
+            Gas::zero(),
+            0,
+            BTreeMap::new(),
+            KeptVMStatus::Executed,
+        )
+        .map_err(|e| NovaVMError::from(e))?;
+        Ok((VMStatus::Executed, output, None))
+    }
+
+    /// Initialize the chain with `genesis_config`. The modules are published and the
+    /// accounts, coins and init functions are applied in a single session, which results
+    /// in a single write set. The gas schedule and the module publishers are stored in
+    /// `0x1::vm_config` by the same session, and configure the vm once it succeeded.
+    pub fn initialize_with_config<S: StateView, T: TableView, A: BlockInfoResolver + BankBridge>(
+        &mut self,
+        state_view_impl: &StateViewImpl<'_, S>,
+        table_view_impl: &mut TableViewImpl<'_, T>,
+        api: &A,
+        genesis_config: GenesisConfig,
+    ) -> Result<(VMStatus, MessageOutput, Option<SerializedReturnValues>), NovaVMError> {
+        let gas_params = match &genesis_config.gas_schedule {
+            Some(gas_schedule) => Some(gas_params_from_schedule(gas_schedule)?),
+            None => None,
+        };
+
+        let mut session = self.create_session_with_api(
+            state_view_impl,
+            table_view_impl,
+            api,
+            vec![0; 32],
+            vec![],
+            None,
+        );
+        // genesis modules are not subject to the storage deposit
+        session.set_storage_deposit_price(StorageDepositPrice::default());
+
+        self.publish_genesis_modules(
+            &mut session,
+            &genesis_config.stdlib,
+            genesis_config.module_bundle,
+        )?;
+
+        // Store the vm config before running the genesis functions, so they can read it.
+        // It is applied to the vm only once the genesis succeeded, which does not change
+        // the genesis itself: its functions run unmetered and it publishes modules
+        // without the publisher check.
+        if let Some(gas_schedule) = &genesis_config.gas_schedule {
+            let gas_schedule = GasSchedule::from(gas_schedule);
+            self.store_vm_config(
+                &mut session,
+                "store_gas_schedule",
+                vec![
+                    bcs::to_bytes(&gas_schedule.names).unwrap(),
+                    bcs::to_bytes(&gas_schedule.values).unwrap(),
+                ],
+            )?;
+        }
+        if let Some(module_publishers) = &genesis_config.module_publishers {
+            self.store_vm_config(
+                &mut session,
+                "store_module_publishers",
+                vec![bcs::to_bytes(module_publishers).unwrap()],
+            )?;
+        }
+
+        for account in genesis_config.accounts {
+            let create_account = EntryFunction::new(
+                ModuleId::new(AccountAddress::ONE, Identifier::new("account").unwrap()),
+                Identifier::new("create_account").unwrap(),
+                vec![],
+                vec![],
+            );
+            self.execute_genesis_function(&mut session, account, &create_account)?;
+        }
+
+        for coin in genesis_config.coins {
+            let coin_module = ModuleId::new(AccountAddress::ONE, Identifier::new("coin").unwrap());
+            let ty_args = vec![coin.coin_type];
+
+            let native_initialize = EntryFunction::new(
+                coin_module.clone(),
+                Identifier::new("native_initialize").unwrap(),
+                ty_args.clone(),
+                vec![
+                    bcs::to_bytes(&coin.name).unwrap(),
+                    bcs::to_bytes(&coin.symbol).unwrap(),
+                    bcs::to_bytes(&coin.decimals).unwrap(),
+                ],
+            );
+            self.execute_genesis_function(&mut session, AccountAddress::ONE, &native_initialize)?;
+
+            for balance in coin.balances {
+                let native_mint_to = EntryFunction::new(
+                    coin_module.clone(),
+                    Identifier::new("native_mint_to").unwrap(),
+                    ty_args.clone(),
+                    vec![
+                        bcs::to_bytes(&balance.address).unwrap(),
+                        bcs::to_bytes(&balance.amount).unwrap(),
+                    ],
+                );
+                self.execute_genesis_function(&mut session, AccountAddress::ONE, &native_mint_to)?;
+            }
+        }
+
+        for init_function in genesis_config.init_functions {
+            self.execute_genesis_function(&mut session, AccountAddress::ONE, &init_function)?;
+        }
+
+        let session_output = session.finish()?;
+
+        let output = get_message_output(
+            session_output,
+            Gas::zero(),
+            0,
+            BTreeMap::new(),
+            KeptVMStatus::Executed,
+        )
+        .map_err(|e| NovaVMError::from(e))?;
+
+        if let Some(gas_params) = gas_params {
+            self.set_gas_params(gas_params);
+        }
+        self.set_module_publishers(
+            genesis_config
+                .module_publishers
+                .map(|publishers| publishers.into_iter().collect()),
+        );
+        Ok((VMStatus::Executed, output, None))
+    }
+
+    /// Store a part of the vm configuration with a function of `0x1::vm_config`,
+    /// which only the vm can call.
+    fn store_vm_config<S>(
+        &self,
+        session: &mut SessionExt<'_, '_, S>,
+        function: &str,
+        args: Vec<Vec<u8>>,
+    ) -> Result<(), NovaVMError>
+    where
+        S: MoveResolver + SizeResolver + TableMetaResolver + StorageUsageResolver,
+    {
+        let module = ModuleId::new(AccountAddress::ONE, vm_config_module_name());
+        let function = Identifier::new(function).unwrap();
+        let mut signer_and_args = vec![MoveValue::Signer(AccountAddress::ONE)
+            .simple_serialize()
+            .unwrap()];
+        signer_and_args.extend(args);
+
+        session
+            .execute_function_bypass_visibility(
+                &module,
+                &function,
+                vec![],
+                signer_and_args,
+                &mut UnmeteredGasMeter,
+            )
+            .map_err(|e| {
+                NovaVMError::generic_err(format!(
+                    "failed to store the vm config with {}::{}: {:?}",
+                    module, function, e
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    /// Publish the standard library modules selected by `stdlib_config` with the custom modules.
+    fn publish_genesis_modules<S>(
+        &self,
+        session: &mut SessionExt<'_, '_, S>,
+        stdlib_config: &StdlibConfig,
+        custom_module_bundle: Option<ModuleBundle>,
+    ) -> Result<(), NovaVMError>
+    where
+        S: MoveResolver + SizeResolver + TableMetaResolver + StorageUsageResolver,
+    {
+        let mut compiled_modules = compile_stdlib_modules(stdlib_config)?;
 
         if let Some(module_bundle) = custom_module_bundle {
             let custom_modules = self
@@ -137,13 +457,29 @@ impl NovaVM {
             compiled_modules.extend(custom_modules.into_iter());
         }
 
-        let mut session = self.create_session(state_view_impl, table_view_impl, vec![0; 32]);
+        if compiled_modules.is_empty() {
+            return Ok(());
+        }
+
+        // the genesis modules can only depend on each other
         let modules = Modules::new(&compiled_modules);
+        for module in &compiled_modules {
+            modules
+                .get_immediate_dependencies(&module.self_id())
+                .map_err(|e| {
+                    NovaVMError::generic_err(format!(
+                        "missing dependency of {}: {}",
+                        module.self_id(),
+                        e
+                    ))
+                })?;
+        }
+
         let dep_graph = modules.compute_dependency_graph();
         let mut addr: Option<AccountAddress> = None;
         let modules = dep_graph
             .compute_topological_order()
-            .unwrap()
+            .map_err(|e| NovaVMError::generic_err(e.to_string()))?
             .map(|m| {
                 addr = Some(*m.self_id().address());
                 let mut bytes = vec![];
@@ -161,15 +497,55 @@ impl NovaVM {
                 })?;
 
         self.move_vm.mark_loader_cache_as_invalid();
+        Ok(())
+    }
 
-        let session_output = session.finish()?;
+    /// Execute an entry function of the genesis with `sender` as the signer.
+    fn execute_genesis_function<S>(
+        &self,
+        session: &mut SessionExt<'_, '_, S>,
+        sender: AccountAddress,
+        entry_fn: &EntryFunction,
+    ) -> Result<(), NovaVMError>
+    where
+        S: MoveResolver + SizeResolver + TableMetaResolver + StorageUsageResolver,
+    {
+        let function = session
+            .load_function(entry_fn.module(), entry_fn.function(), entry_fn.ty_args())
+            .map_err(|e| e.into_vm_status())?;
+        let args = validate_combine_signer_and_txn_args(
+            session,
+            &mut UnmeteredGasMeter,
+            &self.allowed_structs,
+            vec![sender],
+            None,
+            entry_fn.args().to_vec(),
+            &function,
+        )?;
 
-        let output = get_message_output(session_output, Gas::zero(), KeptVMStatus::Executed)
-            .map_err(|e| NovaVMError::from(e))?;
-        Ok((VMStatus::Executed, output, None))
+        session
+            .execute_entry_function(
+                entry_fn.module(),
+                entry_fn.function(),
+                entry_fn.ty_args().to_vec(),
+                args,
+                &mut UnmeteredGasMeter,
+            )
+            .map_err(|e| {
+                self.move_vm.mark_loader_cache_as_invalid();
+                info!(
+                    "genesis function {}::{} failed: {:?}",
+                    entry_fn.module(),
+                    entry_fn.function(),
+                    e
+                );
+                NovaVMError::from(e.into_vm_status())
+            })?;
+
+        Ok(())
     }
 
-    pub fn execute_message<S: StateView, T: TableView, A: BlockInfoResolver>(
+    pub fn execute_message<S: StateView, T: TableView, A: BlockInfoResolver + BankBridge>(
         &mut self,
         msg: Message,
         state_view_impl: &StateViewImpl<'_, S>,
@@ -178,6 +554,7 @@ impl NovaVM {
         gas_limit: Gas,
     ) -> Result<(VMStatus, MessageOutput, Option<SerializedReturnValues>), NovaVMError> {
         let sender = msg.sender();
+        let fee_payer = msg.fee_payer();
 
         let gas_params = self.gas_params.clone();
         let mut gas_meter = NovaGasMeter::new(gas_params, gas_limit);
@@ -197,6 +574,7 @@ impl NovaVM {
                 self.execute_script_or_entry_function(
                     msg.session_id().to_vec(),
                     sender,
+                    fee_payer,
                     state_view_impl,
                     table_view_impl,
                     api,
@@ -220,8 +598,8 @@ impl NovaVM {
         // Charge gas for error handling
         let gas_used = gas_limit.checked_sub(gas_meter.balance()).unwrap();
 
-        match result {
-            Ok(status_and_output) => Ok(status_and_output),
+        let (status, message_output, retval) = match result {
+            Ok(status_and_output) => status_and_output,
             Err(err) => {
                 let txn_status = MessageStatus::from(err.clone());
 
@@ -230,9 +608,69 @@ impl NovaVM {
                     false => self.failed_message_cleanup(err, gas_used),
                 };
 
-                Ok((status, message_output, None))
+                (status, message_output, None)
+            }
+        };
+
+        // The messages growing the storage over the quota are kept as failed, so the
+        // gas used is still charged, but their write set is discarded
+        let (status, message_output, retval) = match status {
+            VMStatus::Executed => match self.storage_quota_exceeded_by(&message_output)? {
+                Some(addr) => {
+                    info!("storage quota exceeded by {}", addr);
+                    let gas_used = message_output
+                        .gas_used()
+                        .saturating_sub(message_output.storage_fee());
+                    let (status, message_output) = self.failed_message_cleanup(
+                        VMStatus::Error(STORAGE_QUOTA_EXCEEDED),
+                        Gas::new(gas_used),
+                    );
+                    (status, message_output, None)
+                }
+                None => (status, message_output, retval),
+            },
+            _ => (status, message_output, retval),
+        };
+
+        // Charge the fee payer instead of the sender for sponsored messages
+        let message_output = match fee_payer {
+            Some(fee_payer) => message_output.charge_fee_payer(fee_payer),
+            None => message_output,
+        };
+
+        Ok((status, message_output, retval))
+    }
+
+    /// Find an account whose storage is grown over the storage quota by the output.
+    /// The accounts over the quota are still allowed to free their storage.
+    fn storage_quota_exceeded_by(
+        &self,
+        output: &MessageOutput,
+    ) -> Result<Option<AccountAddress>, NovaVMError> {
+        let storage_quota = match self.storage_quota {
+            Some(storage_quota) => storage_quota,
+            None => return Ok(None),
+        };
+
+        for (addr, size_delta) in output.size_change_set().changes() {
+            if size_delta.is_decrease || size_delta.amount == 0 {
+                continue;
+            }
+
+            let storage_usage = match output
+                .write_set()
+                .get(&AccessPath::storage_usage_access_path(*addr))
+            {
+                Some(Op::New(blob)) | Some(Op::Modify(blob)) => StorageUsage::deserialize(blob)
+                    .map_err(|e| NovaVMError::generic_err(e.to_string()))?,
+                _ => continue,
+            };
+            if storage_usage.size > storage_quota {
+                return Ok(Some(*addr));
             }
         }
+
+        Ok(None)
     }
 
     fn publish_module_bundle<S: StateView, T: TableView>(
@@ -244,7 +682,22 @@ impl NovaVM {
         modules: &ModuleBundle,
         gas_meter: &mut NovaGasMeter,
     ) -> Result<(VMStatus, MessageOutput, Option<SerializedReturnValues>), VMStatus> {
-        let mut session = self.create_session(state_view_impl, table_view_impl, session_id);
+        let mut session = self.create_session(
+            state_view_impl,
+            table_view_impl,
+            session_id,
+            vec![sender],
+            Some(gas_meter.gas_balance()),
+        );
+
+        self.check_module_publisher(&sender)
+            .map_err(|e| e.into_vm_status())?;
+
+        // Check randomness usage of modules which opted in to it
+        let compiled_modules = self
+            .deserialize_module_bundle(modules)
+            .map_err(|e| e.into_vm_status())?;
+        verify_randomness_usage(&compiled_modules).map_err(|e| e.into_vm_status())?;
 
         let module_bin_list = modules.clone().into_inner();
         session
@@ -262,31 +715,42 @@ impl NovaVM {
         Ok((status, output, None))
     }
 
-    fn execute_script_or_entry_function<S: StateView, T: TableView, A: BlockInfoResolver>(
+    fn execute_script_or_entry_function<
+        S: StateView,
+        T: TableView,
+        A: BlockInfoResolver + BankBridge,
+    >(
         &self,
         session_id: Vec<u8>,
         sender: Option<AccountAddress>,
+        fee_payer: Option<AccountAddress>,
         state_view_impl: &StateViewImpl<'_, S>,
         table_view_impl: &mut TableViewImpl<'_, T>,
         api: &A,
         payload: &MessagePayload,
         gas_meter: &mut NovaGasMeter,
     ) -> Result<(VMStatus, MessageOutput, Option<SerializedReturnValues>), VMStatus> {
-        let mut session =
-            self.create_session_with_api(state_view_impl, table_view_impl, api, session_id.clone());
-
         let senders = match sender {
             Some(s) => vec![s],
             None => vec![],
         };
 
+        let mut session = self.create_session_with_api(
+            state_view_impl,
+            table_view_impl,
+            api,
+            session_id,
+            senders.clone(),
+            Some(gas_meter.gas_balance()),
+        );
+
         let res = match payload {
                 MessagePayload::Script(script) => {
                     // we only use the ok path, let move vm handle the wrong path.
                     // let Ok(s) = CompiledScript::deserialize(script.code());
                     let loaded_func =
                         session.load_script(script.code(), script.ty_args().to_vec())?;
-                    let args = validate_combine_signer_and_txn_args(&session, senders, script.args().to_vec(), &loaded_func)?;
+                    let args = validate_combine_signer_and_txn_args(&mut session, gas_meter, &self.allowed_structs, senders, fee_payer, script.args().to_vec(), &loaded_func)?;
 
                     session.execute_script(
                         script.code().to_vec(),
@@ -301,7 +765,7 @@ impl NovaVM {
                         entry_fn.function(),
                         entry_fn.ty_args(),
                     )?;
-                    let args = validate_combine_signer_and_txn_args(&session, senders, entry_fn.args().to_vec(), &function)?;
+                    let args = validate_combine_signer_and_txn_args(&mut session, gas_meter, &self.allowed_structs, senders, fee_payer, entry_fn.args().to_vec(), &function)?;
 
                     session.execute_entry_function(
                         entry_fn.module(),
@@ -341,11 +805,21 @@ impl NovaVM {
         session_output: SessionOutput,
         gas_meter: &mut NovaGasMeter,
     ) -> Result<(VMStatus, MessageOutput), VMStatus> {
+        // Charge the storage deposit, so it counts against the gas limit
+        gas_meter.charge_storage_deposit(&session_output.4)?;
+
         let gas_limit = gas_meter.gas_limit();
         let gas_used = gas_limit.checked_sub(gas_meter.balance()).unwrap();
+        let (storage_fee, storage_refunds) = gas_meter.storage_fee(&session_output.4);
         Ok((
             VMStatus::Executed,
-            get_message_output(session_output, gas_used, KeptVMStatus::Executed)?,
+            get_message_output(
+                session_output,
+                gas_used,
+                storage_fee,
+                storage_refunds,
+                KeptVMStatus::Executed,
+            )?,
         ))
     }
 
@@ -356,8 +830,14 @@ impl NovaVM {
     ) -> (VMStatus, MessageOutput) {
         match MessageStatus::from(error_code.clone()) {
             MessageStatus::Keep(status) => {
-                let txn_output = get_message_output(empty_session_output(), gas_used, status)
-                    .unwrap_or_else(|e| discard_error_vm_status(e, gas_used).1);
+                let txn_output = get_message_output(
+                    empty_session_output(),
+                    gas_used,
+                    0,
+                    BTreeMap::new(),
+                    status,
+                )
+                .unwrap_or_else(|e| discard_error_vm_status(e, gas_used).1);
                 (error_code, txn_output)
             }
             MessageStatus::Discard(status) => (
@@ -406,6 +886,8 @@ impl NovaVM {
             // `init_module` and verify some deployment conditions, while the VM need to do
             // the deserialization again. Consider adding an API to MoveVM which allows to
             // directly pass CompiledModule.
+            self.check_module_publisher(&destination)?;
+
             let bundle = ModuleBundle::new(modules);
             let modules = self.deserialize_module_bundle(&bundle)?;
 
@@ -465,9 +947,22 @@ impl NovaVM {
                 "not all registered modules published",
             ));
         }
+        verify_randomness_usage(modules)?;
         Ok(())
     }
 
+    /// Check `publisher` is allowed to publish modules by `module_publishers`.
+    fn check_module_publisher(&self, publisher: &AccountAddress) -> VMResult<()> {
+        match &self.module_publishers {
+            Some(publishers) if !publishers.contains(publisher) => {
+                Err(PartialVMError::new(StatusCode::CONSTRAINT_NOT_SATISFIED)
+                    .with_message(format!("{} is not allowed to publish modules", publisher))
+                    .finish(Location::Undefined))
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn metadata_validation_error(msg: &str) -> VMError {
         PartialVMError::new(StatusCode::CONSTRAINT_NOT_SATISFIED)
             .with_message(format!("metadata and code bundle mismatch: {}", msg))
@@ -475,6 +970,76 @@ impl NovaVM {
     }
 }
 
+/// Build the native and transaction gas parameters from a gas schedule, which must
+/// define every parameter.
+fn gas_params_from_schedule(
+    gas_schedule: &BTreeMap<String, u64>,
+) -> Result<NovaGasParameters, NovaVMError> {
+    NovaGasParameters::from_on_chain_gas_schedule(gas_schedule)
+        .ok_or_else(|| NovaVMError::generic_err("gas schedule is missing some parameters"))
+}
+
+/// Load a `0x1::vm_config` resource stored by the genesis, if any.
+fn load_vm_config_resource<S: StateView, R: DeserializeOwned>(
+    state_view_impl: &StateViewImpl<'_, S>,
+    struct_tag: StructTag,
+) -> Result<Option<R>, NovaVMError> {
+    let bytes = state_view_impl
+        .get_resource(&AccountAddress::ONE, &struct_tag)
+        .map_err(|e| NovaVMError::from(e.into_vm_status()))?;
+    bytes
+        .map(|bytes| bcs::from_bytes(&bytes))
+        .transpose()
+        .map_err(|e| NovaVMError::generic_err(format!("invalid {}: {}", struct_tag, e)))
+}
+
+/// Compile the standard library modules selected by `stdlib_config`.
+fn compile_stdlib_modules(
+    stdlib_config: &StdlibConfig,
+) -> Result<Vec<CompiledModule>, NovaVMError> {
+    let default_config = StdlibConfig::default();
+    let stdlib_config = match stdlib_config.is_empty() {
+        true => &default_config,
+        false => stdlib_config,
+    };
+
+    let mut compiled_modules = vec![];
+    for bundle in StdlibBundle::ALL {
+        let whole = stdlib_config.bundles.contains(&bundle);
+        let picked = stdlib_config
+            .modules
+            .iter()
+            .filter(|module| module.bundle == bundle)
+            .map(|module| module.name.as_str())
+            .collect::<BTreeSet<&str>>();
+        if !whole && picked.is_empty() {
+            continue;
+        }
+
+        let modules = match bundle {
+            StdlibBundle::MoveStdlib => compile_move_stdlib_modules(),
+            StdlibBundle::MoveNursery => compile_move_nursery_modules(),
+            StdlibBundle::NovaStdlib => compile_nova_stdlib_modules(),
+        };
+        for name in &picked {
+            if !modules.iter().any(|m| m.self_id().name().as_str() == *name) {
+                return Err(NovaVMError::generic_err(format!(
+                    "module {} not found in {:?}",
+                    name, bundle
+                )));
+            }
+        }
+
+        compiled_modules.extend(
+            modules
+                .into_iter()
+                .filter(|m| whole || picked.contains(m.self_id().name().as_str())),
+        );
+    }
+
+    Ok(compiled_modules)
+}
+
 pub(crate) fn discard_error_output(err: StatusCode, gas_used: Gas) -> MessageOutput {
     info!("discard error output: {:?}", err);
     // Since this message will be discarded, no writeset will be included.
@@ -482,7 +1047,10 @@ pub(crate) fn discard_error_output(err: StatusCode, gas_used: Gas) -> MessageOut
         vec![],
         WriteSet::default(),
         SizeChangeSet::default(),
+        BankChangeSet::default(),
         gas_used.into(),
+        0,
+        BTreeMap::new(),
         MessageStatus::Discard(err),
     )
 }
@@ -503,15 +1071,20 @@ pub(crate) fn discard_error_vm_status(err: VMStatus, gas_used: Gas) -> (VMStatus
 pub(crate) fn get_message_output(
     session_output: SessionOutput,
     gas_used: Gas,
+    storage_fee: u64,
+    storage_refunds: BTreeMap<AccountAddress, u64>,
     status: KeptVMStatus,
 ) -> Result<MessageOutput, VMStatus> {
-    let (events, write_set, size_change_set) = session_output;
+    let (events, write_set, size_change_set, bank_change_set, _) = session_output;
 
     Ok(MessageOutput::new(
         events,
         write_set,
         size_change_set,
+        bank_change_set,
         gas_used.into(),
+        storage_fee,
+        storage_refunds,
         MessageStatus::Keep(status),
     ))
 }
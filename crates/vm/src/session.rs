@@ -3,41 +3,86 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use nova_natives::table::NativeTableContext;
+use nova_natives::{bank::NativeBankContext, table::NativeTableContext};
 use nova_types::{
-    size_change_set::SizeChangeSet, size_delta::SizeDelta, table::TableHandle,
-    table_meta::TableMeta, table_meta_change_set::TableMetaChangeSet, write_set::WriteSet,
+    bank_change_set::BankChangeSet,
+    size_change_set::SizeChangeSet,
+    size_delta::SizeDelta,
+    storage_usage::{StorageDeposit, StorageDepositPrice, StorageUsage, StorageUsageChangeSet},
+    table::TableHandle,
+    table_meta::TableMeta,
+    table_meta_change_set::TableMetaChangeSet,
+    write_set::WriteSet,
 };
 
 use nova_storage::size::size_resolver::SizeResolver;
+use nova_storage::storage_usage::storage_usage_resolver::StorageUsageResolver;
 use nova_storage::table_meta::table_meta_resolver::TableMetaResolver;
-use nova_storage::{size::compute_size_changes, table_meta::compute_table_meta_changes};
+use nova_storage::{
+    size::compute_size_changes, storage_usage::compute_storage_usage_changes,
+    table_meta::compute_table_meta_changes,
+};
 
 use move_deps::{
     move_binary_format::errors::Location,
     move_core_types::{
-        account_address::AccountAddress, effects::Event, resolver::MoveResolver,
+        account_address::AccountAddress,
+        effects::{Event, Op},
+        resolver::MoveResolver,
         vm_status::VMStatus,
     },
     move_vm_runtime::session::Session,
 };
 
-pub type SessionOutput = (Vec<Event>, WriteSet, SizeChangeSet);
+pub type SessionOutput = (
+    Vec<Event>,
+    WriteSet,
+    SizeChangeSet,
+    BankChangeSet,
+    StorageDeposit,
+);
 pub fn empty_session_output() -> SessionOutput {
-    (vec![], WriteSet::default(), SizeChangeSet::default())
+    (
+        vec![],
+        WriteSet::default(),
+        SizeChangeSet::default(),
+        BankChangeSet::default(),
+        StorageDeposit::default(),
+    )
 }
 
 pub struct SessionExt<'r, 'l, S> {
     resolver: &'r S,
     inner: Session<'r, 'l, S>,
+    deposit_price: StorageDepositPrice,
+    has_bank_context: bool,
 }
 
 impl<'r, 'l, S> SessionExt<'r, 'l, S>
 where
-    S: MoveResolver + SizeResolver + TableMetaResolver,
+    S: MoveResolver + SizeResolver + TableMetaResolver + StorageUsageResolver,
 {
-    pub fn new(inner: Session<'r, 'l, S>, resolver: &'r S) -> Self {
-        Self { inner, resolver }
+    pub fn new(
+        inner: Session<'r, 'l, S>,
+        resolver: &'r S,
+        deposit_price: StorageDepositPrice,
+    ) -> Self {
+        Self {
+            inner,
+            resolver,
+            deposit_price,
+            has_bank_context: false,
+        }
+    }
+
+    /// Register the bank context; its buffered operations are returned by `finish`.
+    pub fn add_bank_context(&mut self, bank_context: NativeBankContext<'r>) {
+        self.inner.get_native_extensions().add(bank_context);
+        self.has_bank_context = true;
+    }
+
+    pub fn set_storage_deposit_price(&mut self, deposit_price: StorageDepositPrice) {
+        self.deposit_price = deposit_price;
     }
 
     pub fn finish(self) -> Result<SessionOutput, VMStatus> {
@@ -49,6 +94,10 @@ where
         let table_change_set = table_context
             .into_change_set()
             .map_err(|e| e.finish(Location::Undefined).into_vm_status())?;
+        let bank_change_set = match self.has_bank_context {
+            true => extensions.remove::<NativeBankContext>().into_change_set(),
+            false => BankChangeSet::default(),
+        };
 
         // Compute storage size delta for all accounts to charge storage fee
         let mut size_changes: BTreeMap<AccountAddress, SizeDelta> =
@@ -64,11 +113,36 @@ where
             &table_change_set.removed_tables,
         )?;
 
-        // build output change set from the changes
+        // The storage usage stays with the owners of the bytes, even for the sponsored
+        // messages, so the deposit is refunded to the owners when the bytes are removed
         let size_change_set = SizeChangeSet::new(size_changes);
-        let write_set = WriteSet::new(change_set, table_change_set, table_meta_change_set);
 
-        Ok((events, write_set, size_change_set))
+        // Track storage usage and deposit of all accounts
+        let (storage_usage_changes, storage_deposit): (
+            BTreeMap<AccountAddress, Op<StorageUsage>>,
+            StorageDeposit,
+        ) = compute_storage_usage_changes(
+            self.resolver,
+            size_change_set.changes(),
+            &self.deposit_price,
+        )?;
+        let storage_usage_change_set = StorageUsageChangeSet::new(storage_usage_changes)?;
+
+        // build output change set from the changes
+        let write_set = WriteSet::new(
+            change_set,
+            table_change_set,
+            table_meta_change_set,
+            storage_usage_change_set,
+        );
+
+        Ok((
+            events,
+            write_set,
+            size_change_set,
+            bank_change_set,
+            storage_deposit,
+        ))
     }
 }
 
@@ -1,17 +1,24 @@
-use nova_storage::{state_view::StateView, table_view::TableView};
+use nova_storage::{size::stored_size, state_view::StateView, table_view::TableView};
 use std::{
     collections::BTreeMap,
     ops::{Bound, RangeBounds},
 };
 
-use move_deps::move_core_types::effects::Op;
-use nova_natives::{block::BlockInfoResolver, table::TableResolver};
+use move_deps::move_core_types::{account_address::AccountAddress, effects::Op};
+use nova_natives::table::TableResolver;
 use nova_types::{
-    access_path::AccessPath, iterator::Order, table::TableHandle, write_set::WriteSet,
+    access_path::{AccessPath, DataPath},
+    iterator::Order,
+    storage_usage::StorageUsage,
+    table::{KeyEncoding, TableHandle},
+    table_meta::TableMeta,
+    write_set::WriteSet,
 };
 
 use anyhow::{anyhow, Error};
 
+pub use nova_natives::mocks::MockApi;
+
 #[derive(Debug)]
 pub struct MockChain {
     map: BTreeMap<Vec<u8>, Vec<u8>>,
@@ -32,12 +39,90 @@ impl MockChain {
     }
 
     pub fn create_api(&self, height: u64, timestamp: u64) -> MockApi {
-        MockApi { height, timestamp }
+        MockApi {
+            height,
+            timestamp,
+            ..Default::default()
+        }
     }
 
     pub fn commit(&mut self, state: MockState) {
         self.map = state.map;
     }
+
+    /// Check the storage usage of every account, accumulated from the size deltas of
+    /// the committed messages, matches the size recomputed from the whole state.
+    /// Also check the size of every table matches the size of its items.
+    pub fn check_storage_usage_invariant(&self) {
+        let mut account_sizes: BTreeMap<AccountAddress, u64> = BTreeMap::new();
+        let mut table_sizes: BTreeMap<AccountAddress, usize> = BTreeMap::new();
+        let mut table_lengths: BTreeMap<AccountAddress, u64> = BTreeMap::new();
+        let mut storage_usages: BTreeMap<AccountAddress, u64> = BTreeMap::new();
+
+        for (key, value) in self.map.iter() {
+            let ap = decode_access_path(key);
+            match &ap.path {
+                DataPath::Code(_) | DataPath::Resource(_) => {
+                    *account_sizes.entry(ap.address).or_default() += stored_size(&ap, value) as u64;
+                }
+                DataPath::TableItem(_) => {
+                    let table_meta = self.get_table_meta(&ap.address);
+                    let size = stored_size(&ap, value);
+                    *table_sizes.entry(ap.address).or_default() += size;
+                    *table_lengths.entry(ap.address).or_default() += 1;
+                    if table_meta.payer != AccountAddress::ZERO {
+                        *account_sizes.entry(table_meta.payer).or_default() += size as u64;
+                    }
+                }
+                DataPath::StorageUsage => {
+                    let storage_usage = StorageUsage::deserialize(value).unwrap();
+                    storage_usages.insert(ap.address, storage_usage.size);
+                }
+                DataPath::TableMeta => (),
+            }
+        }
+
+        for (table_addr, size) in table_sizes {
+            let table_meta = self.get_table_meta(&table_addr);
+            assert_eq!(
+                table_meta.size, size,
+                "size of table {} does not match its items",
+                table_addr
+            );
+            assert_eq!(
+                table_meta.length,
+                Some(table_lengths[&table_addr]),
+                "length of table {} does not match its items",
+                table_addr
+            );
+        }
+
+        let mut addrs: Vec<&AccountAddress> = account_sizes.keys().collect();
+        addrs.extend(storage_usages.keys());
+        for addr in addrs {
+            assert_eq!(
+                storage_usages.get(addr).copied().unwrap_or_default(),
+                account_sizes.get(addr).copied().unwrap_or_default(),
+                "storage usage of {} does not match its stored size",
+                addr
+            );
+        }
+    }
+
+    fn get_table_meta(&self, table_addr: &AccountAddress) -> TableMeta {
+        let ap = AccessPath::table_meta_access_path(*table_addr);
+        let bytes = self
+            .map
+            .get(&ap.to_bytes().unwrap())
+            .expect("table meta must exist for the table items");
+        TableMeta::deserialize(bytes).unwrap()
+    }
+}
+
+fn decode_access_path(key: &[u8]) -> AccessPath {
+    let address = AccountAddress::from_bytes(&key[..AccountAddress::LENGTH]).unwrap();
+    let path = DataPath::decode(&key[AccountAddress::LENGTH..]).unwrap();
+    AccessPath::new(address, path)
 }
 
 pub struct MockState {
@@ -71,11 +156,19 @@ impl StateView for MockState {
     fn get(&self, access_path: &AccessPath) -> anyhow::Result<Option<Vec<u8>>> {
         Ok(self.map.get(&access_path.to_bytes()?).cloned())
     }
+
+    fn get_prefix(&self, prefix: &[u8]) -> anyhow::Result<Vec<(AccessPath, Vec<u8>)>> {
+        self.map
+            .range(prefix.to_vec()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((AccessPath::from_bytes(key)?, value.clone())))
+            .collect()
+    }
 }
 
 pub struct MockTableState<'r> {
     inner: &'r MockState,
-    iterators: Vec<Vec<Vec<u8>>>,
+    iterators: Vec<Option<Vec<Vec<u8>>>>,
 }
 
 impl<'r> MockTableState<'r> {
@@ -97,6 +190,14 @@ impl<'r> TableView for MockTableState<'r> {
         Ok(self.inner.map.get(&access_path).cloned())
     }
 
+    fn resolve_key_encoding(&self, handle: &TableHandle) -> anyhow::Result<KeyEncoding> {
+        let access_path = AccessPath::table_meta_access_path(handle.0).to_bytes()?;
+        Ok(match self.inner.map.get(&access_path) {
+            Some(bytes) => TableMeta::deserialize(bytes)?.key_encoding,
+            None => KeyEncoding::Bcs,
+        })
+    }
+
     fn create_iterator(
         &mut self,
         handle: &TableHandle,
@@ -124,7 +225,7 @@ impl<'r> TableView for MockTableState<'r> {
         match (bounds.start_bound(), bounds.end_bound()) {
             (Bound::Included(start), Bound::Excluded(end)) if start > end => {
                 let iterator_id = self.iterators.len();
-                self.iterators.push(vec![]);
+                self.iterators.push(Some(vec![]));
                 return Ok(iterator_id as u32);
             }
             _ => {}
@@ -134,7 +235,7 @@ impl<'r> TableView for MockTableState<'r> {
 
         let prefix_len = prefix.len();
         let iter = self.inner.map.range(bounds);
-        self.iterators.push(match order {
+        self.iterators.push(Some(match order {
             Order::Ascending => iter
                 .map(|v| clone_and_format_item(v, prefix_len))
                 .collect::<Vec<Vec<u8>>>(),
@@ -142,21 +243,31 @@ impl<'r> TableView for MockTableState<'r> {
                 .rev()
                 .map(|v| clone_and_format_item(v, prefix_len))
                 .collect(),
-        });
+        }));
 
         Ok(iterator_id as u32)
     }
 
     fn next_key(&mut self, iterator_id: u32) -> anyhow::Result<Option<Vec<u8>>> {
         match self.iterators.get_mut(iterator_id as usize) {
-            Some(iterator) => Ok(match iterator.get(0).map(|v| v.to_vec()) {
+            Some(Some(iterator)) => Ok(match iterator.get(0).map(|v| v.to_vec()) {
                 Some(key_bytes) => {
                     iterator.remove(0);
                     Some(key_bytes)
                 }
                 None => None,
             }),
-            None => Err(anyhow!("iterator not found")),
+            _ => Err(anyhow!("iterator not found")),
+        }
+    }
+
+    fn destroy_iterator(&mut self, iterator_id: u32) -> anyhow::Result<()> {
+        match self.iterators.get_mut(iterator_id as usize) {
+            Some(iterator @ Some(_)) => {
+                *iterator = None;
+                Ok(())
+            }
+            _ => Err(anyhow!("iterator not found")),
         }
     }
 }
@@ -196,17 +307,6 @@ fn clone_and_format_item(item_ref: BTreeMapRecordRef, prefix_length: usize) -> V
     key[prefix_length..].to_vec()
 }
 
-pub struct MockApi {
-    pub height: u64,
-    pub timestamp: u64,
-}
-
-impl BlockInfoResolver for MockApi {
-    fn get_block_info(&self) -> anyhow::Result<(u64 /* height */, u64 /* timestamp */)> {
-        Ok((self.height, self.timestamp))
-    }
-}
-
 /// A dummy storage containing no modules or resources.
 /// only used for unit test
 #[derive(Debug, Clone)]
@@ -227,6 +327,10 @@ impl TableResolver for BlankTableViewImpl {
         Ok(None)
     }
 
+    fn resolve_key_encoding(&self, _handle: &TableHandle) -> Result<KeyEncoding, Error> {
+        Ok(KeyEncoding::Bcs)
+    }
+
     fn create_iterator(
         &mut self,
         _handle: &TableHandle,
@@ -240,4 +344,8 @@ impl TableResolver for BlankTableViewImpl {
     fn next_key(&mut self, _iterator_id: u32) -> anyhow::Result<Option<Vec<u8>>> {
         Ok(None)
     }
+
+    fn destroy_iterator(&mut self, _iterator_id: u32) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
@@ -4,7 +4,11 @@ use super::mock_chain::MockChain;
 
 use nova_gas::Gas;
 use nova_storage::{state_view_impl::StateViewImpl, table_view_impl::TableViewImpl};
-use nova_types::{message::Message, message::MessageOutput, size_delta::SizeDelta};
+use nova_types::{
+    genesis::StdlibConfig,
+    message::{Message, MessageOutput, MessagePayload},
+    size_delta::SizeDelta,
+};
 
 use crate::{nova_vm::NovaVM, test_utils::mock_chain::MockTableState};
 
@@ -76,6 +80,8 @@ pub enum ExpectedOutputItem {
     VMStatusReturn(VMStatus),
     ResultBytes(Vec<u8>),
     SizeChange(BTreeMap<AccountAddress, SizeDelta>),
+    StorageFee(u64),
+    StorageRefunds(BTreeMap<AccountAddress, u64>),
 }
 
 impl ExpectedOutputItem {
@@ -103,10 +109,24 @@ impl ExpectedOutputItem {
                     output.size_change_set().changes()
                 );
             }
+            ExpectedOutputItem::StorageFee(exp_fee) => {
+                assert!(output.storage_fee() == *exp_fee);
+            }
+            ExpectedOutputItem::StorageRefunds(exp_refunds) => {
+                assert!(
+                    *output.storage_refunds() == *exp_refunds,
+                    "expected\n{:?}\n\noutput\n{:?}",
+                    exp_refunds,
+                    output.storage_refunds()
+                );
+            }
         };
     }
 }
 
+const GAS_LIMIT: u64 = 100_000;
+const MODULE_BUNDLE_GAS_LIMIT: u64 = 1_000_000;
+
 pub fn run_transaction(testcases: Vec<MockTx>) {
     let mut chain = MockChain::new();
     let mut vm = NovaVM::new();
@@ -119,14 +139,19 @@ pub fn run_transaction(testcases: Vec<MockTx>) {
     let mut table_resolver = TableViewImpl::new(&mut table_state);
 
     let (status, output, _) = vm
-        .initialize(&resolver, &mut table_resolver, None)
+        .initialize(
+            &resolver,
+            &mut table_resolver,
+            &StdlibConfig::default(),
+            None,
+        )
         .expect("Module must load");
     assert!(status == VMStatus::Executed);
     let inner_output = output.into_inner();
     state.push_write_set(inner_output.1);
     chain.commit(state);
+    chain.check_storage_usage_invariant();
 
-    let gas_limit = Gas::new(100_000u64);
     let mut num = 0;
     for MockTx {
         msg_tests,
@@ -143,6 +168,11 @@ pub fn run_transaction(testcases: Vec<MockTx>) {
             let mut table_state = MockTableState::new(&state);
             let mut table_resolver = TableViewImpl::new(&mut table_state);
 
+            // module bundles pay the storage deposit of the published code
+            let gas_limit = match msg.payload() {
+                MessagePayload::ModuleBundle(_) => Gas::new(MODULE_BUNDLE_GAS_LIMIT),
+                _ => Gas::new(GAS_LIMIT),
+            };
             let vm_output = vm
                 .execute_message(msg, &resolver, &mut table_resolver, Some(&api), gas_limit)
                 .expect("nova vm failure");
@@ -165,6 +195,7 @@ pub fn run_transaction(testcases: Vec<MockTx>) {
 
         if should_commit {
             chain.commit(state);
+            chain.check_storage_usage_invariant();
         }
     }
 }
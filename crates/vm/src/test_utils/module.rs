@@ -30,6 +30,29 @@ pub fn create_basic_coin() -> Module {
     s
 }
 
+pub fn create_arguments() -> Module {
+    let s = Module::new(
+        include_bytes!("../../../move-test/build/test1/bytecode_modules/Arguments.mv").to_vec(),
+    );
+    let _compiled_module = CompiledModule::deserialize(s.code()).unwrap();
+
+    s
+}
+
+pub fn create_randomness_entry() -> Module {
+    Module::new(
+        include_bytes!("../../../move-test/build/test1/bytecode_modules/RandomnessEntry.mv")
+            .to_vec(),
+    )
+}
+
+pub fn create_randomness_public() -> Module {
+    Module::new(
+        include_bytes!("../../../move-test/build/test1/bytecode_modules/RandomnessPublic.mv")
+            .to_vec(),
+    )
+}
+
 pub fn get_basic_coin_module_id() -> ModuleId {
     ModuleId::new(AccountAddress::ONE, Identifier::new("BasicCoin").unwrap())
 }
@@ -40,3 +63,10 @@ pub fn get_table_test_data_module_id() -> ModuleId {
 
     ModuleId::new(account_two, Identifier::new("TableTestData").unwrap())
 }
+
+pub fn get_arguments_module_id() -> ModuleId {
+    let account_two =
+        AccountAddress::from_hex_literal("0x2").expect("0x2 account should be created");
+
+    ModuleId::new(account_two, Identifier::new("Arguments").unwrap())
+}
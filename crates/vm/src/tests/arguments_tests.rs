@@ -0,0 +1,263 @@
+use nova_gas::Gas;
+use nova_storage::{state_view_impl::StateViewImpl, table_view_impl::TableViewImpl};
+use nova_types::{
+    entry_function::EntryFunction, genesis::StdlibConfig, message::Message, module::ModuleBundle,
+};
+
+use move_deps::move_core_types::{
+    identifier::Identifier,
+    language_storage::{ModuleId, CORE_CODE_ADDRESS},
+    vm_status::{AbortLocation, StatusCode, VMStatus},
+};
+
+use crate::test_utils::mock_chain::{MockApi, MockChain, MockTableState};
+use crate::test_utils::{generate_account, module};
+use crate::NovaVM;
+
+const POINT: &str = "0x2::Arguments::Point";
+
+fn call(session_id: u8, function: &str, arg: Vec<u8>) -> Message {
+    Message::new_entry_function(
+        vec![session_id; 32],
+        None,
+        EntryFunction::new(
+            module::get_arguments_module_id(),
+            Identifier::new(function).unwrap(),
+            vec![],
+            vec![arg],
+        ),
+    )
+}
+
+fn execute(
+    chain: &mut MockChain,
+    vm: &mut NovaVM,
+    api: &MockApi,
+    msg: Message,
+) -> (VMStatus, Option<Vec<u8>>) {
+    let mut state = chain.create_state();
+    let (status, output, retval) = {
+        let resolver = StateViewImpl::new(&state);
+        let mut table_state = MockTableState::new(&state);
+        let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+        vm.execute_message(
+            msg,
+            &resolver,
+            &mut table_resolver,
+            Some(api),
+            Gas::new(100_000u64),
+        )
+        .expect("nova vm failure")
+    };
+
+    if status == VMStatus::Executed {
+        state.push_write_set(output.into_inner().1);
+        chain.commit(state);
+    }
+    let result = retval.and_then(|r| r.return_values.first().map(|v| v.0.clone()));
+    (status, result)
+}
+
+fn setup() -> (MockChain, NovaVM, MockApi) {
+    let mut chain = MockChain::new();
+    let mut vm = NovaVM::new();
+    let api = chain.create_api(0, 0);
+
+    let mut state = chain.create_state();
+    let output = {
+        let resolver = StateViewImpl::new(&state);
+        let mut table_state = MockTableState::new(&state);
+        let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+        let (status, output, _) = vm
+            .initialize(
+                &resolver,
+                &mut table_resolver,
+                &StdlibConfig::default(),
+                None,
+            )
+            .expect("Module must load");
+        assert_eq!(status, VMStatus::Executed);
+        output
+    };
+    state.push_write_set(output.into_inner().1);
+    chain.commit(state);
+
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        Message::new_module(
+            vec![0; 32],
+            Some(generate_account("0x2")),
+            ModuleBundle::from(module::create_arguments()),
+        ),
+    );
+    assert_eq!(status, VMStatus::Executed);
+
+    (chain, vm, api)
+}
+
+fn u64_bytes(v: u64) -> Option<Vec<u8>> {
+    Some(bcs::to_bytes(&v).unwrap())
+}
+
+fn is_abort_at(status: &VMStatus, module_id: &ModuleId, exp_code: u64) -> bool {
+    match status {
+        VMStatus::MoveAbort(AbortLocation::Module(id), code) => {
+            id == module_id && *code == exp_code
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test_string_arguments() {
+    let (mut chain, mut vm, api) = setup();
+    let ascii = ModuleId::new(CORE_CODE_ADDRESS, Identifier::new("ascii").unwrap());
+    let string = ModuleId::new(CORE_CODE_ADDRESS, Identifier::new("string").unwrap());
+
+    let (status, result) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(1, "ascii_length", bcs::to_bytes(&b"nova".to_vec()).unwrap()),
+    );
+    assert_eq!(status, VMStatus::Executed);
+    assert_eq!(result, u64_bytes(4));
+
+    let (status, result) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(
+            2,
+            "utf8_length",
+            bcs::to_bytes(&"노바".to_string()).unwrap(),
+        ),
+    );
+    assert_eq!(status, VMStatus::Executed);
+    assert_eq!(result, u64_bytes(6));
+
+    // non-ascii bytes are rejected by `ascii::string`
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(3, "ascii_length", bcs::to_bytes(&vec![0x80u8]).unwrap()),
+    );
+    assert!(is_abort_at(&status, &ascii, 0x10000), "{:?}", status);
+
+    // invalid utf8 bytes are rejected by `string::utf8`
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(4, "utf8_length", bcs::to_bytes(&vec![0xffu8]).unwrap()),
+    );
+    assert!(is_abort_at(&status, &string, 1), "{:?}", status);
+}
+
+#[test]
+fn test_registered_struct_arguments() {
+    let (mut chain, mut vm, api) = setup();
+    let points = bcs::to_bytes(&vec![(1u64, 2u64), (3u64, 4u64)]).unwrap();
+
+    // not allowed before the registration
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(1, "sum_points", points.clone()),
+    );
+    assert_eq!(
+        status,
+        VMStatus::Error(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE)
+    );
+
+    vm.register_txn_arg_struct(POINT, "new_point").unwrap();
+
+    let (status, result) = execute(&mut chain, &mut vm, &api, call(2, "sum_points", points));
+    assert_eq!(status, VMStatus::Executed);
+    assert_eq!(result, u64_bytes(10));
+
+    let (status, result) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(
+            3,
+            "sum_optional_point",
+            bcs::to_bytes(&Some((5u64, 6u64))).unwrap(),
+        ),
+    );
+    assert_eq!(status, VMStatus::Executed);
+    assert_eq!(result, u64_bytes(11));
+
+    // the constructor rejects the points out of the grid
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(
+            4,
+            "sum_points",
+            bcs::to_bytes(&vec![(101u64, 0u64)]).unwrap(),
+        ),
+    );
+    assert!(
+        is_abort_at(&status, &module::get_arguments_module_id(), 1),
+        "{:?}",
+        status
+    );
+
+    // bytes which are not a point
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(5, "sum_points", bcs::to_bytes(&vec![1u64]).unwrap()),
+    );
+    assert_eq!(
+        status,
+        VMStatus::Error(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
+    );
+
+    assert!(vm.unregister_txn_arg_struct(POINT));
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        call(
+            6,
+            "sum_optional_point",
+            bcs::to_bytes(&Some((5u64, 6u64))).unwrap(),
+        ),
+    );
+    assert_eq!(
+        status,
+        VMStatus::Error(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE)
+    );
+}
+
+#[test]
+fn test_register_txn_arg_struct_restrictions() {
+    let mut vm = NovaVM::new();
+
+    // framework structs keep their builtin handling
+    assert!(vm
+        .register_txn_arg_struct("0x1::coin::Coin", "zero")
+        .is_err());
+    assert!(vm
+        .register_txn_arg_struct("0x2::Arguments::Point<u64>", "new_point")
+        .is_err());
+    assert!(vm.register_txn_arg_struct("Point", "new_point").is_err());
+    assert!(vm.register_txn_arg_struct(POINT, "new-point").is_err());
+    assert!(!vm.allowed_txn_arg_structs().contains(POINT));
+
+    vm.register_txn_arg_struct(POINT, "new_point").unwrap();
+    assert!(vm.allowed_txn_arg_structs().contains(POINT));
+    assert!(vm.unregister_txn_arg_struct(POINT));
+    assert!(!vm.unregister_txn_arg_struct(POINT));
+}
@@ -0,0 +1,183 @@
+use nova_gas::Gas;
+use nova_natives::bank::BankBridge;
+use nova_storage::{
+    state_view::StateView, state_view_impl::StateViewImpl, table_view_impl::TableViewImpl,
+};
+use nova_types::{
+    access_path::AccessPath,
+    bank_change_set::BankOp,
+    entry_function::EntryFunction,
+    genesis::{GenesisCoin, GenesisConfig},
+    message::{Message, MessageOutput},
+    module::ModuleBundle,
+};
+
+use move_deps::move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    parser::parse_struct_tag,
+    vm_status::VMStatus,
+};
+
+use crate::test_utils::mock_chain::{MockApi, MockChain, MockState, MockTableState};
+use crate::test_utils::module;
+use crate::NovaVM;
+
+const DENOM: &str = "unova";
+
+fn coin_type() -> TypeTag {
+    TypeTag::Struct(parse_struct_tag("0x1::BasicCoin::Nova").unwrap())
+}
+
+fn transfer(session_id: u8, from: AccountAddress, to: AccountAddress, amount: u64) -> Message {
+    Message::new_entry_function(
+        vec![session_id; 32],
+        Some(from),
+        EntryFunction::new(
+            ModuleId::new(AccountAddress::ONE, Identifier::new("coin").unwrap()),
+            Identifier::new("transfer").unwrap(),
+            vec![coin_type()],
+            vec![bcs::to_bytes(&to).unwrap(), bcs::to_bytes(&amount).unwrap()],
+        ),
+    )
+}
+
+/// Initialize the chain with `0x1::BasicCoin::Nova` mapped to `DENOM`.
+fn initialize_mapped_coin(chain: &mut MockChain, vm: &mut NovaVM, api: &MockApi) {
+    let genesis_config = GenesisConfig {
+        module_bundle: Some(ModuleBundle::from(module::create_basic_coin())),
+        coins: vec![GenesisCoin {
+            coin_type: coin_type(),
+            name: "Nova".to_string(),
+            symbol: "NOVA".to_string(),
+            decimals: 6,
+            balances: vec![],
+        }],
+        init_functions: vec![EntryFunction::new(
+            ModuleId::new(AccountAddress::ONE, Identifier::new("coin").unwrap()),
+            Identifier::new("native_register_denom").unwrap(),
+            vec![coin_type()],
+            vec![bcs::to_bytes(&DENOM.to_string()).unwrap()],
+        )],
+        ..Default::default()
+    };
+
+    let mut state = chain.create_state();
+    let output = {
+        let resolver = StateViewImpl::new(&state);
+        let mut table_state = MockTableState::new(&state);
+        let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+        let (status, output, _) = vm
+            .initialize_with_config(&resolver, &mut table_resolver, api, genesis_config)
+            .expect("genesis must succeed");
+        assert_eq!(status, VMStatus::Executed);
+        output
+    };
+    state.push_write_set(output.into_inner().1);
+    chain.commit(state);
+}
+
+fn execute(
+    vm: &mut NovaVM,
+    state: &MockState,
+    api: &MockApi,
+    msg: Message,
+) -> (VMStatus, MessageOutput) {
+    let resolver = StateViewImpl::new(state);
+    let mut table_state = MockTableState::new(state);
+    let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+    let (status, output, _) = vm
+        .execute_message(
+            msg,
+            &resolver,
+            &mut table_resolver,
+            Some(api),
+            Gas::new(100_000u64),
+        )
+        .expect("nova vm failure");
+    (status, output)
+}
+
+#[test]
+fn test_mapped_coin_bank_operations_are_buffered() {
+    let account_two =
+        AccountAddress::from_hex_literal("0x2").expect("0x2 account should be created");
+    let account_three =
+        AccountAddress::from_hex_literal("0x3").expect("0x3 account should be created");
+
+    let mut chain = MockChain::new();
+    let mut vm = NovaVM::new();
+    let api = chain.create_api(0, 0);
+    initialize_mapped_coin(&mut chain, &mut vm, &api);
+    api.bank.mint(DENOM, &account_two, 100);
+
+    // the transfer is buffered in the output instead of changing the bank
+    let mut state = chain.create_state();
+    let (status, output) = execute(
+        &mut vm,
+        &state,
+        &api,
+        transfer(1, account_two, account_three, 30),
+    );
+    assert_eq!(status, VMStatus::Executed);
+    assert_eq!(
+        output.bank_change_set().ops(),
+        &[
+            BankOp::Withdraw {
+                denom: DENOM.to_string(),
+                address: account_two,
+                amount: 30,
+            },
+            BankOp::Deposit {
+                denom: DENOM.to_string(),
+                address: account_three,
+                amount: 30,
+            },
+        ]
+    );
+    assert_eq!(api.bank_balance(DENOM, &account_two).unwrap(), 100);
+    assert_eq!(api.bank_balance(DENOM, &account_three).unwrap(), 0);
+
+    // apply the succeeded message like the chain does
+    for op in output.bank_change_set().ops() {
+        match op {
+            BankOp::Withdraw {
+                denom,
+                address,
+                amount,
+            } => api.bank_withdraw(denom, address, *amount),
+            BankOp::Deposit {
+                denom,
+                address,
+                amount,
+            } => api.bank_deposit(denom, address, *amount),
+        }
+        .unwrap();
+    }
+    state.push_write_set(output.into_inner().1);
+    chain.commit(state);
+    assert_eq!(api.bank_balance(DENOM, &account_two).unwrap(), 70);
+    assert_eq!(api.bank_balance(DENOM, &account_three).unwrap(), 30);
+
+    // the recipient got a coin store for the frozen flag and the events
+    let state = chain.create_state();
+    let coin_store = parse_struct_tag("0x1::coin::CoinStore<0x1::BasicCoin::Nova>").unwrap();
+    assert!(state
+        .get(&AccessPath::resource_access_path(account_three, coin_store))
+        .unwrap()
+        .is_some());
+
+    // a failed message leaves no bank operation behind
+    let (status, output) = execute(
+        &mut vm,
+        &state,
+        &api,
+        transfer(2, account_two, account_three, 300),
+    );
+    assert!(matches!(status, VMStatus::MoveAbort(..)));
+    assert!(output.bank_change_set().is_empty());
+    assert_eq!(api.bank_balance(DENOM, &account_two).unwrap(), 70);
+}
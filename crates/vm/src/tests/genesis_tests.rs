@@ -0,0 +1,236 @@
+use nova_gas::{Gas, InitialGasSchedule, NovaGasParameters, ToOnChainGasSchedule};
+use nova_storage::{
+    state_view::StateView, state_view_impl::StateViewImpl, table_view_impl::TableViewImpl,
+};
+use nova_types::{
+    access_path::AccessPath,
+    entry_function::EntryFunction,
+    genesis::{GenesisBalance, GenesisCoin, GenesisConfig, StdlibBundle, StdlibConfig},
+    message::Message,
+    module::ModuleBundle,
+};
+
+use move_deps::move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    parser::parse_struct_tag,
+    vm_status::{StatusCode, VMStatus},
+};
+
+use crate::test_utils::mock_chain::{MockChain, MockTableState};
+use crate::test_utils::module;
+use crate::NovaVM;
+
+#[test]
+fn test_initialize_with_config() {
+    let account_two =
+        AccountAddress::from_hex_literal("0x2").expect("0x2 account should be created");
+    let account_three =
+        AccountAddress::from_hex_literal("0x3").expect("0x3 account should be created");
+
+    let mut chain = MockChain::new();
+    let mut vm = NovaVM::new();
+    let api = chain.create_api(0, 0);
+
+    let genesis_config = GenesisConfig {
+        stdlib: StdlibConfig::default(),
+        module_bundle: Some(ModuleBundle::from(module::create_basic_coin())),
+        accounts: vec![account_two],
+        coins: vec![GenesisCoin {
+            coin_type: TypeTag::Struct(parse_struct_tag("0x1::BasicCoin::Nova").unwrap()),
+            name: "Nova".to_string(),
+            symbol: "NOVA".to_string(),
+            decimals: 6,
+            balances: vec![GenesisBalance {
+                address: account_three,
+                amount: 100,
+            }],
+        }],
+        gas_schedule: Some(
+            NovaGasParameters::initial()
+                .to_on_chain_gas_schedule()
+                .into_iter()
+                .collect(),
+        ),
+        module_publishers: Some(vec![account_three]),
+        init_functions: vec![],
+    };
+
+    let mut state = chain.create_state();
+    let output = {
+        let resolver = StateViewImpl::new(&state);
+        let mut table_state = MockTableState::new(&state);
+        let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+        let (status, output, _) = vm
+            .initialize_with_config(&resolver, &mut table_resolver, &api, genesis_config)
+            .expect("genesis must succeed");
+        assert_eq!(status, VMStatus::Executed);
+        output
+    };
+    state.push_write_set(output.into_inner().1);
+    chain.commit(state);
+    chain.check_storage_usage_invariant();
+
+    // the account and the coin balance are created by the genesis
+    let state = chain.create_state();
+    let account = parse_struct_tag("0x1::account::Account").unwrap();
+    assert!(state
+        .get(&AccessPath::resource_access_path(account_two, account))
+        .unwrap()
+        .is_some());
+    let coin_store = parse_struct_tag("0x1::coin::CoinStore<0x1::BasicCoin::Nova>").unwrap();
+    let coin_store = state
+        .get(&AccessPath::resource_access_path(account_three, coin_store))
+        .unwrap()
+        .expect("coin store must be registered");
+    assert_eq!(coin_store[..8], 100u64.to_le_bytes());
+
+    // only the module publishers can publish modules
+    let resolver = StateViewImpl::new(&state);
+    let mut table_state = MockTableState::new(&state);
+    let mut table_resolver = TableViewImpl::new(&mut table_state);
+    let (status, _, _) = vm
+        .execute_message(
+            Message::new_module(
+                vec![1; 32],
+                Some(account_two),
+                ModuleBundle::from(module::create_std_coin()),
+            ),
+            &resolver,
+            &mut table_resolver,
+            Some(&api),
+            Gas::new(100_000u64),
+        )
+        .expect("nova vm failure");
+    assert_eq!(status.status_code(), StatusCode::CONSTRAINT_NOT_SATISFIED);
+
+    // a restarted vm loads the configuration stored by the genesis
+    let mut restarted = NovaVM::new();
+    assert!(restarted.module_publishers().is_none());
+    restarted.load_vm_config(&resolver).unwrap();
+    assert_eq!(restarted.module_publishers(), vm.module_publishers());
+}
+
+#[test]
+fn test_initialize_with_config_failure() {
+    let account_three =
+        AccountAddress::from_hex_literal("0x3").expect("0x3 account should be created");
+
+    let chain = MockChain::new();
+    let mut vm = NovaVM::new();
+    let api = chain.create_api(0, 0);
+
+    let genesis_config = GenesisConfig {
+        module_publishers: Some(vec![account_three]),
+        init_functions: vec![EntryFunction::new(
+            ModuleId::new(AccountAddress::ONE, Identifier::new("account").unwrap()),
+            Identifier::new("missing_function").unwrap(),
+            vec![],
+            vec![],
+        )],
+        ..GenesisConfig::default()
+    };
+
+    let state = chain.create_state();
+    let resolver = StateViewImpl::new(&state);
+    let mut table_state = MockTableState::new(&state);
+    let mut table_resolver = TableViewImpl::new(&mut table_state);
+    assert!(vm
+        .initialize_with_config(&resolver, &mut table_resolver, &api, genesis_config)
+        .is_err());
+
+    // the vm is only configured by a successful genesis
+    assert!(vm.module_publishers().is_none());
+}
+
+#[test]
+fn test_initialize_with_stdlib_config() {
+    let mut chain = MockChain::new();
+    let mut vm = NovaVM::new();
+
+    let mut state = chain.create_state();
+    let output = {
+        let resolver = StateViewImpl::new(&state);
+        let mut table_state = MockTableState::new(&state);
+        let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+        let stdlib_config = StdlibConfig {
+            bundles: vec![StdlibBundle::MoveStdlib],
+            modules: vec![],
+        };
+        let (status, output, _) = vm
+            .initialize(&resolver, &mut table_resolver, &stdlib_config, None)
+            .expect("genesis must succeed");
+        assert_eq!(status, VMStatus::Executed);
+        output
+    };
+    state.push_write_set(output.into_inner().1);
+    chain.commit(state);
+
+    // only the selected bundle is published
+    let state = chain.create_state();
+    let std_addr = AccountAddress::ONE;
+    assert!(state
+        .get(&AccessPath::code_access_path(
+            std_addr,
+            Identifier::new("vector").unwrap()
+        ))
+        .unwrap()
+        .is_some());
+    assert!(state
+        .get(&AccessPath::code_access_path(
+            std_addr,
+            Identifier::new("coin").unwrap()
+        ))
+        .unwrap()
+        .is_none());
+
+    // the selected modules have to contain all of their dependencies
+    let resolver = StateViewImpl::new(&state);
+    let mut table_state = MockTableState::new(&state);
+    let mut table_resolver = TableViewImpl::new(&mut table_state);
+    let stdlib_config = StdlibConfig {
+        bundles: vec![StdlibBundle::NovaStdlib],
+        modules: vec![],
+    };
+    assert!(vm
+        .initialize(&resolver, &mut table_resolver, &stdlib_config, None)
+        .is_err());
+}
+
+#[test]
+fn test_initialize_with_empty_stdlib_config() {
+    let mut chain = MockChain::new();
+    let mut vm = NovaVM::new();
+
+    let mut state = chain.create_state();
+    let output = {
+        let resolver = StateViewImpl::new(&state);
+        let mut table_state = MockTableState::new(&state);
+        let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+        let stdlib_config = StdlibConfig {
+            bundles: vec![],
+            modules: vec![],
+        };
+        let (status, output, _) = vm
+            .initialize(&resolver, &mut table_resolver, &stdlib_config, None)
+            .expect("genesis must succeed");
+        assert_eq!(status, VMStatus::Executed);
+        output
+    };
+    state.push_write_set(output.into_inner().1);
+    chain.commit(state);
+
+    // an empty config publishes the whole standard library
+    let state = chain.create_state();
+    assert!(state
+        .get(&AccessPath::code_access_path(
+            AccountAddress::ONE,
+            Identifier::new("coin").unwrap()
+        ))
+        .unwrap()
+        .is_some());
+}
@@ -1,6 +1,12 @@
 #[cfg(feature = "testing")]
 pub mod move_unit_tests;
+pub mod arguments_tests;
+pub mod bank_tests;
+pub mod genesis_tests;
+pub mod storage_quota_tests;
 pub mod table_tests;
 pub mod tx_simple_tests;
 pub mod tx_std_coin_tests;
+pub mod tx_token_tests;
+pub mod verifier_tests;
 pub mod vm_error_tests;
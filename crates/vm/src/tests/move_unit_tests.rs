@@ -13,7 +13,8 @@ use move_deps::{
 };
 use nova_gas::NativeGasParameters;
 use nova_natives::{
-    all_natives, block::NativeBlockContext, code::NativeCodeContext, table::NativeTableContext,
+    all_natives, bank::NativeBankContext, block::NativeBlockContext, code::NativeCodeContext,
+    table::NativeTableContext, transaction_context::NativeTransactionContext,
 };
 use std::path::PathBuf;
 use tempfile::tempdir;
@@ -29,10 +30,12 @@ fn unit_test_extensions_hook(exts: &mut NativeContextExtensions) {
     exts.add(NativeTableContext::new([0; 32], unsafe {
         &mut BLANK_TABLE_RESOLVER
     }));
-    exts.add(NativeBlockContext::new(&MockApi {
-        height: 0,
-        timestamp: 0,
-    }));
+
+    // leaked to satisfy the 'static bound of the hook; one per test is negligible
+    let api: &'static MockApi = Box::leak(Box::new(MockApi::default()));
+    exts.add(NativeBlockContext::new(api));
+    exts.add(NativeBankContext::new(api));
+    exts.add(NativeTransactionContext::new([0; 32], vec![], None));
 }
 
 fn nova_test_natives() -> NativeFunctionTable {
@@ -0,0 +1,130 @@
+use nova_gas::Gas;
+use nova_storage::{state_view_impl::StateViewImpl, table_view_impl::TableViewImpl};
+use nova_types::{
+    genesis::StdlibConfig, message::Message, module::ModuleBundle,
+    storage_usage::STORAGE_QUOTA_EXCEEDED,
+};
+
+use move_deps::move_core_types::{account_address::AccountAddress, vm_status::VMStatus};
+
+use crate::test_utils::mock_chain::{MockApi, MockChain, MockTableState};
+use crate::test_utils::{entry_function, generate_account, module};
+use crate::NovaVM;
+
+/// Execute the message and return its status with the gas used.
+fn execute(chain: &mut MockChain, vm: &mut NovaVM, api: &MockApi, msg: Message) -> (VMStatus, u64) {
+    let mut state = chain.create_state();
+    let (status, output, _) = {
+        let resolver = StateViewImpl::new(&state);
+        let mut table_state = MockTableState::new(&state);
+        let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+        vm.execute_message(
+            msg,
+            &resolver,
+            &mut table_resolver,
+            Some(api),
+            Gas::new(1_000_000u64),
+        )
+        .expect("nova vm failure")
+    };
+
+    let gas_used = output.gas_used();
+    if status == VMStatus::Executed {
+        state.push_write_set(output.into_inner().1);
+        chain.commit(state);
+    } else {
+        // the failed messages do not write anything
+        assert!(output.write_set().into_iter().next().is_none());
+    }
+    (status, gas_used)
+}
+
+fn setup() -> (MockChain, NovaVM, MockApi) {
+    let mut chain = MockChain::new();
+    let mut vm = NovaVM::new();
+    let api = chain.create_api(0, 0);
+
+    let mut state = chain.create_state();
+    let output = {
+        let resolver = StateViewImpl::new(&state);
+        let mut table_state = MockTableState::new(&state);
+        let mut table_resolver = TableViewImpl::new(&mut table_state);
+
+        let (status, output, _) = vm
+            .initialize(
+                &resolver,
+                &mut table_resolver,
+                &StdlibConfig::default(),
+                None,
+            )
+            .expect("Module must load");
+        assert_eq!(status, VMStatus::Executed);
+        output
+    };
+    state.push_write_set(output.into_inner().1);
+    chain.commit(state);
+
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        Message::new_module(
+            vec![0; 32],
+            Some(AccountAddress::ONE),
+            ModuleBundle::from(module::create_basic_coin()),
+        ),
+    );
+    assert_eq!(status, VMStatus::Executed);
+
+    (chain, vm, api)
+}
+
+#[test]
+fn test_storage_quota() {
+    let (mut chain, mut vm, api) = setup();
+    let account_two = generate_account("0x2");
+    let account_three = generate_account("0x3");
+    let fee_payer = generate_account("0x4");
+
+    // the message is kept as failed and charged the gas used
+    vm.set_storage_quota(Some(10));
+    let (status, gas_used) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        Message::new_entry_function(vec![1; 32], Some(account_two), entry_function::mint(100)),
+    );
+    assert_eq!(status, VMStatus::Error(STORAGE_QUOTA_EXCEEDED));
+    assert_ne!(gas_used, 0);
+
+    // the storage of a sponsored message counts against the quota of its owner
+    let (status, gas_used) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        Message::new_entry_function(vec![2; 32], Some(account_three), entry_function::mint(100))
+            .with_fee_payer(fee_payer),
+    );
+    assert_eq!(status, VMStatus::Error(STORAGE_QUOTA_EXCEEDED));
+    assert_ne!(gas_used, 0);
+
+    vm.set_storage_quota(None);
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        Message::new_entry_function(vec![3; 32], Some(account_two), entry_function::mint(100)),
+    );
+    assert_eq!(status, VMStatus::Executed);
+
+    // accounts over the quota can still run the messages not growing their storage
+    vm.set_storage_quota(Some(10));
+    let (status, _) = execute(
+        &mut chain,
+        &mut vm,
+        &api,
+        Message::new_entry_function(vec![4; 32], Some(account_two), entry_function::mint(100)),
+    );
+    assert_eq!(status, VMStatus::Executed);
+}
@@ -1,6 +1,10 @@
 use move_deps::move_core_types::vm_status::VMStatus;
 
-use nova_types::{message::Message, module::ModuleBundle, size_delta::SizeDelta};
+use nova_types::{
+    entry_function::EntryFunction, message::Message, module::ModuleBundle, size_delta::SizeDelta,
+};
+
+use proptest::{collection::vec, prelude::*};
 
 use crate::test_utils::generate_account;
 use crate::test_utils::mock_tx::{run_transaction, ExpectedOutput, ExpectedOutputItem, MockTx};
@@ -54,6 +58,8 @@ fn test_tables() {
                     // Per Item = 59 + 8 = 67
                     // Table len 3 => 67 * 3 = 201
                 ),
+                // 303 bytes added => 303 * 10
+                Item::StorageFee(3030),
             ]),
         ),
         MockTx::one(
@@ -66,7 +72,7 @@ fn test_tables() {
                 Item::VMStatusReturn(VMStatus::Executed),
                 Item::SizeChange(
                     [
-                        (account_two, SizeDelta::decreasing(289)),
+                        (account_two, SizeDelta::decreasing(102 + 201)),
                         (account_three, SizeDelta::increasing(130 + 201 + 111)),
                         // access_path "0000000000000000000000000000000000000003/1/0x2::TableTestData::S<address, 0x1::table::Table<u64, u64>>" => 102
                         // S { address, u64 } => 28
@@ -81,6 +87,11 @@ fn test_tables() {
                     ]
                     .into(),
                 ),
+                // 442 bytes added => 442 * 10
+                Item::StorageFee(4420),
+                // 303 bytes removed from the payer of the table
+                // => 303 * 10 deposited * 50% refunded to the payer
+                Item::StorageRefunds([(account_two, 1515)].into()),
             ]),
         ),
         MockTx::one(
@@ -146,7 +157,14 @@ fn test_tables() {
             ),
             ExpectedOutput(vec![
                 Item::VMStatusReturn(VMStatus::Executed),
-                Item::SizeChange([(generate_account("0x7"), SizeDelta::decreasing(162))].into()),
+                Item::SizeChange(
+                    [(generate_account("0x7"), SizeDelta::decreasing(102 + 134))].into(),
+                    // S<u64, u64> => 102
+                    // 2 rows => 67 * 2 = 134
+                ),
+                // 236 bytes removed => 236 * 10 deposited * 50% refunded
+                Item::StorageFee(0),
+                Item::StorageRefunds([(generate_account("0x7"), 1180)].into()),
             ]),
         ),
         MockTx::one(
@@ -183,3 +201,104 @@ fn test_tables() {
 
     run_transaction(testcases);
 }
+
+#[test]
+fn test_table_fee_payer() {
+    type Item = ExpectedOutputItem;
+
+    let account_two = generate_account("0x2");
+    let account_three = generate_account("0x3");
+    let fee_payer = generate_account("0x4");
+
+    let testcases: Vec<MockTx> = vec![
+        MockTx::one(
+            // publish module
+            Message::new_module(
+                vec![1; 32],
+                Some(account_two),
+                ModuleBundle::from(module::create_table_test_data()),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, None),
+        ),
+        // the fee payer only pays the fee, the storage usage stays with the owner
+        MockTx::one(
+            Message::new_entry_function(
+                vec![2; 32],
+                Some(account_three),
+                entry_function::table_len(),
+            )
+            .with_fee_payer(fee_payer),
+            ExpectedOutput(vec![
+                Item::VMStatusReturn(VMStatus::Executed),
+                Item::ResultBytes(vec![3, 0, 0, 0, 0, 0, 0, 0]),
+                Item::SizeChange([(account_three, SizeDelta::increasing(102 + 201))].into()),
+            ]),
+        ),
+        MockTx::one(
+            Message::new_entry_function(
+                vec![3; 32],
+                Some(account_two),
+                entry_function::table_move_from(),
+            )
+            .with_fee_payer(fee_payer),
+            ExpectedOutput::new(VMStatus::Executed, None),
+        ),
+        // the bytes stored by a sponsored message are released from the usage of
+        // the owner when it removes them without a fee payer
+        MockTx::one(
+            Message::new_entry_function(
+                vec![4; 32],
+                Some(account_two),
+                entry_function::table_remove(),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, None),
+        ),
+    ];
+
+    run_transaction(testcases);
+}
+
+fn table_op(op: u8) -> EntryFunction {
+    match op {
+        0 => entry_function::table_len(),
+        1 => entry_function::move_table(),
+        2 => entry_function::table_of_tables(),
+        3 => entry_function::table_move_from(),
+        4 => entry_function::table_remove(),
+        5 => entry_function::add_after_remove(),
+        _ => entry_function::prepare_table_for_iterator(),
+    }
+}
+
+proptest! {
+    // every case runs the genesis, so keep the number of cases low
+    #![proptest_config(ProptestConfig::with_cases(8))]
+
+    // `run_transaction` checks the storage usage of the accounts against the
+    // state after every message, whichever messages succeed or fail
+    #[test]
+    fn test_table_storage_usage_invariant(ops in vec((2u8..6, 0u8..7), 1..12)) {
+        let mut testcases: Vec<MockTx> = vec![MockTx::one(
+            // publish module
+            Message::new_module(
+                vec![1; 32],
+                Some(generate_account("0x2")),
+                ModuleBundle::from(module::create_table_test_data()),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, None),
+        )];
+
+        for (i, (account, op)) in ops.into_iter().enumerate() {
+            testcases.push(MockTx::one(
+                Message::new_entry_function(
+                    vec![i as u8 + 2; 32],
+                    Some(generate_account(&format!("0x{}", account))),
+                    table_op(op),
+                ),
+                ExpectedOutput(vec![]),
+            ));
+        }
+
+        run_transaction(testcases);
+    }
+}
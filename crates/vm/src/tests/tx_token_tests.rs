@@ -0,0 +1,182 @@
+use nova_types::{entry_function::EntryFunction, message::Message};
+
+use move_deps::move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::ModuleId,
+    vm_status::{AbortLocation, VMStatus},
+};
+
+use crate::test_utils::generate_account;
+use crate::test_utils::mock_tx::{run_transaction, ExpectedOutput, MockTx};
+
+fn token_entry_function(function: &str, args: Vec<Vec<u8>>) -> EntryFunction {
+    EntryFunction::new(
+        ModuleId::new(AccountAddress::ONE, Identifier::new("token").unwrap()),
+        Identifier::new(function).unwrap(),
+        vec![],
+        args,
+    )
+}
+
+fn string_arg(s: &str) -> Vec<u8> {
+    bcs::to_bytes(s).unwrap()
+}
+
+fn mint_args(name: &str, to: AccountAddress) -> Vec<Vec<u8>> {
+    vec![
+        string_arg("collection"),
+        string_arg(name),
+        string_arg("test token"),
+        string_arg("https://example.com/token"),
+        to.to_vec(),
+        bcs::to_bytes(&vec!["rarity".to_string()]).unwrap(),
+        bcs::to_bytes(&vec![b"legendary".to_vec()]).unwrap(),
+        bcs::to_bytes::<Vec<String>>(&vec![]).unwrap(),
+        bcs::to_bytes::<Vec<Vec<u8>>>(&vec![]).unwrap(),
+    ]
+}
+
+#[test]
+fn test_token() {
+    let account_two = generate_account("0x2");
+    let account_three = generate_account("0x3");
+
+    let testcases: Vec<MockTx> = vec![
+        MockTx::one(
+            // create a collection of up to two tokens
+            Message::new_entry_function(
+                vec![1; 32],
+                Some(account_two),
+                token_entry_function(
+                    "create_collection",
+                    vec![
+                        string_arg("collection"),
+                        string_arg("test collection"),
+                        string_arg("https://example.com/collection"),
+                        2u64.to_le_bytes().to_vec(),
+                        5u64.to_le_bytes().to_vec(),
+                        100u64.to_le_bytes().to_vec(),
+                        account_two.to_vec(),
+                    ],
+                ),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, Some(vec![])),
+        ),
+        MockTx::one(
+            // mint a token to 0x3
+            Message::new_entry_function(
+                vec![2; 32],
+                Some(account_two),
+                token_entry_function("mint", mint_args("token 1", account_three)),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, Some(vec![])),
+        ),
+        MockTx::one(
+            // mint a token to 0x2
+            Message::new_entry_function(
+                vec![3; 32],
+                Some(account_two),
+                token_entry_function("mint", mint_args("token 2", account_two)),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, Some(vec![])),
+        ),
+        MockTx::one(
+            // the collection is full
+            Message::new_entry_function(
+                vec![4; 32],
+                Some(account_two),
+                token_entry_function("mint", mint_args("token 3", account_two)),
+            ),
+            ExpectedOutput::new(
+                VMStatus::MoveAbort(
+                    AbortLocation::Module(ModuleId::new(
+                        AccountAddress::ONE,
+                        Identifier::new("token").unwrap(),
+                    )),
+                    0x20005,
+                ),
+                None,
+            ),
+        ),
+        MockTx::one(
+            // transfer token 1 from 0x3 to 0x2
+            Message::new_entry_function(
+                vec![5; 32],
+                Some(account_three),
+                token_entry_function(
+                    "transfer",
+                    vec![
+                        account_two.to_vec(),
+                        string_arg("collection"),
+                        string_arg("token 1"),
+                        account_two.to_vec(),
+                    ],
+                ),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, Some(vec![])),
+        ),
+        MockTx::one(
+            // get the owner of token 1
+            Message::new_entry_function(
+                vec![6; 32],
+                None,
+                token_entry_function(
+                    "owner_of",
+                    vec![
+                        account_two.to_vec(),
+                        string_arg("collection"),
+                        string_arg("token 1"),
+                    ],
+                ),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, Some(account_two.to_vec())),
+        ),
+        MockTx::one(
+            // burn token 1
+            Message::new_entry_function(
+                vec![7; 32],
+                Some(account_two),
+                token_entry_function(
+                    "burn",
+                    vec![
+                        account_two.to_vec(),
+                        string_arg("collection"),
+                        string_arg("token 1"),
+                    ],
+                ),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, Some(vec![])),
+        ),
+        MockTx::one(
+            // token 2 is moved to the freed index
+            Message::new_entry_function(
+                vec![8; 32],
+                None,
+                token_entry_function(
+                    "token_name_by_index",
+                    vec![
+                        account_two.to_vec(),
+                        string_arg("collection"),
+                        0u64.to_le_bytes().to_vec(),
+                    ],
+                ),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, Some(string_arg("token 2"))),
+        ),
+        MockTx::one(
+            // get the supply of the collection
+            Message::new_entry_function(
+                vec![9; 32],
+                None,
+                token_entry_function(
+                    "collection_supply",
+                    vec![account_two.to_vec(), string_arg("collection")],
+                ),
+            ),
+            ExpectedOutput::new(VMStatus::Executed, Some(1u64.to_le_bytes().to_vec())),
+        ),
+    ];
+
+    run_transaction(testcases);
+}
@@ -0,0 +1,24 @@
+use move_deps::{move_binary_format::CompiledModule, move_core_types::vm_status::StatusCode};
+
+use nova_types::module::Module;
+
+use crate::test_utils::module;
+use crate::verifier::verify_randomness_usage;
+
+fn compile(module: Module) -> CompiledModule {
+    CompiledModule::deserialize(module.code()).unwrap()
+}
+
+#[test]
+fn test_randomness_in_entry_functions() {
+    // the public entry and the private entry functions use randomness
+    let modules = [compile(module::create_randomness_entry())];
+    verify_randomness_usage(&modules).unwrap();
+}
+
+#[test]
+fn test_randomness_in_public_function() {
+    let modules = [compile(module::create_randomness_public())];
+    let err = verify_randomness_usage(&modules).unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::CONSTRAINT_NOT_SATISFIED);
+}
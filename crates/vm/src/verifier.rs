@@ -0,0 +1,96 @@
+use std::collections::BTreeSet;
+
+use move_deps::{
+    move_binary_format::{
+        access::ModuleAccess,
+        errors::{Location, PartialVMError, VMResult},
+        file_format::{Bytecode, FunctionHandleIndex, Visibility},
+        CompiledModule,
+    },
+    move_core_types::{language_storage::CORE_CODE_ADDRESS, vm_status::StatusCode},
+};
+
+/// The name of the marker struct which opts a module in to the randomness check.
+const RANDOMNESS_OPT_IN_MARKER: &str = "RandomnessEntryOnly";
+const RANDOMNESS_MODULE_NAME: &str = "randomness";
+
+/// Reject modules which declared `RandomnessEntryOnly` but expose randomness through
+/// a `public` or `public(friend)` non-entry function, either directly or through a
+/// private helper. Such functions allow test-and-abort attacks, because the calling
+/// module can abort the transaction whenever it dislikes the random outcome.
+pub(crate) fn verify_randomness_usage(modules: &[CompiledModule]) -> VMResult<()> {
+    for module in modules {
+        if !has_randomness_opt_in_marker(module) {
+            continue;
+        }
+
+        let uses_randomness = functions_using_randomness(module);
+        for func_def in module.function_defs() {
+            let callable_by_modules = !func_def.is_entry
+                && matches!(func_def.visibility, Visibility::Public | Visibility::Friend);
+            if callable_by_modules && uses_randomness.contains(&func_def.function) {
+                let handle = module.function_handle_at(func_def.function);
+                return Err(PartialVMError::new(StatusCode::CONSTRAINT_NOT_SATISFIED)
+                    .with_message(format!(
+                        "non-entry function '{}::{}' must not use randomness",
+                        module.self_id(),
+                        module.identifier_at(handle.name)
+                    ))
+                    .finish(Location::Module(module.self_id())));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn has_randomness_opt_in_marker(module: &CompiledModule) -> bool {
+    module.struct_defs().iter().any(|def| {
+        let handle = module.struct_handle_at(def.struct_handle);
+        module.identifier_at(handle.name).as_str() == RANDOMNESS_OPT_IN_MARKER
+    })
+}
+
+fn is_randomness_function(module: &CompiledModule, idx: FunctionHandleIndex) -> bool {
+    let handle = module.function_handle_at(idx);
+    let module_handle = module.module_handle_at(handle.module);
+    *module.address_identifier_at(module_handle.address) == CORE_CODE_ADDRESS
+        && module.identifier_at(module_handle.name).as_str() == RANDOMNESS_MODULE_NAME
+}
+
+fn callee(module: &CompiledModule, instr: &Bytecode) -> Option<FunctionHandleIndex> {
+    match instr {
+        Bytecode::Call(idx) => Some(*idx),
+        Bytecode::CallGeneric(idx) => Some(module.function_instantiation_at(*idx).handle),
+        _ => None,
+    }
+}
+
+/// Compute the function handles of the module which (transitively) call the randomness module.
+fn functions_using_randomness(module: &CompiledModule) -> BTreeSet<FunctionHandleIndex> {
+    let mut result = BTreeSet::new();
+    loop {
+        let mut changed = false;
+        for func_def in module.function_defs() {
+            if result.contains(&func_def.function) {
+                continue;
+            }
+
+            let uses_randomness = func_def.code.as_ref().map_or(false, |code| {
+                code.code.iter().any(|instr| match callee(module, instr) {
+                    Some(idx) => result.contains(&idx) || is_randomness_function(module, idx),
+                    None => false,
+                })
+            });
+
+            if uses_randomness {
+                result.insert(func_def.function);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return result;
+        }
+    }
+}
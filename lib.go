@@ -35,11 +35,93 @@ func (vm *VM) Initialize(
 		kvStore,
 		vm.printDebug,
 		bz,
+		nil,
 	)
 
 	return err
 }
 
+// InitializeWithStdlib deploys the selected std libs and move libs
+// for bootstrapping genesis
+func (vm *VM) InitializeWithStdlib(
+	kvStore api.KVStore,
+	moduleBundle types.ModuleBundle,
+	stdlibConfig types.StdlibConfig,
+) error {
+	bz, err := json.Marshal(moduleBundle)
+	if err != nil {
+		return err
+	}
+
+	sc, err := json.Marshal(stdlibConfig)
+	if err != nil {
+		return err
+	}
+
+	err = api.Initialize(
+		vm.inner,
+		kvStore,
+		vm.printDebug,
+		bz,
+		sc,
+	)
+
+	return err
+}
+
+// InitializeWithConfig publishes the standard library and applies
+// the genesis config in a single session
+func (vm *VM) InitializeWithConfig(
+	kvStore api.KVStore,
+	goApi api.GoAPI,
+	genesisConfig types.GenesisConfig,
+) error {
+	bz, err := json.Marshal(genesisConfig)
+	if err != nil {
+		return err
+	}
+
+	err = api.InitializeWithConfig(
+		vm.inner,
+		kvStore,
+		goApi,
+		vm.printDebug,
+		bz,
+	)
+
+	return err
+}
+
+// LoadVMConfig configures the vm with the gas schedule and the module
+// publishers stored by InitializeWithConfig. It has to be called
+// whenever the vm is created for a chain which is already initialized.
+func (vm *VM) LoadVMConfig(kvStore api.KVStore) error {
+	return api.LoadVMConfig(vm.inner, kvStore)
+}
+
+// RegisterTxnArgStruct allows the struct `structName` (`0x2::module::Name`)
+// as an argument of entry functions and scripts. The arguments are built by
+// calling the function `constructor` of the struct module with the fields of
+// the given value; framework and generic structs cannot be registered.
+// The registration is not stored in the state, so every node has to repeat it
+// whenever it creates the VM.
+func (vm *VM) RegisterTxnArgStruct(structName string, constructor string) error {
+	return api.RegisterTxnArgStruct(vm.inner, structName, constructor)
+}
+
+// UnregisterTxnArgStruct disallows a struct registered by RegisterTxnArgStruct.
+func (vm *VM) UnregisterTxnArgStruct(structName string) error {
+	return api.UnregisterTxnArgStruct(vm.inner, structName)
+}
+
+// SetStorageQuota limits the number of bytes stored by an account, including
+// the tables it pays for; zero removes the limit. Messages growing the storage
+// of an account over the quota fail with types.StorageQuotaExceededError.
+// Like the registered structs, the quota is not stored in the state.
+func (vm *VM) SetStorageQuota(storageQuota uint64) error {
+	return api.SetStorageQuota(vm.inner, storageQuota)
+}
+
 // VM Destroyer
 func (vm *VM) Destroy() {
 	api.ReleaseVM(vm.inner)
@@ -117,6 +199,36 @@ func (vm *VM) ExecuteEntryFunction(
 	txHash types.Bytes, // txHash is used for sessionID
 	sender types.AccountAddress,
 	payload types.ExecuteEntryFunctionPayload,
+) (uint64, []types.Event, []types.SizeDelta, error) {
+	return vm.executeEntryFunction(kvStore, goApi, gasLimit, txHash, sender, nil, payload)
+}
+
+// ExecuteEntryFunctionWithFeePayer calls a given contract on behalf of the sender.
+// The gas, including the storage deposit, is charged to the fee payer, while the
+// storage usage stays with the accounts owning the bytes. The fee payer is
+// also passed to the entry function when it declares one more signer.
+// CONTRACT: the caller must have verified the signature of the fee payer,
+// as the VM charges it and signs for it without any further check.
+func (vm *VM) ExecuteEntryFunctionWithFeePayer(
+	kvStore api.KVStore,
+	goApi api.GoAPI,
+	gasLimit uint64,
+	txHash types.Bytes, // txHash is used for sessionID
+	sender types.AccountAddress,
+	feePayer types.AccountAddress,
+	payload types.ExecuteEntryFunctionPayload,
+) (uint64, []types.Event, []types.SizeDelta, error) {
+	return vm.executeEntryFunction(kvStore, goApi, gasLimit, txHash, sender, feePayer, payload)
+}
+
+func (vm *VM) executeEntryFunction(
+	kvStore api.KVStore,
+	goApi api.GoAPI,
+	gasLimit uint64,
+	txHash types.Bytes,
+	sender types.AccountAddress,
+	feePayer types.AccountAddress,
+	payload types.ExecuteEntryFunctionPayload,
 ) (uint64, []types.Event, []types.SizeDelta, error) {
 	bz, err := json.Marshal(payload)
 	if err != nil {
@@ -131,6 +243,7 @@ func (vm *VM) ExecuteEntryFunction(
 		gasLimit,
 		txHash,
 		sender,
+		feePayer,
 		bz,
 	)
 
@@ -152,6 +265,36 @@ func (vm *VM) ExecuteScript(
 	txHash types.Bytes, // txHash is used for sessionID
 	sender types.AccountAddress,
 	payload types.ExecuteScriptPayload,
+) (uint64, []types.Event, []types.SizeDelta, error) {
+	return vm.executeScript(kvStore, goApi, gasLimit, txHash, sender, nil, payload)
+}
+
+// ExecuteScriptWithFeePayer executes a given script on behalf of the sender.
+// The gas, including the storage deposit, is charged to the fee payer, while the
+// storage usage stays with the accounts owning the bytes. The fee payer is
+// also passed to the script when it declares one more signer.
+// CONTRACT: the caller must have verified the signature of the fee payer,
+// as the VM charges it and signs for it without any further check.
+func (vm *VM) ExecuteScriptWithFeePayer(
+	kvStore api.KVStore,
+	goApi api.GoAPI,
+	gasLimit uint64,
+	txHash types.Bytes, // txHash is used for sessionID
+	sender types.AccountAddress,
+	feePayer types.AccountAddress,
+	payload types.ExecuteScriptPayload,
+) (uint64, []types.Event, []types.SizeDelta, error) {
+	return vm.executeScript(kvStore, goApi, gasLimit, txHash, sender, feePayer, payload)
+}
+
+func (vm *VM) executeScript(
+	kvStore api.KVStore,
+	goApi api.GoAPI,
+	gasLimit uint64,
+	txHash types.Bytes,
+	sender types.AccountAddress,
+	feePayer types.AccountAddress,
+	payload types.ExecuteScriptPayload,
 ) (uint64, []types.Event, []types.SizeDelta, error) {
 	bz, err := json.Marshal(payload)
 	if err != nil {
@@ -166,6 +309,7 @@ func (vm *VM) ExecuteScript(
 		gasLimit,
 		txHash,
 		sender,
+		feePayer,
 		bz,
 	)
 
@@ -192,6 +336,77 @@ func (vm *VM) DecodeMoveResource(
 	)
 }
 
+// DecodeTableEntry decode table entry bytes to key and value
+// instances and return as jSON string. Pass nil valueBytes
+// to load the value of the key from the store.
+func (vm *VM) DecodeTableEntry(
+	kvStore api.KVStore,
+	tableHandle types.AccountAddress,
+	keyBytes []byte,
+	valueBytes []byte,
+) ([]byte, error) {
+	return api.DecodeTableEntry(
+		kvStore,
+		tableHandle,
+		keyBytes,
+		valueBytes,
+	)
+}
+
+// ScanTable returns up to limit entries of the table as jSON string
+// with the key to start the next page from. Pass nil startKey to
+// start from the first entry in the given order. The limit is capped
+// to 100 entries.
+func (vm *VM) ScanTable(
+	kvStore api.KVStore,
+	tableHandle types.AccountAddress,
+	startKey []byte,
+	limit uint64,
+	order types.Order,
+) ([]byte, error) {
+	return api.ScanTable(
+		kvStore,
+		tableHandle,
+		startKey,
+		limit,
+		int32(order),
+	)
+}
+
+// ListAccountData returns the names of the modules and the
+// decoded resources stored under the address as jSON string
+func (vm *VM) ListAccountData(
+	kvStore api.KVStore,
+	address types.AccountAddress,
+) ([]byte, error) {
+	return api.ListAccountData(
+		kvStore,
+		address,
+	)
+}
+
+// ExportGenesisState returns the whole state of the
+// store as jSON string to be imported into a new chain
+func (vm *VM) ExportGenesisState(
+	kvStore api.KVStore,
+) ([]byte, error) {
+	return api.ExportGenesisState(
+		kvStore,
+	)
+}
+
+// ImportGenesisState writes the state exported by
+// ExportGenesisState into the fresh store
+func (vm *VM) ImportGenesisState(
+	kvStore api.KVStore,
+	genesisState []byte,
+) error {
+	return api.ImportGenesisState(
+		kvStore,
+		genesisState,
+	)
+}
+
 // DecodeModuleBytes decode module bytes to MoveModule
 // instance and return as jSON string
 func (vm *VM) DecodeModuleBytes(
@@ -3,7 +3,10 @@ package nova_test
 import (
 	"bytes"
 	"encoding/base64"
+	"encoding/hex"
+	"encoding/json"
 	"os"
+	"strings"
 	"testing"
 	"time"
 
@@ -34,6 +37,61 @@ func initializeVM(t *testing.T) (vm.VM, *api.Lookup) {
 	return vm, kvStore
 }
 
+func Test_InitializeWithConfig(t *testing.T) {
+	f, err := os.ReadFile("./crates/move-test/build/test1/bytecode_modules/BasicCoin.mv")
+	require.NoError(t, err)
+
+	account, err := types.NewAccountAddress("0x2")
+	require.NoError(t, err)
+	holder, err := types.NewAccountAddress("0x3")
+	require.NoError(t, err)
+
+	kvStore := api.NewLookup()
+	vm := vm.NewVM(true)
+	defer vm.Destroy()
+
+	mockAPI := api.NewMockBlockInfo(0, uint64(time.Now().Unix()))
+	err = vm.InitializeWithConfig(
+		kvStore,
+		api.NewMockAPI(&mockAPI),
+		types.GenesisConfig{
+			ModuleBundle: &types.ModuleBundle{
+				Codes: []types.Module{{Code: f}},
+			},
+			Accounts: []types.AccountAddress{account},
+			Coins: []types.GenesisCoin{
+				{
+					CoinType: "0x1::BasicCoin::Nova",
+					Name:     "Nova",
+					Symbol:   "NOVA",
+					Decimals: 6,
+					Balances: []types.GenesisBalance{{Address: holder, Amount: 100}},
+				},
+			},
+			ModulePublishers: []types.AccountAddress{holder},
+		},
+	)
+	require.NoError(t, err)
+
+	bz, err := vm.ListAccountData(kvStore, account)
+	require.NoError(t, err)
+	require.Contains(t, string(bz), `"type":"0x1::account::Account"`)
+
+	bz, err = vm.ListAccountData(kvStore, holder)
+	require.NoError(t, err)
+	require.Contains(t, string(bz), `"type":"0x1::coin::CoinStore<0x1::BasicCoin::Nova>"`)
+
+	// the module publishers are stored to configure the vm on restart
+	stdAccount, err := types.NewAccountAddress("0x1")
+	require.NoError(t, err)
+	bz, err = vm.ListAccountData(kvStore, stdAccount)
+	require.NoError(t, err)
+	require.Contains(t, string(bz), `"type":"0x1::vm_config::ModulePublishers"`)
+
+	err = vm.LoadVMConfig(kvStore)
+	require.NoError(t, err)
+}
+
 func Test_PublishModuleBundle(t *testing.T) {
 	vm, kvStore := initializeVM(t)
 	defer vm.Destroy()
@@ -176,7 +234,8 @@ func Test_FailOnExecute(t *testing.T) {
 		Args:     []types.Bytes{types.SerializeUint64(amount)},
 	}
 
-	mockAPI := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	blockInfo := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	mockAPI := api.NewMockAPI(&blockInfo)
 	_, _, _, err = vm.ExecuteEntryFunction(
 		kvStore,
 		mockAPI,
@@ -210,7 +269,8 @@ func Test_OutOfGas(t *testing.T) {
 		Args:     []types.Bytes{types.SerializeUint64(amount)},
 	}
 
-	mockAPI := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	blockInfo := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	mockAPI := api.NewMockAPI(&blockInfo)
 	_, _, _, err = vm.ExecuteEntryFunction(
 		kvStore,
 		mockAPI,
@@ -245,7 +305,8 @@ func Test_QueryContract(t *testing.T) {
 		Args:     []types.Bytes{types.Bytes(testAccount)},
 	}
 
-	mockAPI := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	blockInfo := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	mockAPI := api.NewMockAPI(&blockInfo)
 	res, err := vm.QueryEntryFunction(
 		kvStore,
 		mockAPI,
@@ -273,6 +334,47 @@ func Test_DecodeResource(t *testing.T) {
 	require.Equal(t, bz, []byte(`{"type":"0x2::TestCoin::Coin<0x2::TestCoin::Nova>","data":{"test":true,"value":"300"}}`))
 }
 
+func Test_ListAccountData(t *testing.T) {
+	vm, kvStore := initializeVM(t)
+	defer vm.Destroy()
+
+	publishModuleBundle(t, vm, kvStore)
+
+	minter, err := types.NewAccountAddress("0x2")
+	require.NoError(t, err)
+
+	mintCoin(t, vm, kvStore, minter, 100)
+
+	bz, err := vm.ListAccountData(kvStore, minter)
+	require.NoError(t, err)
+	require.Contains(t, string(bz), `"TestCoin"`)
+	require.Contains(t, string(bz), `"type":"0x2::TestCoin::Coin<0x2::TestCoin::Nova>"`)
+}
+
+func Test_GenesisState(t *testing.T) {
+	vm, kvStore := initializeVM(t)
+	defer vm.Destroy()
+
+	publishModuleBundle(t, vm, kvStore)
+
+	minter, err := types.NewAccountAddress("0x2")
+	require.NoError(t, err)
+
+	mintCoin(t, vm, kvStore, minter, 100)
+
+	genesisState, err := vm.ExportGenesisState(kvStore)
+	require.NoError(t, err)
+	require.Contains(t, string(genesisState), `"type":"0x2::TestCoin::Coin<0x2::TestCoin::Nova>"`)
+
+	newKvStore := api.NewLookup()
+	err = vm.ImportGenesisState(newKvStore, genesisState)
+	require.NoError(t, err)
+
+	bz, err := vm.ExportGenesisState(newKvStore)
+	require.NoError(t, err)
+	require.Equal(t, genesisState, bz)
+}
+
 func Test_DecodeModule(t *testing.T) {
 	vm, _ := initializeVM(t)
 	defer vm.Destroy()
@@ -318,7 +420,8 @@ func Test_ExecuteScript(t *testing.T) {
 		Args:   []types.Bytes{optionalUint64},
 	}
 
-	mockAPI := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	blockInfo := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	mockAPI := api.NewMockAPI(&blockInfo)
 	usedGas, events, _, err := vm.ExecuteScript(
 		kvStore,
 		mockAPI,
@@ -356,7 +459,8 @@ func Test_TableIterator(t *testing.T) {
 		Args:     []types.Bytes{},
 	}
 
-	mockAPI := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	blockInfo := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	mockAPI := api.NewMockAPI(&blockInfo)
 	_, _, _, err = vm.ExecuteEntryFunction(
 		kvStore,
 		mockAPI,
@@ -409,3 +513,154 @@ func Test_TableIterator(t *testing.T) {
 	)
 	require.NoError(t, err)
 }
+
+func Test_ScanTable(t *testing.T) {
+	vm, kvStore := initializeVM(t)
+	defer vm.Destroy()
+
+	publishModuleBundle(t, vm, kvStore)
+
+	testAccount, err := types.NewAccountAddress("0x2")
+	require.NoError(t, err)
+	orderedAccount, err := types.NewAccountAddress("0x3")
+	require.NoError(t, err)
+
+	// prepare a table with bcs keys under 0x2 and one with ordered keys under 0x3
+	blockInfo := api.NewMockBlockInfo(100, uint64(time.Now().Unix()))
+	mockAPI := api.NewMockAPI(&blockInfo)
+	for _, prepare := range []struct {
+		sender   types.AccountAddress
+		function types.Identifier
+	}{
+		{testAccount, "prepare_table_for_iterator"},
+		{orderedAccount, "prepare_ordered_table_for_iterator"},
+	} {
+		_, _, _, err = vm.ExecuteEntryFunction(
+			kvStore,
+			mockAPI,
+			100000000,
+			bytes.Repeat([]byte{0}, 32),
+			prepare.sender,
+			types.ExecuteEntryFunctionPayload{
+				Module: types.ModuleId{
+					Address: testAccount,
+					Name:    "TableTestData",
+				},
+				Function: prepare.function,
+				TyArgs:   []types.TypeTag{},
+				Args:     []types.Bytes{},
+			},
+		)
+		require.NoError(t, err)
+	}
+
+	bcsHandle := findTableHandle(t, vm, kvStore, "Bcs", 10)
+	orderedHandle := findTableHandle(t, vm, kvStore, "Ordered", 4)
+
+	// pages in both orders
+	keys, nextKey := scanTable(t, vm, kvStore, bcsHandle, nil, 3, types.OrderAscending)
+	require.Equal(t, []string{"1", "2", "3"}, keys)
+	keys, _ = scanTable(t, vm, kvStore, bcsHandle, nextKey, 3, types.OrderAscending)
+	require.Equal(t, []string{"4", "5", "6"}, keys)
+
+	keys, nextKey = scanTable(t, vm, kvStore, bcsHandle, nil, 3, types.OrderDescending)
+	require.Equal(t, []string{"10", "9", "8"}, keys)
+	keys, _ = scanTable(t, vm, kvStore, bcsHandle, nextKey, 3, types.OrderDescending)
+	require.Equal(t, []string{"7", "6", "5"}, keys)
+
+	// ordered keys follow the order of the values, unlike little endian bcs keys
+	keys, nextKey = scanTable(t, vm, kvStore, orderedHandle, nil, 3, types.OrderAscending)
+	require.Equal(t, []string{"1", "2", "256"}, keys)
+	keys, lastKey := scanTable(t, vm, kvStore, orderedHandle, nextKey, 3, types.OrderAscending)
+	require.Equal(t, []string{"65536"}, keys)
+	require.Nil(t, lastKey)
+
+	keys, _ = scanTable(t, vm, kvStore, orderedHandle, nil, 100, types.OrderDescending)
+	require.Equal(t, []string{"65536", "256", "2", "1"}, keys)
+
+	// the stored key of a page decodes into its entry
+	bz, err := vm.DecodeTableEntry(kvStore, orderedHandle, nextKey, nil)
+	require.NoError(t, err)
+	require.Equal(t, `{"key_type":"u64","value_type":"u64","key":"65536","value":"65536"}`, string(bz))
+
+	bz, err = vm.DecodeTableEntry(kvStore, orderedHandle, nextKey, types.SerializeUint64(7))
+	require.NoError(t, err)
+	require.Equal(t, `{"key_type":"u64","value_type":"u64","key":"65536","value":"7"}`, string(bz))
+
+	_, err = vm.ScanTable(kvStore, bcsHandle, nil, 0, types.OrderAscending)
+	require.Error(t, err)
+}
+
+// findTableHandle returns the handle of the table with the key encoding and the length
+func findTableHandle(
+	t *testing.T,
+	vm vm.VM,
+	kvStore *api.Lookup,
+	keyEncoding string,
+	length uint64,
+) types.AccountAddress {
+	bz, err := vm.ExportGenesisState(kvStore)
+	require.NoError(t, err)
+
+	var genesisState struct {
+		TableMetas []struct {
+			Handle string `json:"handle"`
+			Meta   struct {
+				KeyEncoding string  `json:"key_encoding"`
+				Length      *uint64 `json:"length"`
+			} `json:"meta"`
+		} `json:"table_metas"`
+	}
+	require.NoError(t, json.Unmarshal(bz, &genesisState))
+
+	for _, tableMeta := range genesisState.TableMetas {
+		meta := tableMeta.Meta
+		if meta.KeyEncoding == keyEncoding && meta.Length != nil && *meta.Length == length {
+			handle, err := types.NewAccountAddress(tableMeta.Handle)
+			require.NoError(t, err)
+
+			return handle
+		}
+	}
+
+	require.FailNow(t, "table not found")
+	return nil
+}
+
+// scanTable returns the keys of the page with the stored key of the next page
+func scanTable(
+	t *testing.T,
+	vm vm.VM,
+	kvStore *api.Lookup,
+	handle types.AccountAddress,
+	startKey []byte,
+	limit uint64,
+	order types.Order,
+) ([]string, []byte) {
+	bz, err := vm.ScanTable(kvStore, handle, startKey, limit, order)
+	require.NoError(t, err)
+
+	var page struct {
+		Entries []struct {
+			Key   string `json:"key"`
+			Value string `json:"value"`
+		} `json:"entries"`
+		NextKey *string `json:"next_key"`
+	}
+	require.NoError(t, json.Unmarshal(bz, &page))
+
+	keys := make([]string, 0, len(page.Entries))
+	for _, entry := range page.Entries {
+		require.Equal(t, entry.Key, entry.Value)
+		keys = append(keys, entry.Key)
+	}
+
+	if page.NextKey == nil {
+		return keys, nil
+	}
+
+	nextKey, err := hex.DecodeString(strings.TrimPrefix(*page.NextKey, "0x"))
+	require.NoError(t, err)
+
+	return keys, nextKey
+}
@@ -1,8 +1,12 @@
 use crate::error::GoError;
-use crate::memory::UnmanagedVector;
+use crate::memory::{U8SliceView, UnmanagedVector};
 
 use anyhow::anyhow;
-use nova_natives::block::BlockInfoResolver;
+use move_deps::move_core_types::account_address::AccountAddress;
+use nova_natives::{
+    bank::BankBridge,
+    block::{BlockContext, BlockInfoResolver},
+};
 
 // this represents something passed in from the caller side of FFI
 // in this case a struct with go function pointers
@@ -22,6 +26,41 @@ pub struct GoApi_vtable {
         *mut u64,             // timestamp
         *mut UnmanagedVector, // error_msg
     ) -> i32,
+    pub get_block_context: extern "C" fn(
+        *const api_t,
+        *mut UnmanagedVector, // chain_id
+        *mut UnmanagedVector, // block_hash
+        *mut UnmanagedVector, // proposer
+        *mut u64,             // timestamp_nanos
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
+    pub bank_withdraw: extern "C" fn(
+        *const api_t,
+        U8SliceView,          // denom
+        U8SliceView,          // addr
+        u64,                  // amount
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
+    pub bank_deposit: extern "C" fn(
+        *const api_t,
+        U8SliceView,          // denom
+        U8SliceView,          // addr
+        u64,                  // amount
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
+    pub bank_balance: extern "C" fn(
+        *const api_t,
+        U8SliceView,          // denom
+        U8SliceView,          // addr
+        *mut u64,             // balance
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
+    pub bank_supply: extern "C" fn(
+        *const api_t,
+        U8SliceView,          // denom
+        *mut u64,             // supply
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
 }
 
 #[repr(C)]
@@ -59,4 +98,139 @@ impl BlockInfoResolver for GoApi {
 
         Ok((height, timestamp))
     }
+
+    // return chain id, block hash, proposer and timestamp in nanoseconds of the latest block
+    fn get_block_context(&self) -> anyhow::Result<BlockContext> {
+        let mut chain_id = UnmanagedVector::default();
+        let mut block_hash = UnmanagedVector::default();
+        let mut proposer = UnmanagedVector::default();
+        let mut timestamp_nanos = 0_u64;
+        let mut error_msg = UnmanagedVector::default();
+
+        let go_error: GoError = (self.vtable.get_block_context)(
+            self.state,
+            &mut chain_id,
+            &mut block_hash,
+            &mut proposer,
+            &mut timestamp_nanos,
+            &mut error_msg,
+        )
+        .into();
+        // We destruct the UnmanagedVectors here, no matter if we need the data.
+        let chain_id = chain_id.consume().unwrap_or_default();
+        let block_hash = block_hash.consume().unwrap_or_default();
+        let proposer = proposer.consume().unwrap_or_default();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || "Failed to get latest block context".to_string();
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        Ok(BlockContext {
+            chain_id: String::from_utf8(chain_id)?,
+            block_hash,
+            proposer: AccountAddress::from_bytes(proposer)?,
+            timestamp_nanos,
+        })
+    }
+}
+
+impl BankBridge for GoApi {
+    // move coins from the account to the bridge escrow
+    fn bank_withdraw(&self, denom: &str, addr: &AccountAddress, amount: u64) -> anyhow::Result<()> {
+        let mut error_msg = UnmanagedVector::default();
+
+        let go_error: GoError = (self.vtable.bank_withdraw)(
+            self.state,
+            U8SliceView::new(Some(denom.as_bytes())),
+            U8SliceView::new(Some(addr.as_ref())),
+            amount,
+            &mut error_msg,
+        )
+        .into();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || format!("Failed to withdraw {}{} from bank", amount, denom);
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        Ok(())
+    }
+
+    // move coins from the bridge escrow to the account
+    fn bank_deposit(&self, denom: &str, addr: &AccountAddress, amount: u64) -> anyhow::Result<()> {
+        let mut error_msg = UnmanagedVector::default();
+
+        let go_error: GoError = (self.vtable.bank_deposit)(
+            self.state,
+            U8SliceView::new(Some(denom.as_bytes())),
+            U8SliceView::new(Some(addr.as_ref())),
+            amount,
+            &mut error_msg,
+        )
+        .into();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || format!("Failed to deposit {}{} to bank", amount, denom);
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn bank_balance(&self, denom: &str, addr: &AccountAddress) -> anyhow::Result<u64> {
+        let mut balance = 0_u64;
+        let mut error_msg = UnmanagedVector::default();
+
+        let go_error: GoError = (self.vtable.bank_balance)(
+            self.state,
+            U8SliceView::new(Some(denom.as_bytes())),
+            U8SliceView::new(Some(addr.as_ref())),
+            &mut balance,
+            &mut error_msg,
+        )
+        .into();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || format!("Failed to query bank balance of {}", denom);
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        Ok(balance)
+    }
+
+    fn bank_supply(&self, denom: &str) -> anyhow::Result<u64> {
+        let mut supply = 0_u64;
+        let mut error_msg = UnmanagedVector::default();
+
+        let go_error: GoError = (self.vtable.bank_supply)(
+            self.state,
+            U8SliceView::new(Some(denom.as_bytes())),
+            &mut supply,
+            &mut error_msg,
+        )
+        .into();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || format!("Failed to query bank supply of {}", denom);
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        Ok(supply)
+    }
 }
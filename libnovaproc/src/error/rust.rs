@@ -1,6 +1,7 @@
 use errno::{set_errno, Errno};
 use move_deps::move_core_types::vm_status::{StatusCode, VMStatus};
 use nova_types::errors::{BackendError, NovaVMError};
+use nova_types::storage_usage::STORAGE_QUOTA_EXCEEDED;
 use thiserror::Error;
 
 use crate::memory::UnmanagedVector;
@@ -14,6 +15,8 @@ pub enum RustError {
     InvalidUtf8 { msg: String },
     #[error("Ran out of gas")]
     OutOfGas {},
+    #[error("Storage quota exceeded")]
+    StorageQuotaExceeded {},
     #[error("Caught panic")]
     Panic {},
     #[error("Null/Nil argument: {}", name)]
@@ -91,6 +94,10 @@ impl RustError {
         RustError::OutOfGas {}
     }
 
+    pub fn storage_quota_exceeded() -> Self {
+        RustError::StorageQuotaExceeded {}
+    }
+
     pub fn backend_failure<S: ToString>(msg: S) -> Self {
         RustError::BackendFailure {
             msg: msg.to_string(),
@@ -109,6 +116,7 @@ impl From<VMStatus> for RustError {
     fn from(source: VMStatus) -> Self {
         match &source {
             VMStatus::Executed => RustError::success(),
+            VMStatus::Error(STORAGE_QUOTA_EXCEEDED) => RustError::storage_quota_exceeded(),
             VMStatus::Error(_code) => RustError::vm_err(source),
             VMStatus::MoveAbort(location, code) => RustError::aborted(location, *code),
             VMStatus::ExecutionFailure {
@@ -158,6 +166,7 @@ enum ErrnoValue {
     Success = 0,
     Other = 1,
     OutOfGas = 2,
+    StorageQuotaExceeded = 3,
 }
 
 pub fn clear_error() {
@@ -182,6 +191,7 @@ pub fn set_error(err: RustError, error_msg: Option<&mut UnmanagedVector>) {
 
     let errno = match err {
         RustError::OutOfGas { .. } => ErrnoValue::OutOfGas,
+        RustError::StorageQuotaExceeded { .. } => ErrnoValue::StorageQuotaExceeded,
         _ => ErrnoValue::Other,
     } as i32;
     set_errno(Errno(errno));
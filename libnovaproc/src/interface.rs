@@ -14,6 +14,7 @@ use move_deps::move_cli::Move;
 use move_deps::move_core_types::account_address::AccountAddress;
 use move_deps::move_package::BuildConfig;
 use nova_compiler::New;
+use nova_types::iterator::Order;
 use novavm::NovaVM;
 
 #[allow(non_camel_case_types)]
@@ -52,11 +53,13 @@ pub extern "C" fn initialize(
     _verbose: bool,
     errmsg: Option<&mut UnmanagedVector>,
     module_bundle: ByteSliceView,
+    stdlib_config: ByteSliceView,
 ) -> () {
     let module_bundle = module_bundle.read().unwrap();
+    let stdlib_config = stdlib_config.read();
     let res = match to_vm(vm_ptr) {
         Some(vm) => catch_unwind(AssertUnwindSafe(move || {
-            vm::initialize_vm(vm, db, module_bundle)
+            vm::initialize_vm(vm, db, module_bundle, stdlib_config)
         }))
         .unwrap_or_else(|_| Err(Error::panic())),
         None => Err(Error::unset_arg(VM_ARG)),
@@ -65,6 +68,107 @@ pub extern "C" fn initialize(
     handle_c_error_default(res, errmsg)
 }
 
+#[no_mangle]
+pub extern "C" fn initialize_with_config(
+    vm_ptr: *mut vm_t,
+    db: Db,
+    api: GoApi,
+    _verbose: bool,
+    errmsg: Option<&mut UnmanagedVector>,
+    genesis_config: ByteSliceView,
+) -> () {
+    let genesis_config = genesis_config.read().unwrap();
+    let res = match to_vm(vm_ptr) {
+        Some(vm) => catch_unwind(AssertUnwindSafe(move || {
+            vm::initialize_vm_with_config(vm, db, api, genesis_config)
+        }))
+        .unwrap_or_else(|_| Err(Error::panic())),
+        None => Err(Error::unset_arg(VM_ARG)),
+    };
+
+    handle_c_error_default(res, errmsg)
+}
+
+/// exported function to configure the vm again with the gas schedule and the
+/// module publishers stored by `initialize_with_config`
+#[no_mangle]
+pub extern "C" fn load_vm_config(
+    vm_ptr: *mut vm_t,
+    db: Db,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> () {
+    let res = match to_vm(vm_ptr) {
+        Some(vm) => catch_unwind(AssertUnwindSafe(move || vm::load_vm_config(vm, db)))
+            .unwrap_or_else(|_| Err(Error::panic())),
+        None => Err(Error::unset_arg(VM_ARG)),
+    };
+
+    handle_c_error_default(res, errmsg)
+}
+
+/// exported function to allow a struct as an argument of entry functions and
+/// scripts; the arguments are built by the `constructor` function of its module
+#[no_mangle]
+pub extern "C" fn register_txn_arg_struct(
+    vm_ptr: *mut vm_t,
+    errmsg: Option<&mut UnmanagedVector>,
+    struct_name: ByteSliceView,
+    constructor: ByteSliceView,
+) -> () {
+    let struct_name = struct_name.read().unwrap();
+    let constructor = constructor.read().unwrap();
+    let res = match to_vm(vm_ptr) {
+        Some(vm) => catch_unwind(AssertUnwindSafe(move || {
+            vm::register_txn_arg_struct(vm, struct_name, constructor)
+        }))
+        .unwrap_or_else(|_| Err(Error::panic())),
+        None => Err(Error::unset_arg(VM_ARG)),
+    };
+
+    handle_c_error_default(res, errmsg)
+}
+
+/// exported function to disallow a struct registered by `register_txn_arg_struct`
+#[no_mangle]
+pub extern "C" fn unregister_txn_arg_struct(
+    vm_ptr: *mut vm_t,
+    errmsg: Option<&mut UnmanagedVector>,
+    struct_name: ByteSliceView,
+) -> () {
+    let struct_name = struct_name.read().unwrap();
+    let res = match to_vm(vm_ptr) {
+        Some(vm) => catch_unwind(AssertUnwindSafe(move || {
+            vm::unregister_txn_arg_struct(vm, struct_name)
+        }))
+        .unwrap_or_else(|_| Err(Error::panic())),
+        None => Err(Error::unset_arg(VM_ARG)),
+    };
+
+    handle_c_error_default(res, errmsg)
+}
+
+/// exported function to limit the number of bytes stored by an account,
+/// including the tables it pays for; zero removes the limit
+#[no_mangle]
+pub extern "C" fn set_storage_quota(
+    vm_ptr: *mut vm_t,
+    errmsg: Option<&mut UnmanagedVector>,
+    storage_quota: u64,
+) -> () {
+    let res = match to_vm(vm_ptr) {
+        Some(vm) => {
+            vm.set_storage_quota(match storage_quota {
+                0 => None,
+                storage_quota => Some(storage_quota),
+            });
+            Ok(())
+        }
+        None => Err(Error::unset_arg(VM_ARG)),
+    };
+
+    handle_c_error_default(res, errmsg)
+}
+
 /// exported function to publish module bundle
 #[no_mangle]
 pub extern "C" fn publish_module_bundle(
@@ -104,17 +208,34 @@ pub extern "C" fn execute_contract(
     errmsg: Option<&mut UnmanagedVector>,
     session_id: ByteSliceView,
     sender: ByteSliceView,
+    fee_payer: ByteSliceView,
     message: ByteSliceView,
 ) -> UnmanagedVector {
     let sid = session_id.read().unwrap();
     let payload = message.read().unwrap();
     let addr = AccountAddress::from_bytes(sender.read().unwrap()).unwrap();
+    let fee_payer = fee_payer
+        .read()
+        .map(AccountAddress::from_bytes)
+        .transpose()
+        .map_err(Error::vm_err);
 
     let res = match to_vm(vm_ptr) {
-        Some(vm) => catch_unwind(AssertUnwindSafe(move || {
-            vm::execute_contract(vm, sid.to_vec(), addr, payload.to_vec(), db, api, gas_limit)
-        }))
-        .unwrap_or_else(|_| Err(Error::panic())),
+        Some(vm) => fee_payer.and_then(|fee_payer| {
+            catch_unwind(AssertUnwindSafe(move || {
+                vm::execute_contract(
+                    vm,
+                    sid.to_vec(),
+                    addr,
+                    fee_payer,
+                    payload.to_vec(),
+                    db,
+                    api,
+                    gas_limit,
+                )
+            }))
+            .unwrap_or_else(|_| Err(Error::panic()))
+        }),
         None => Err(Error::unset_arg(VM_ARG)),
     };
 
@@ -158,17 +279,34 @@ pub extern "C" fn execute_script(
     errmsg: Option<&mut UnmanagedVector>,
     session_id: ByteSliceView,
     sender: ByteSliceView,
+    fee_payer: ByteSliceView,
     message: ByteSliceView,
 ) -> UnmanagedVector {
     let sid = session_id.read().unwrap();
     let payload = message.read().unwrap();
     let addr = AccountAddress::from_bytes(sender.read().unwrap()).unwrap();
+    let fee_payer = fee_payer
+        .read()
+        .map(AccountAddress::from_bytes)
+        .transpose()
+        .map_err(Error::vm_err);
 
     let res = match to_vm(vm_ptr) {
-        Some(vm) => catch_unwind(AssertUnwindSafe(move || {
-            vm::execute_script(vm, sid.to_vec(), addr, payload.to_vec(), db, api, gas_limit)
-        }))
-        .unwrap_or_else(|_| Err(Error::panic())),
+        Some(vm) => fee_payer.and_then(|fee_payer| {
+            catch_unwind(AssertUnwindSafe(move || {
+                vm::execute_script(
+                    vm,
+                    sid.to_vec(),
+                    addr,
+                    fee_payer,
+                    payload.to_vec(),
+                    db,
+                    api,
+                    gas_limit,
+                )
+            }))
+            .unwrap_or_else(|_| Err(Error::panic()))
+        }),
         None => Err(Error::unset_arg(VM_ARG)),
     };
 
@@ -195,6 +333,98 @@ pub extern "C" fn decode_move_resource(
     UnmanagedVector::new(Some(ret))
 }
 
+#[no_mangle]
+pub extern "C" fn decode_table_entry(
+    db: Db,
+    errmsg: Option<&mut UnmanagedVector>,
+    table_handle: ByteSliceView,
+    key_bytes: ByteSliceView,
+    value_bytes: ByteSliceView,
+) -> UnmanagedVector {
+    let table_handle = table_handle.read().unwrap();
+    let key_bytes = key_bytes.read().unwrap();
+    let value_bytes = value_bytes.read();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::decode_table_entry(db, table_handle, key_bytes, value_bytes)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[no_mangle]
+pub extern "C" fn scan_table(
+    db: Db,
+    errmsg: Option<&mut UnmanagedVector>,
+    table_handle: ByteSliceView,
+    start_key: ByteSliceView,
+    limit: u64,
+    order: i32,
+) -> UnmanagedVector {
+    let table_handle = table_handle.read().unwrap();
+    let start_key = start_key.read();
+
+    let res = match Order::try_from(order) {
+        Ok(order) => catch_unwind(AssertUnwindSafe(move || {
+            api_handler::scan_table(db, table_handle, start_key, limit, order)
+        }))
+        .unwrap_or_else(|_| Err(Error::panic())),
+        Err(e) => Err(Error::backend_failure(e)),
+    };
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[no_mangle]
+pub extern "C" fn list_account_data(
+    db: Db,
+    errmsg: Option<&mut UnmanagedVector>,
+    address: ByteSliceView,
+) -> UnmanagedVector {
+    let address = address.read().unwrap();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::list_account_data(db, address)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[no_mangle]
+pub extern "C" fn export_genesis_state(
+    db: Db,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::export_genesis_state(db)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[no_mangle]
+pub extern "C" fn import_genesis_state(
+    db: Db,
+    errmsg: Option<&mut UnmanagedVector>,
+    genesis_state: ByteSliceView,
+) -> () {
+    let genesis_state = genesis_state.read().unwrap();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::import_genesis_state(db, genesis_state)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    handle_c_error_default(res, errmsg)
+}
+
 #[no_mangle]
 pub extern "C" fn decode_module_bytes(
     errmsg: Option<&mut UnmanagedVector>,
@@ -24,6 +24,12 @@ pub struct Iterator_vtable {
             *mut UnmanagedVector, // error message output
         ) -> i32,
     >,
+    pub close_db: Option<
+        extern "C" fn(
+            iterator_t,
+            *mut UnmanagedVector, // error message output
+        ) -> i32,
+    >,
 }
 
 #[repr(C)]
@@ -73,4 +79,26 @@ impl GoIter {
             None => Ok(None),
         }
     }
+
+    /// Close the iterator on the Go side to release the database resources.
+    pub fn close(&self) -> anyhow::Result<()> {
+        let close_db = match self.vtable.close_db {
+            Some(f) => f,
+            None => return Err(anyhow!("iterator vtable not set")),
+        };
+
+        let mut error_msg = UnmanagedVector::default();
+        let go_result: GoError =
+            (close_db)(self.state, &mut error_msg as *mut UnmanagedVector).into();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || "Failed to close iterator".to_string();
+        unsafe {
+            if let Err(err) = go_result.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
-use crate::move_api::move_types::MoveResource;
+use crate::move_api::move_types::{MoveResource, MoveTableEntry, MoveValue};
 
 use anyhow::Result;
-use move_deps::move_core_types::{language_storage::StructTag, resolver::MoveResolver};
+use move_deps::move_core_types::{
+    language_storage::{StructTag, TypeTag},
+    resolver::MoveResolver,
+};
 use move_deps::move_resource_viewer::MoveValueAnnotator;
 
 use std::str::FromStr;
@@ -23,6 +26,33 @@ impl<'a, R: MoveResolver + ?Sized> MoveConverter<'a, R> {
 
     pub fn try_into_resource<'b>(&self, struct_tag: &str, bytes: &'b [u8]) -> Result<MoveResource> {
         let struct_tag = StructTag::from_str(struct_tag)?;
-        self.inner.view_resource(&struct_tag, bytes)?.try_into()
+        self.try_into_resource_with_tag(&struct_tag, bytes)
+    }
+
+    pub fn try_into_resource_with_tag(
+        &self,
+        struct_tag: &StructTag,
+        bytes: &[u8],
+    ) -> Result<MoveResource> {
+        self.inner.view_resource(struct_tag, bytes)?.try_into()
+    }
+
+    pub fn try_into_move_value(&self, type_tag: &TypeTag, bytes: &[u8]) -> Result<MoveValue> {
+        self.inner.view_value(type_tag, bytes)?.try_into()
+    }
+
+    pub fn try_into_table_entry(
+        &self,
+        key_type: &TypeTag,
+        value_type: &TypeTag,
+        key_bytes: &[u8],
+        value_bytes: &[u8],
+    ) -> Result<MoveTableEntry> {
+        Ok(MoveTableEntry {
+            key_type: key_type.into(),
+            value_type: value_type.into(),
+            key: self.try_into_move_value(key_type, key_bytes)?,
+            value: self.try_into_move_value(value_type, value_bytes)?,
+        })
     }
 }
@@ -0,0 +1,152 @@
+use crate::move_api::{
+    address::Address,
+    convert::MoveConverter,
+    move_types::{HexEncodedBytes, MoveResource},
+    wrappers::IdentifierWrapper,
+};
+
+use anyhow::Result;
+use move_deps::move_core_types::effects::Op;
+use nova_storage::state_view::StateView;
+use nova_storage::state_view_impl::StateViewImpl;
+use nova_types::access_path::{AccessPath, DataPath};
+use nova_types::storage_usage::StorageUsage;
+use nova_types::table_meta::TableMeta;
+use nova_types::write_set::WriteSet;
+use serde::{Deserialize, Serialize};
+
+/// A JSON snapshot of the whole state, used to migrate the state into a new chain
+/// or to build test fixtures.
+///
+/// Resources keep their BCS bytes next to the decoded value, because the importer
+/// cannot recover the bytes from the JSON value without the module layouts.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GenesisState {
+    pub modules: Vec<GenesisModule>,
+    pub resources: Vec<GenesisResource>,
+    pub table_items: Vec<GenesisTableItem>,
+    pub table_metas: Vec<GenesisTableMeta>,
+    pub storage_usages: Vec<GenesisStorageUsage>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GenesisModule {
+    pub address: Address,
+    pub name: IdentifierWrapper,
+    pub code: HexEncodedBytes,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GenesisResource {
+    pub address: Address,
+    pub resource: MoveResource,
+    pub bytes: HexEncodedBytes,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GenesisTableItem {
+    pub handle: Address,
+    pub key: HexEncodedBytes,
+    pub value: HexEncodedBytes,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisTableMeta {
+    pub handle: Address,
+    pub meta: TableMeta,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisStorageUsage {
+    pub address: Address,
+    pub usage: StorageUsage,
+}
+
+impl GenesisState {
+    /// Walks every access path of the state and decodes the stored data.
+    pub fn export<S: StateView>(state_view: &S) -> Result<Self> {
+        let state_view_impl = StateViewImpl::new(state_view);
+        let converter = MoveConverter::new(&state_view_impl);
+
+        let mut genesis_state = GenesisState::default();
+
+        // some stores do not allow to iterate with an empty prefix,
+        // so walk the state by the first byte of the keys
+        for first_byte in 0..=u8::MAX {
+            for (access_path, bytes) in state_view.get_prefix(&[first_byte])? {
+                let address = Address::from(access_path.address);
+                match access_path.path {
+                    DataPath::Code(name) => genesis_state.modules.push(GenesisModule {
+                        address,
+                        name: IdentifierWrapper(name),
+                        code: HexEncodedBytes(bytes),
+                    }),
+                    DataPath::Resource(struct_tag) => {
+                        genesis_state.resources.push(GenesisResource {
+                            address,
+                            resource: converter.try_into_resource_with_tag(&struct_tag, &bytes)?,
+                            bytes: HexEncodedBytes(bytes),
+                        })
+                    }
+                    DataPath::TableItem(key) => genesis_state.table_items.push(GenesisTableItem {
+                        handle: address,
+                        key: HexEncodedBytes(key),
+                        value: HexEncodedBytes(bytes),
+                    }),
+                    DataPath::TableMeta => genesis_state.table_metas.push(GenesisTableMeta {
+                        handle: address,
+                        meta: TableMeta::deserialize(&bytes)?,
+                    }),
+                    DataPath::StorageUsage => {
+                        genesis_state.storage_usages.push(GenesisStorageUsage {
+                            address,
+                            usage: StorageUsage::deserialize(&bytes)?,
+                        })
+                    }
+                }
+            }
+        }
+
+        Ok(genesis_state)
+    }
+
+    /// Builds the write set which replays the snapshot into a fresh store.
+    pub fn into_write_set(self) -> Result<WriteSet> {
+        let mut writes: Vec<(AccessPath, Op<Vec<u8>>)> = vec![];
+        for module in self.modules {
+            writes.push((
+                AccessPath::code_access_path(module.address.into(), module.name.0),
+                Op::New(module.code.0),
+            ));
+        }
+        for resource in self.resources {
+            writes.push((
+                AccessPath::resource_access_path(
+                    resource.address.into(),
+                    resource.resource.typ.try_into()?,
+                ),
+                Op::New(resource.bytes.0),
+            ));
+        }
+        for item in self.table_items {
+            writes.push((
+                AccessPath::table_item_access_path(item.handle.into(), item.key.0),
+                Op::New(item.value.0),
+            ));
+        }
+        for table_meta in self.table_metas {
+            writes.push((
+                AccessPath::table_meta_access_path(table_meta.handle.into()),
+                Op::New(table_meta.meta.serialize()?),
+            ));
+        }
+        for storage_usage in self.storage_usages {
+            writes.push((
+                AccessPath::storage_usage_access_path(storage_usage.address.into()),
+                Op::New(storage_usage.usage.serialize()?),
+            ));
+        }
+
+        Ok(writes.into_iter().collect())
+    }
+}
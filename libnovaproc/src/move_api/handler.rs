@@ -1,8 +1,28 @@
 use crate::move_api::convert::MoveConverter;
-use crate::move_api::move_types::{MoveModuleBytecode, MoveScriptBytecode};
+use crate::move_api::genesis::GenesisState;
+use crate::move_api::move_types::{
+    HexEncodedBytes, MoveAccountData, MoveModuleBytecode, MoveResource, MoveScriptBytecode,
+    MoveTableEntries, MoveTableEntry,
+};
+use crate::move_api::wrappers::IdentifierWrapper;
+use crate::table_storage::GoTableStorage;
+use crate::vm::push_write_set;
 use crate::{error::Error, Db, GoStorage};
 
+use anyhow::anyhow;
+use move_deps::move_core_types::{account_address::AccountAddress, language_storage::TypeTag};
+use nova_storage::state_view::StateView;
 use nova_storage::state_view_impl::StateViewImpl;
+use nova_storage::table_meta::table_meta_resolver::TableMetaResolver;
+use nova_storage::table_view::TableView;
+use nova_types::access_path::{AccessPath, DataPath, DataType};
+use nova_types::iterator::Order;
+use nova_types::ordered_key::{decode_ordered_key, ordered_key_layout};
+use nova_types::table::{KeyEncoding, TableHandle};
+use nova_types::table_meta::TableMeta;
+
+/// The maximum number of entries returned by a page of `scan_table`.
+pub const MAX_SCAN_TABLE_LIMIT: u64 = 100;
 
 pub(crate) fn decode_move_resource(
     db_handle: Db,
@@ -21,6 +41,263 @@ pub(crate) fn decode_move_resource(
     serde_json::to_vec(&resource).map_err(|e| Error::BackendFailure { msg: e.to_string() })
 }
 
+pub(crate) fn decode_table_entry(
+    db_handle: Db,
+    table_handle: &[u8],
+    key_bytes: &[u8],
+    value_bytes: Option<&[u8]>,
+) -> Result<Vec<u8>, Error> {
+    let storage = GoStorage::new(&db_handle);
+    let table_storage = GoTableStorage::new(&db_handle);
+    let entry = load_table_entry(
+        &storage,
+        &table_storage,
+        &to_table_handle(table_handle)?,
+        key_bytes,
+        value_bytes,
+    )?;
+
+    // serialize response as json
+    serde_json::to_vec(&entry).map_err(Error::backend_failure)
+}
+
+/// Decode an entry of the table with the key and value types stored in its meta.
+/// The value is loaded from `table_view` if it is not provided.
+pub(crate) fn load_table_entry<S: StateView, T: TableView>(
+    state_view: &S,
+    table_view: &T,
+    handle: &TableHandle,
+    key_bytes: &[u8],
+    value_bytes: Option<&[u8]>,
+) -> Result<MoveTableEntry, Error> {
+    let state_view_impl = StateViewImpl::new(state_view);
+    let table_meta = load_table_meta(&state_view_impl, handle)?;
+    let (key_type, value_type) = table_types(&table_meta, handle)?;
+
+    let value_bytes = match value_bytes {
+        Some(value_bytes) => value_bytes.to_vec(),
+        None => resolve_table_entry(table_view, handle, key_bytes)?,
+    };
+
+    let converter = MoveConverter::new(&state_view_impl);
+    converter
+        .try_into_table_entry(
+            key_type,
+            value_type,
+            &to_bcs_key(&table_meta, key_type, key_bytes)?,
+            &value_bytes,
+        )
+        .map_err(Error::backend_failure)
+}
+
+pub(crate) fn scan_table(
+    db_handle: Db,
+    table_handle: &[u8],
+    start_key: Option<&[u8]>,
+    limit: u64,
+    order: Order,
+) -> Result<Vec<u8>, Error> {
+    let storage = GoStorage::new(&db_handle);
+    let mut table_storage = GoTableStorage::new(&db_handle);
+    let entries = load_table_entries(
+        &storage,
+        &mut table_storage,
+        &to_table_handle(table_handle)?,
+        start_key,
+        limit,
+        order,
+    )?;
+
+    // serialize response as json
+    serde_json::to_vec(&entries).map_err(Error::backend_failure)
+}
+
+/// Decode a page of at most `limit` entries of the table, capped to
+/// `MAX_SCAN_TABLE_LIMIT`, starting from the stored key `start_key` included.
+pub(crate) fn load_table_entries<S: StateView, T: TableView>(
+    state_view: &S,
+    table_view: &mut T,
+    handle: &TableHandle,
+    start_key: Option<&[u8]>,
+    limit: u64,
+    order: Order,
+) -> Result<MoveTableEntries, Error> {
+    if limit == 0 {
+        return Err(Error::backend_failure("limit must be greater than zero"));
+    }
+    let limit = limit.min(MAX_SCAN_TABLE_LIMIT);
+
+    let state_view_impl = StateViewImpl::new(state_view);
+    let table_meta = load_table_meta(&state_view_impl, handle)?;
+    let (key_type, value_type) = table_types(&table_meta, handle)?;
+
+    // the start key is the upper bound of a descending page, which is
+    // exclusive, so bound it by the key right after the start key
+    let upper_bound = start_key.map(|key| [key, &[0u8]].concat());
+    let (start, end) = match order {
+        Order::Ascending => (start_key, None),
+        Order::Descending => (None, upper_bound.as_deref()),
+    };
+
+    // load one more key than the limit to find the cursor of the next page
+    let iterator_id = table_view
+        .create_iterator(handle, start, end, order)
+        .map_err(Error::backend_failure)?;
+    let mut keys: Vec<Vec<u8>> = vec![];
+    while (keys.len() as u64) <= limit {
+        match table_view
+            .next_key(iterator_id)
+            .map_err(Error::backend_failure)?
+        {
+            Some(key) => keys.push(key),
+            None => break,
+        }
+    }
+    table_view
+        .destroy_iterator(iterator_id)
+        .map_err(Error::backend_failure)?;
+
+    let next_key = if keys.len() as u64 > limit {
+        keys.pop().map(HexEncodedBytes::from)
+    } else {
+        None
+    };
+
+    let converter = MoveConverter::new(&state_view_impl);
+    let entries = keys
+        .iter()
+        .map(|key| {
+            let value_bytes = resolve_table_entry(table_view, handle, key)?;
+            converter
+                .try_into_table_entry(
+                    key_type,
+                    value_type,
+                    &to_bcs_key(&table_meta, key_type, key)?,
+                    &value_bytes,
+                )
+                .map_err(Error::backend_failure)
+        })
+        .collect::<Result<Vec<MoveTableEntry>, Error>>()?;
+
+    Ok(MoveTableEntries { entries, next_key })
+}
+
+pub(crate) fn list_account_data(db_handle: Db, address: &[u8]) -> Result<Vec<u8>, Error> {
+    let address = AccountAddress::from_bytes(address).map_err(Error::backend_failure)?;
+    let storage = GoStorage::new(&db_handle);
+
+    let modules = storage
+        .get_prefix(&AccessPath::data_type_prefix(address, DataType::CODE))
+        .map_err(Error::backend_failure)?
+        .into_iter()
+        .map(|(access_path, _)| match access_path.path {
+            DataPath::Code(module_name) => Ok(IdentifierWrapper(module_name)),
+            path => Err(Error::backend_failure(format!(
+                "unexpected data path {} in the module prefix",
+                path
+            ))),
+        })
+        .collect::<Result<Vec<IdentifierWrapper>, Error>>()?;
+
+    let state_view_impl = StateViewImpl::new(&storage);
+    let converter = MoveConverter::new(&state_view_impl);
+    let resources = storage
+        .get_prefix(&AccessPath::data_type_prefix(address, DataType::RESOURCE))
+        .map_err(Error::backend_failure)?
+        .into_iter()
+        .map(|(access_path, bytes)| match access_path.path {
+            DataPath::Resource(struct_tag) => converter
+                .try_into_resource_with_tag(&struct_tag, &bytes)
+                .map_err(Error::backend_failure),
+            path => Err(Error::backend_failure(format!(
+                "unexpected data path {} in the resource prefix",
+                path
+            ))),
+        })
+        .collect::<Result<Vec<MoveResource>, Error>>()?;
+
+    // serialize response as json
+    serde_json::to_vec(&MoveAccountData { modules, resources }).map_err(Error::backend_failure)
+}
+
+pub(crate) fn export_genesis_state(db_handle: Db) -> Result<Vec<u8>, Error> {
+    let storage = GoStorage::new(&db_handle);
+    let genesis_state = GenesisState::export(&storage).map_err(Error::backend_failure)?;
+
+    // serialize response as json
+    serde_json::to_vec(&genesis_state).map_err(Error::backend_failure)
+}
+
+pub(crate) fn import_genesis_state(db_handle: Db, genesis_state: &[u8]) -> Result<(), Error> {
+    let genesis_state: GenesisState =
+        serde_json::from_slice(genesis_state).map_err(Error::backend_failure)?;
+    let write_set = genesis_state
+        .into_write_set()
+        .map_err(Error::backend_failure)?;
+
+    let mut storage = GoStorage::new(&db_handle);
+    push_write_set(&mut storage, &write_set)?;
+
+    Ok(())
+}
+
+fn to_table_handle(table_handle: &[u8]) -> Result<TableHandle, Error> {
+    AccountAddress::from_bytes(table_handle)
+        .map(TableHandle)
+        .map_err(Error::backend_failure)
+}
+
+fn load_table_meta<S: StateView>(
+    state_view_impl: &StateViewImpl<S>,
+    handle: &TableHandle,
+) -> Result<TableMeta, Error> {
+    state_view_impl
+        .get_table_meta(handle)
+        .map_err(Error::backend_failure)?
+        .ok_or_else(|| Error::backend_failure(format!("table meta not found for {}", handle.0)))
+}
+
+fn resolve_table_entry<T: TableView>(
+    table_view: &T,
+    handle: &TableHandle,
+    key_bytes: &[u8],
+) -> Result<Vec<u8>, Error> {
+    table_view
+        .resolve_table_entry(handle, key_bytes)
+        .map_err(Error::backend_failure)?
+        .ok_or_else(|| Error::backend_failure(format!("table entry not found in {}", handle.0)))
+}
+
+/// Return the key and value types of the table, unknown for the tables created
+/// before they were stored in the meta.
+fn table_types<'a>(
+    table_meta: &'a TableMeta,
+    handle: &TableHandle,
+) -> Result<(&'a TypeTag, &'a TypeTag), Error> {
+    match (&table_meta.key_type, &table_meta.value_type) {
+        (Some(key_type), Some(value_type)) => Ok((key_type, value_type)),
+        _ => Err(Error::backend_failure(format!(
+            "types of table {} are unknown",
+            handle.0
+        ))),
+    }
+}
+
+/// Convert a stored table key back into BCS to decode it with the key type.
+fn to_bcs_key(
+    table_meta: &TableMeta,
+    key_type: &TypeTag,
+    key_bytes: &[u8],
+) -> Result<Vec<u8>, Error> {
+    match table_meta.key_encoding {
+        KeyEncoding::Bcs => Ok(key_bytes.to_vec()),
+        KeyEncoding::Ordered => ordered_key_layout(key_type)
+            .ok_or_else(|| anyhow!("unsupported ordered key type {}", key_type))
+            .and_then(|layout| decode_ordered_key(&layout, key_bytes))
+            .map_err(Error::backend_failure),
+    }
+}
+
 pub(crate) fn decode_script_bytes(script_bytes: Vec<u8>) -> Result<Vec<u8>, Error> {
     let script: MoveScriptBytecode = MoveScriptBytecode::new(script_bytes);
     let abi = script
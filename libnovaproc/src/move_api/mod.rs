@@ -3,6 +3,7 @@ pub mod handler;
 mod address;
 mod bytecode;
 mod convert;
+pub(crate) mod genesis;
 pub(crate) mod move_types;
 mod wrappers;
 
@@ -55,6 +55,30 @@ impl TryFrom<AnnotatedMoveStruct> for MoveResource {
     }
 }
 
+/// A parsed Move table entry
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MoveTableEntry {
+    pub key_type: MoveType,
+    pub value_type: MoveType,
+    pub key: MoveValue,
+    pub value: MoveValue,
+}
+
+/// A page of parsed Move table entries
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MoveTableEntries {
+    pub entries: Vec<MoveTableEntry>,
+    /// The stored key of the first entry of the next page, if there is any
+    pub next_key: Option<HexEncodedBytes>,
+}
+
+/// The parsed Move resources and the names of the modules stored under an account
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MoveAccountData {
+    pub modules: Vec<IdentifierWrapper>,
+    pub resources: Vec<MoveResource>,
+}
+
 /// A string encoded U64
 ///
 /// Encoded as a string to encode into JSON
@@ -1,9 +1,10 @@
 use crate::error::Error;
 use crate::event::ContractEvent;
-use crate::size_delta::SizeDelta;
+use crate::size_delta::{SizeDelta, StorageRefund};
 
 use nova_types::message::MessageOutput;
 
+use move_deps::move_core_types::account_address::AccountAddress;
 use move_deps::move_core_types::vm_status::VMStatus;
 use move_deps::move_vm_runtime::session::SerializedReturnValues;
 
@@ -15,6 +16,9 @@ pub struct ExecutionResult {
     events: Vec<ContractEvent>,
     size_deltas: Vec<SizeDelta>,
     gas_used: u64,
+    storage_fee: u64,
+    storage_refunds: Vec<StorageRefund>,
+    fee_payer: Option<AccountAddress>,
 }
 
 pub fn to_vec<T>(data: &T) -> Result<Vec<u8>, Error>
@@ -29,12 +33,15 @@ impl Serialize for ExecutionResult {
     where
         S: Serializer,
     {
-        // 3 is the number of fields in the struct.
-        let mut state = serializer.serialize_struct("ExecutionResult", 3)?;
+        // 7 is the number of fields in the struct.
+        let mut state = serializer.serialize_struct("ExecutionResult", 7)?;
         state.serialize_field("result", &self.result)?;
         state.serialize_field("events", &self.events)?;
         state.serialize_field("size_deltas", &self.size_deltas)?;
         state.serialize_field("gas_used", &self.gas_used)?;
+        state.serialize_field("storage_fee", &self.storage_fee)?;
+        state.serialize_field("storage_refunds", &self.storage_refunds)?;
+        state.serialize_field("fee_payer", &self.fee_payer)?;
         state.end()
     }
 }
@@ -84,10 +91,19 @@ pub fn generate_result(
         ));
     }
 
+    let storage_refunds = output
+        .storage_refunds()
+        .iter()
+        .map(|(account_addr, amount)| StorageRefund::new(*account_addr, *amount))
+        .collect();
+
     Ok(ExecutionResult {
         result,
         events,
         size_deltas,
         gas_used: output.gas_used(),
+        storage_fee: output.storage_fee(),
+        storage_refunds,
+        fee_payer: output.fee_payer(),
     })
 }
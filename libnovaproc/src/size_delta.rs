@@ -36,3 +36,17 @@ impl std::fmt::Display for SizeDelta {
         write!(f, "{:?}", self)
     }
 }
+
+#[derive(Serialize, Deserialize)]
+pub struct StorageRefund {
+    /// The account address refunded
+    address: AccountAddress,
+    /// The refunded storage deposit in gas units
+    amount: u64,
+}
+
+impl StorageRefund {
+    pub fn new(address: AccountAddress, amount: u64) -> Self {
+        Self { address, amount }
+    }
+}
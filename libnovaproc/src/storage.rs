@@ -1,9 +1,11 @@
 use nova_storage::state_view::StateView;
 use nova_types::access_path::AccessPath;
+use nova_types::iterator::Order;
 use novavm::backend::BackendResult;
 
 use crate::db::Db;
 use crate::error::GoError;
+use crate::iterator::GoIter;
 use crate::memory::{U8SliceView, UnmanagedVector};
 
 use anyhow::anyhow;
@@ -62,6 +64,58 @@ impl<'r> StateView for GoStorage<'r> {
 
         anyhow::Result::Ok(output)
     }
+
+    fn get_prefix(&self, prefix: &[u8]) -> anyhow::Result<Vec<(AccessPath, Vec<u8>)>> {
+        let mut error_msg = UnmanagedVector::default();
+
+        // keep the prefix in the iterated keys to recover the access paths
+        let mut iter = GoIter::new(0);
+        let go_error: GoError = (self.db.vtable.scan_db)(
+            self.db.state,
+            U8SliceView::new(Some(prefix)),
+            U8SliceView::new(None),
+            U8SliceView::new(None),
+            Order::Ascending.into(),
+            &mut iter as *mut GoIter,
+            &mut error_msg as *mut UnmanagedVector,
+        )
+        .into();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || {
+            format!(
+                "Failed to scan the db with prefix: {}",
+                String::from_utf8_lossy(prefix)
+            )
+        };
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        let mut keys = vec![];
+        let res = loop {
+            match iter.next_key() {
+                Ok(Some(key)) => keys.push(key),
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            }
+        };
+
+        // release the iterator before reading the values, even if the iteration failed
+        iter.close()?;
+        res?;
+
+        keys.into_iter()
+            .map(|key| {
+                let value = Storage::get(self, &key)
+                    .map_err(|e| anyhow!(e))?
+                    .ok_or_else(|| anyhow!("iterated key has no value in the db"))?;
+                Ok((AccessPath::from_bytes(&key)?, value))
+            })
+            .collect()
+    }
 }
 
 impl<'r> Storage for GoStorage<'r> {
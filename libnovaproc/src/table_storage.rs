@@ -3,7 +3,8 @@ use std::collections::HashMap;
 use nova_storage::table_view::TableView;
 use nova_types::access_path::AccessPath;
 use nova_types::iterator::Order;
-use nova_types::table::TableHandle;
+use nova_types::table::{KeyEncoding, TableHandle};
+use nova_types::table_meta::TableMeta;
 
 use crate::db::Db;
 use crate::error::GoError;
@@ -15,6 +16,7 @@ use anyhow::anyhow;
 pub struct GoTableStorage<'r> {
     db: &'r Db,
     iterators: HashMap<u32, GoIter>,
+    next_iterator_id: u32,
 }
 
 impl<'r> GoTableStorage<'r> {
@@ -22,17 +24,13 @@ impl<'r> GoTableStorage<'r> {
         GoTableStorage {
             db,
             iterators: HashMap::new(),
+            next_iterator_id: 0,
         }
     }
 }
 
-impl<'r> TableView for GoTableStorage<'r> {
-    fn resolve_table_entry(
-        &self,
-        handle: &TableHandle,
-        key: &[u8],
-    ) -> anyhow::Result<Option<Vec<u8>>> {
-        let access_path = AccessPath::table_item_access_path(handle.0, key.to_vec());
+impl<'r> GoTableStorage<'r> {
+    fn read(&self, access_path: &AccessPath) -> anyhow::Result<Option<Vec<u8>>> {
         let key = access_path.to_bytes()?;
         let mut output = UnmanagedVector::default();
         let mut error_msg = UnmanagedVector::default();
@@ -56,6 +54,25 @@ impl<'r> TableView for GoTableStorage<'r> {
 
         anyhow::Result::Ok(output)
     }
+}
+
+impl<'r> TableView for GoTableStorage<'r> {
+    fn resolve_table_entry(
+        &self,
+        handle: &TableHandle,
+        key: &[u8],
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        self.read(&AccessPath::table_item_access_path(handle.0, key.to_vec()))
+    }
+
+    fn resolve_key_encoding(&self, handle: &TableHandle) -> anyhow::Result<KeyEncoding> {
+        Ok(
+            match self.read(&AccessPath::table_meta_access_path(handle.0))? {
+                Some(bytes) => TableMeta::deserialize(&bytes)?.key_encoding,
+                None => KeyEncoding::Bcs,
+            },
+        )
+    }
 
     fn create_iterator(
         &mut self,
@@ -94,10 +111,9 @@ impl<'r> TableView for GoTableStorage<'r> {
             }
         }
 
-        let next_id: u32 = self
-            .iterators
-            .len()
-            .try_into()
+        let next_id = self.next_iterator_id;
+        self.next_iterator_id = next_id
+            .checked_add(1)
             .expect("Iterator count exceeded uint32 range. This is a bug.");
         self.iterators.insert(next_id, iter);
 
@@ -111,4 +127,11 @@ impl<'r> TableView for GoTableStorage<'r> {
         };
         iterator.next_key()
     }
+
+    fn destroy_iterator(&mut self, iterator_id: u32) -> anyhow::Result<()> {
+        match self.iterators.remove(&iterator_id) {
+            Some(iterator) => iterator.close(),
+            None => Err(anyhow!("Iterator does not exist")),
+        }
+    }
 }
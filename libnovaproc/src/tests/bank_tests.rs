@@ -0,0 +1,62 @@
+use crate::vm::apply_bank_change_set;
+
+use move_deps::move_core_types::account_address::AccountAddress;
+use nova_natives::bank::BankBridge;
+use nova_natives::mocks::MockApi;
+use nova_types::bank_change_set::{BankChangeSet, BankOp};
+
+const DENOM: &str = "uinit";
+
+fn accounts() -> (AccountAddress, AccountAddress) {
+    (
+        AccountAddress::from_hex_literal("0x2").unwrap(),
+        AccountAddress::from_hex_literal("0x3").unwrap(),
+    )
+}
+
+fn withdraw(address: AccountAddress, amount: u64) -> BankOp {
+    BankOp::Withdraw {
+        denom: DENOM.to_string(),
+        address,
+        amount,
+    }
+}
+
+fn deposit(address: AccountAddress, amount: u64) -> BankOp {
+    BankOp::Deposit {
+        denom: DENOM.to_string(),
+        address,
+        amount,
+    }
+}
+
+#[test]
+fn apply_bank_change_set_works() {
+    let (one, two) = accounts();
+    let api = MockApi::default();
+    api.bank.mint(DENOM, &one, 100);
+
+    let change_set = BankChangeSet::new(vec![withdraw(one, 70), deposit(two, 50)]);
+    apply_bank_change_set(&api, &change_set).unwrap();
+
+    assert_eq!(api.bank_balance(DENOM, &one).unwrap(), 30);
+    assert_eq!(api.bank_balance(DENOM, &two).unwrap(), 50);
+}
+
+#[test]
+fn apply_bank_change_set_is_all_or_nothing() {
+    let (one, two) = accounts();
+    let api = MockApi::default();
+    api.bank.mint(DENOM, &one, 100);
+
+    // the second withdrawal overdraws the balance left by the first one
+    let change_set = BankChangeSet::new(vec![withdraw(one, 70), withdraw(one, 40)]);
+    assert!(apply_bank_change_set(&api, &change_set).is_err());
+    assert_eq!(api.bank_balance(DENOM, &one).unwrap(), 100);
+
+    // the deposit releases more than the supply
+    let change_set = BankChangeSet::new(vec![withdraw(one, 10), deposit(two, 111)]);
+    assert!(apply_bank_change_set(&api, &change_set).is_err());
+    assert_eq!(api.bank_balance(DENOM, &one).unwrap(), 100);
+    assert_eq!(api.bank_balance(DENOM, &two).unwrap(), 0);
+}
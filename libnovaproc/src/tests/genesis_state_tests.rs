@@ -0,0 +1,99 @@
+use crate::move_api::genesis::GenesisState;
+use crate::tests::map_state::MapState;
+
+use move_deps::move_core_types::{
+    account_address::AccountAddress,
+    effects::Op,
+    identifier::Identifier,
+    language_storage::{StructTag, TypeTag},
+};
+use nova_types::access_path::AccessPath;
+use nova_types::storage_usage::StorageUsage;
+use nova_types::table::KeyEncoding;
+use nova_types::table_meta::TableMeta;
+
+use std::collections::BTreeMap;
+
+#[test]
+fn genesis_state_round_trip_works() {
+    let handle = AccountAddress::from_hex_literal("0x123").unwrap();
+    let table_meta = TableMeta {
+        payer: AccountAddress::ONE,
+        size: 10,
+        key_type: Some(TypeTag::U64),
+        value_type: Some(TypeTag::Vector(Box::new(TypeTag::U8))),
+        key_encoding: KeyEncoding::Ordered,
+        length: Some(1),
+    };
+
+    // the resources are decoded with the modules of the state
+    let module_name = Identifier::new("BasicCoin").unwrap();
+    let coin = StructTag {
+        address: AccountAddress::ONE,
+        module: module_name.clone(),
+        name: Identifier::new("Coin").unwrap(),
+        type_params: vec![TypeTag::Struct(StructTag {
+            address: AccountAddress::ONE,
+            module: module_name.clone(),
+            name: Identifier::new("Nova").unwrap(),
+            type_params: vec![],
+        })],
+    };
+
+    let mut map = BTreeMap::new();
+    for (access_path, value) in [
+        (
+            AccessPath::code_access_path(AccountAddress::ONE, module_name),
+            include_bytes!("../../../crates/move-test/build/test1/bytecode_modules/BasicCoin.mv")
+                .to_vec(),
+        ),
+        (
+            AccessPath::resource_access_path(AccountAddress::ONE, coin),
+            bcs::to_bytes(&(100u64, true)).unwrap(),
+        ),
+        (
+            AccessPath::table_item_access_path(handle, vec![0, 0, 0, 0, 0, 0, 0, 1]),
+            vec![2, 3, 4],
+        ),
+        (
+            AccessPath::table_meta_access_path(handle),
+            table_meta.serialize().unwrap(),
+        ),
+        (
+            AccessPath::storage_usage_access_path(AccountAddress::ONE),
+            StorageUsage {
+                size: 10,
+                deposit: 100,
+            }
+            .serialize()
+            .unwrap(),
+        ),
+    ] {
+        map.insert(access_path.to_bytes().unwrap(), value);
+    }
+    let state = MapState::new(map.clone());
+
+    let json = serde_json::to_vec(&GenesisState::export(&state).unwrap()).unwrap();
+    let genesis_state: GenesisState = serde_json::from_slice(&json).unwrap();
+    assert_eq!(genesis_state.modules.len(), 1);
+    assert_eq!(genesis_state.resources.len(), 1);
+    assert_eq!(
+        serde_json::to_value(&genesis_state.resources[0].resource).unwrap(),
+        serde_json::json!({
+            "type": "0x1::BasicCoin::Coin<0x1::BasicCoin::Nova>",
+            "data": { "value": "100", "test": true },
+        })
+    );
+    assert_eq!(genesis_state.table_items.len(), 1);
+    assert_eq!(genesis_state.table_metas.len(), 1);
+    assert_eq!(genesis_state.storage_usages.len(), 1);
+
+    let mut imported = BTreeMap::new();
+    for (access_path, op) in genesis_state.into_write_set().unwrap() {
+        match op {
+            Op::New(value) => imported.insert(access_path.to_bytes().unwrap(), value),
+            _ => panic!("genesis state must only create entries"),
+        };
+    }
+    assert_eq!(imported, map);
+}
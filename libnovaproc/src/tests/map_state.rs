@@ -0,0 +1,100 @@
+use anyhow::anyhow;
+use nova_storage::state_view::StateView;
+use nova_storage::table_view::TableView;
+use nova_types::access_path::AccessPath;
+use nova_types::iterator::Order;
+use nova_types::table::{KeyEncoding, TableHandle};
+use nova_types::table_meta::TableMeta;
+
+use std::collections::BTreeMap;
+
+/// An in-memory state keyed by the encoded access paths, standing in for the
+/// Go storage in the query tests.
+pub struct MapState {
+    map: BTreeMap<Vec<u8>, Vec<u8>>,
+    iterators: Vec<Vec<Vec<u8>>>,
+}
+
+impl MapState {
+    pub fn new(map: BTreeMap<Vec<u8>, Vec<u8>>) -> Self {
+        Self {
+            map,
+            iterators: vec![],
+        }
+    }
+}
+
+impl StateView for MapState {
+    fn get(&self, access_path: &AccessPath) -> anyhow::Result<Option<Vec<u8>>> {
+        Ok(self.map.get(&access_path.to_bytes()?).cloned())
+    }
+
+    fn get_prefix(&self, prefix: &[u8]) -> anyhow::Result<Vec<(AccessPath, Vec<u8>)>> {
+        self.map
+            .range(prefix.to_vec()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((AccessPath::from_bytes(key)?, value.clone())))
+            .collect()
+    }
+}
+
+impl TableView for MapState {
+    fn resolve_table_entry(
+        &self,
+        handle: &TableHandle,
+        key: &[u8],
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        self.get(&AccessPath::table_item_access_path(handle.0, key.to_vec()))
+    }
+
+    fn resolve_key_encoding(&self, handle: &TableHandle) -> anyhow::Result<KeyEncoding> {
+        Ok(
+            match self.get(&AccessPath::table_meta_access_path(handle.0))? {
+                Some(bytes) => TableMeta::deserialize(&bytes)?.key_encoding,
+                None => KeyEncoding::Bcs,
+            },
+        )
+    }
+
+    fn create_iterator(
+        &mut self,
+        handle: &TableHandle,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> anyhow::Result<u32> {
+        let prefix = AccessPath::table_item_access_path(handle.0, vec![]).to_bytes()?;
+        let mut keys: Vec<Vec<u8>> = self
+            .map
+            .range(prefix.clone()..)
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, _)| key[prefix.len()..].to_vec())
+            .filter(|key| start.map_or(true, |start| key.as_slice() >= start))
+            .filter(|key| end.map_or(true, |end| key.as_slice() < end))
+            .collect();
+        if let Order::Descending = order {
+            keys.reverse();
+        }
+
+        self.iterators.push(keys);
+        Ok(self.iterators.len() as u32 - 1)
+    }
+
+    fn next_key(&mut self, iterator_id: u32) -> anyhow::Result<Option<Vec<u8>>> {
+        match self.iterators.get_mut(iterator_id as usize) {
+            Some(keys) if keys.is_empty() => Ok(None),
+            Some(keys) => Ok(Some(keys.remove(0))),
+            None => Err(anyhow!("iterator not found")),
+        }
+    }
+
+    fn destroy_iterator(&mut self, iterator_id: u32) -> anyhow::Result<()> {
+        match self.iterators.get_mut(iterator_id as usize) {
+            Some(keys) => {
+                keys.clear();
+                Ok(())
+            }
+            None => Err(anyhow!("iterator not found")),
+        }
+    }
+}
@@ -1,3 +1,7 @@
+pub mod bank_tests;
 pub mod error_tests;
+pub mod genesis_state_tests;
+pub mod map_state;
 pub mod memory_tests;
+pub mod table_query_tests;
 pub mod version_tests;
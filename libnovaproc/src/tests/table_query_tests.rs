@@ -0,0 +1,202 @@
+use crate::move_api::handler::{load_table_entries, load_table_entry, MAX_SCAN_TABLE_LIMIT};
+use crate::move_api::move_types::{MoveValue, U64};
+use crate::tests::map_state::MapState;
+
+use move_deps::move_core_types::{
+    account_address::AccountAddress, language_storage::TypeTag, value::MoveTypeLayout,
+};
+use nova_types::access_path::AccessPath;
+use nova_types::iterator::Order;
+use nova_types::ordered_key::encode_ordered_key;
+use nova_types::table::{KeyEncoding, TableHandle, TableInfo};
+use nova_types::table_meta::TableMeta;
+
+use std::collections::BTreeMap;
+
+fn table_handle() -> TableHandle {
+    TableHandle(AccountAddress::from_hex_literal("0x123").unwrap())
+}
+
+fn stored_key(key: u64, key_encoding: KeyEncoding) -> Vec<u8> {
+    let bcs_key = bcs::to_bytes(&key).unwrap();
+    match key_encoding {
+        KeyEncoding::Bcs => bcs_key,
+        KeyEncoding::Ordered => encode_ordered_key(&MoveTypeLayout::U64, &bcs_key).unwrap(),
+    }
+}
+
+/// A state holding the `Table<u64, u64>` which maps every key of `keys` to itself.
+struct TableState {
+    map: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl TableState {
+    fn new(keys: impl IntoIterator<Item = u64>, key_encoding: KeyEncoding) -> Self {
+        let handle = table_handle();
+        let table_meta = TableMeta::new(&TableInfo::new(TypeTag::U64, TypeTag::U64, key_encoding));
+
+        let mut map = BTreeMap::new();
+        map.insert(
+            AccessPath::table_meta_access_path(handle.0)
+                .to_bytes()
+                .unwrap(),
+            table_meta.serialize().unwrap(),
+        );
+        for key in keys {
+            map.insert(
+                AccessPath::table_item_access_path(handle.0, stored_key(key, key_encoding))
+                    .to_bytes()
+                    .unwrap(),
+                bcs::to_bytes(&key).unwrap(),
+            );
+        }
+        Self { map }
+    }
+
+    /// Scan a page and return its keys with the stored key of the next page.
+    fn scan(
+        &self,
+        start_key: Option<&[u8]>,
+        limit: u64,
+        order: Order,
+    ) -> (Vec<u64>, Option<Vec<u8>>) {
+        let state = MapState::new(self.map.clone());
+        let mut table_state = MapState::new(self.map.clone());
+        let page = load_table_entries(
+            &state,
+            &mut table_state,
+            &table_handle(),
+            start_key,
+            limit,
+            order,
+        )
+        .unwrap();
+
+        let keys = page
+            .entries
+            .into_iter()
+            .map(|entry| match entry.key {
+                MoveValue::U64(U64(key)) => key,
+                key => panic!("unexpected key {:?}", key),
+            })
+            .collect();
+        (keys, page.next_key.map(|key| key.0))
+    }
+
+    /// Scan the whole table page by page.
+    fn scan_all(&self, limit: u64, order: Order) -> Vec<u64> {
+        let mut keys = vec![];
+        let mut start_key = None;
+        loop {
+            let (page, next_key) = self.scan(start_key.as_deref(), limit, order);
+            assert!(page.len() as u64 <= limit);
+            keys.extend(page);
+            match next_key {
+                Some(next_key) => start_key = Some(next_key),
+                None => return keys,
+            }
+        }
+    }
+}
+
+#[test]
+fn scan_table_pages_in_both_orders() {
+    let state = TableState::new(1..=10, KeyEncoding::Bcs);
+
+    let (keys, next_key) = state.scan(None, 3, Order::Ascending);
+    assert_eq!(keys, vec![1, 2, 3]);
+    assert_eq!(next_key, Some(stored_key(4, KeyEncoding::Bcs)));
+    let (keys, _) = state.scan(next_key.as_deref(), 3, Order::Ascending);
+    assert_eq!(keys, vec![4, 5, 6]);
+
+    // the cursor of a descending page is the first entry of the next page
+    let (keys, next_key) = state.scan(None, 3, Order::Descending);
+    assert_eq!(keys, vec![10, 9, 8]);
+    assert_eq!(next_key, Some(stored_key(7, KeyEncoding::Bcs)));
+    let (keys, _) = state.scan(next_key.as_deref(), 3, Order::Descending);
+    assert_eq!(keys, vec![7, 6, 5]);
+
+    assert_eq!(
+        state.scan_all(3, Order::Ascending),
+        (1..=10).collect::<Vec<u64>>()
+    );
+    assert_eq!(
+        state.scan_all(3, Order::Descending),
+        (1..=10).rev().collect::<Vec<u64>>()
+    );
+    assert_eq!(state.scan(None, 10, Order::Descending).1, None);
+}
+
+#[test]
+fn scan_table_follows_the_key_encoding() {
+    let keys = [1, 2, 256, 65536];
+
+    // BCS keys are little endian, so they do not follow the numeric order
+    let state = TableState::new(keys, KeyEncoding::Bcs);
+    assert_eq!(state.scan_all(2, Order::Ascending), vec![65536, 256, 1, 2]);
+
+    let state = TableState::new(keys, KeyEncoding::Ordered);
+    assert_eq!(state.scan_all(2, Order::Ascending), vec![1, 2, 256, 65536]);
+    assert_eq!(state.scan_all(2, Order::Descending), vec![65536, 256, 2, 1]);
+
+    let (keys, next_key) = state.scan(None, 1, Order::Descending);
+    assert_eq!(keys, vec![65536]);
+    assert_eq!(next_key, Some(stored_key(256, KeyEncoding::Ordered)));
+}
+
+#[test]
+fn scan_table_caps_the_limit() {
+    let state = TableState::new(0..MAX_SCAN_TABLE_LIMIT + 10, KeyEncoding::Ordered);
+
+    let (keys, next_key) = state.scan(None, u64::MAX, Order::Ascending);
+    assert_eq!(keys, (0..MAX_SCAN_TABLE_LIMIT).collect::<Vec<u64>>());
+    assert_eq!(
+        next_key,
+        Some(stored_key(MAX_SCAN_TABLE_LIMIT, KeyEncoding::Ordered))
+    );
+
+    let state = MapState::new(state.map.clone());
+    let mut table_state = MapState::new(BTreeMap::new());
+    assert!(load_table_entries(
+        &state,
+        &mut table_state,
+        &table_handle(),
+        None,
+        0,
+        Order::Ascending
+    )
+    .is_err());
+}
+
+#[test]
+fn decode_table_entry_works() {
+    let table = TableState::new([7], KeyEncoding::Ordered);
+    let state = MapState::new(table.map.clone());
+    let key = stored_key(7, KeyEncoding::Ordered);
+
+    // the value is loaded from the table
+    let entry = load_table_entry(&state, &state, &table_handle(), &key, None).unwrap();
+    assert_eq!(entry.key, MoveValue::U64(U64(7)));
+    assert_eq!(entry.value, MoveValue::U64(U64(7)));
+
+    let value = bcs::to_bytes(&8u64).unwrap();
+    let entry = load_table_entry(&state, &state, &table_handle(), &key, Some(&value)).unwrap();
+    assert_eq!(entry.key, MoveValue::U64(U64(7)));
+    assert_eq!(entry.value, MoveValue::U64(U64(8)));
+
+    // missing entry
+    let key = stored_key(8, KeyEncoding::Ordered);
+    assert!(load_table_entry(&state, &state, &table_handle(), &key, None).is_err());
+
+    // the tables without their types in the meta cannot be decoded
+    let mut map = table.map;
+    map.insert(
+        AccessPath::table_meta_access_path(table_handle().0)
+            .to_bytes()
+            .unwrap(),
+        TableMeta::untyped().serialize().unwrap(),
+    );
+    let state = MapState::new(map);
+    let key = stored_key(7, KeyEncoding::Ordered);
+    assert!(load_table_entry(&state, &state, &table_handle(), &key, None).is_err());
+}
@@ -8,10 +8,13 @@ use crate::Db;
 use crate::GoStorage;
 
 use nova_gas::Gas;
+use nova_natives::bank::BankBridge;
 use nova_storage::state_view_impl::StateViewImpl;
 use nova_storage::table_view_impl::TableViewImpl;
 use nova_types::access_path::AccessPath;
+use nova_types::bank_change_set::{BankChangeSet, BankOp};
 use nova_types::errors::BackendError;
+use nova_types::genesis::{GenesisConfig, StdlibConfig};
 use nova_types::write_set::WriteSet;
 use nova_types::{
     entry_function::EntryFunction, message::Message, module::ModuleBundle, script::Script,
@@ -23,25 +26,71 @@ use move_deps::move_core_types::account_address::AccountAddress;
 use move_deps::move_core_types::effects::Op;
 use move_deps::move_core_types::vm_status::VMStatus;
 
-pub(crate) fn initialize_vm(vm: &mut NovaVM, db_handle: Db, payload: &[u8]) -> Result<(), Error> {
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+pub(crate) fn initialize_vm(
+    vm: &mut NovaVM,
+    db_handle: Db,
+    payload: &[u8],
+    stdlib_payload: Option<&[u8]>,
+) -> Result<(), Error> {
     let mut storage = GoStorage::new(&db_handle);
     let mut table_storage = GoTableStorage::new(&db_handle);
 
     // add passed custom module bundles
     let custom_module_bundle: ModuleBundle = serde_json::from_slice(payload).unwrap();
 
+    // publish the whole standard library, unless the caller selected the modules
+    let stdlib_config: StdlibConfig = match stdlib_payload {
+        Some(stdlib_payload) => {
+            serde_json::from_slice(stdlib_payload).map_err(Error::backend_failure)?
+        }
+        None => StdlibConfig::default(),
+    };
+
     let state_view_impl = StateViewImpl::new(&storage);
     let mut table_view_impl = TableViewImpl::new(&mut table_storage);
     let (status, output, _retval) = vm
         .initialize(
             &state_view_impl,
             &mut table_view_impl,
+            &stdlib_config,
             Some(custom_module_bundle),
         )
-        .unwrap();
+        .map_err(|e| Error::from(e))?;
+
+    match status {
+        VMStatus::Executed => {
+            push_write_set(&mut storage, output.write_set())?;
+        }
+        _ => Err(Error::from(status))?,
+    }
+
+    Ok(())
+}
+
+pub(crate) fn initialize_vm_with_config(
+    vm: &mut NovaVM,
+    db_handle: Db,
+    api: GoApi,
+    payload: &[u8],
+) -> Result<(), Error> {
+    let mut storage = GoStorage::new(&db_handle);
+    let mut table_storage = GoTableStorage::new(&db_handle);
+
+    let genesis_config: GenesisConfig =
+        serde_json::from_slice(payload).map_err(Error::backend_failure)?;
+
+    let state_view_impl = StateViewImpl::new(&storage);
+    let mut table_view_impl = TableViewImpl::new(&mut table_storage);
+    let (status, output, _retval) = vm
+        .initialize_with_config(&state_view_impl, &mut table_view_impl, &api, genesis_config)
+        .map_err(|e| Error::from(e))?;
 
     match status {
         VMStatus::Executed => {
+            apply_bank_change_set(&api, output.bank_change_set())?;
             push_write_set(&mut storage, output.write_set())?;
         }
         _ => Err(Error::from(status))?,
@@ -50,6 +99,38 @@ pub(crate) fn initialize_vm(vm: &mut NovaVM, db_handle: Db, payload: &[u8]) -> R
     Ok(())
 }
 
+pub(crate) fn load_vm_config(vm: &mut NovaVM, db_handle: Db) -> Result<(), Error> {
+    let storage = GoStorage::new(&db_handle);
+    let state_view_impl = StateViewImpl::new(&storage);
+
+    vm.load_vm_config(&state_view_impl)
+        .map_err(|e| Error::from(e))
+}
+
+pub(crate) fn register_txn_arg_struct(
+    vm: &mut NovaVM,
+    struct_name: &[u8],
+    constructor: &[u8],
+) -> Result<(), Error> {
+    let struct_name = std::str::from_utf8(struct_name).map_err(Error::invalid_utf8)?;
+    let constructor = std::str::from_utf8(constructor).map_err(Error::invalid_utf8)?;
+
+    vm.register_txn_arg_struct(struct_name, constructor)
+        .map_err(|e| Error::from(e))
+}
+
+pub(crate) fn unregister_txn_arg_struct(vm: &mut NovaVM, struct_name: &[u8]) -> Result<(), Error> {
+    let struct_name = std::str::from_utf8(struct_name).map_err(Error::invalid_utf8)?;
+
+    match vm.unregister_txn_arg_struct(struct_name) {
+        true => Ok(()),
+        false => Err(Error::backend_failure(format!(
+            "{} is not registered",
+            struct_name
+        ))),
+    }
+}
+
 pub(crate) fn publish_module_bundle(
     vm: &mut NovaVM,
     session_id: Vec<u8>, // seed for global unique session id
@@ -95,6 +176,7 @@ pub(crate) fn execute_script(
     vm: &mut NovaVM,
     session_id: Vec<u8>, // seed for global unique session id
     sender: AccountAddress,
+    fee_payer: Option<AccountAddress>,
     payload: Vec<u8>,
     db_handle: Db,
     api: GoApi,
@@ -104,6 +186,7 @@ pub(crate) fn execute_script(
         vm,
         session_id,
         Some(sender),
+        fee_payer,
         payload,
         db_handle,
         api,
@@ -116,6 +199,7 @@ pub(crate) fn execute_contract(
     vm: &mut NovaVM,
     session_id: Vec<u8>, // seed for global unique session id
     sender: AccountAddress,
+    fee_payer: Option<AccountAddress>,
     payload: Vec<u8>,
     db_handle: Db,
     api: GoApi,
@@ -125,6 +209,7 @@ pub(crate) fn execute_contract(
         vm,
         session_id,
         Some(sender),
+        fee_payer,
         payload,
         db_handle,
         api,
@@ -141,7 +226,17 @@ pub(crate) fn query_contract(
     api: GoApi,
     gas: u64,
 ) -> Result<Vec<u8>, Error> {
-    execute_entry_function_internal(vm, vec![0; 32], None, payload, db_handle, api, gas, true)
+    execute_entry_function_internal(
+        vm,
+        vec![0; 32],
+        None,
+        None,
+        payload,
+        db_handle,
+        api,
+        gas,
+        true,
+    )
 }
 
 /////////////////////////////////////////
@@ -152,6 +247,7 @@ fn execute_entry_function_internal(
     vm: &mut NovaVM,
     session_id: Vec<u8>, // seed for global unique session id
     sender: Option<AccountAddress>,
+    fee_payer: Option<AccountAddress>,
     payload: Vec<u8>,
     db_handle: Db,
     api: GoApi,
@@ -167,7 +263,10 @@ fn execute_entry_function_internal(
     let gas_limit = Gas::new(gas);
 
     let ef: EntryFunction = serde_json::from_slice(payload.as_slice()).unwrap();
-    let message: Message = Message::new_entry_function(session_id, sender, ef);
+    let mut message: Message = Message::new_entry_function(session_id, sender, ef);
+    if let Some(fee_payer) = fee_payer {
+        message = message.with_fee_payer(fee_payer);
+    }
 
     let state_view_impl = StateViewImpl::new(&storage);
     let mut table_view_impl = TableViewImpl::new(&mut table_storage);
@@ -185,6 +284,7 @@ fn execute_entry_function_internal(
     match status {
         VMStatus::Executed => {
             if !is_query {
+                apply_bank_change_set(&api, output.bank_change_set())?;
                 push_write_set(&mut storage, output.write_set())?;
             }
 
@@ -221,6 +321,100 @@ pub fn push_write_set(go_storage: &mut GoStorage, write_set: &WriteSet) -> Backe
     Ok(())
 }
 
+/// Apply the bank operations buffered by a succeeded message. All operations
+/// are validated against the bank balances and supply before the first one is
+/// applied, so a message never leaves the bank partially updated.
+pub fn apply_bank_change_set(
+    bank: &dyn BankBridge,
+    bank_change_set: &BankChangeSet,
+) -> Result<(), Error> {
+    validate_bank_change_set(bank, bank_change_set)?;
+
+    for op in bank_change_set.ops() {
+        match op {
+            BankOp::Withdraw {
+                denom,
+                address,
+                amount,
+            } => bank.bank_withdraw(denom, address, *amount),
+            BankOp::Deposit {
+                denom,
+                address,
+                amount,
+            } => bank.bank_deposit(denom, address, *amount),
+        }
+        .map_err(Error::backend_failure)?;
+    }
+
+    Ok(())
+}
+
+/// Replay the bank operations on top of the current balances. A withdrawal needs
+/// the balance of the account, and the coins released by the deposits, beyond
+/// the ones withdrawn by the same message, cannot exceed the supply of the denom.
+fn validate_bank_change_set(
+    bank: &dyn BankBridge,
+    bank_change_set: &BankChangeSet,
+) -> Result<(), Error> {
+    let mut balances: BTreeMap<(&str, AccountAddress), u64> = BTreeMap::new();
+    let mut released: BTreeMap<&str, i128> = BTreeMap::new();
+
+    for op in bank_change_set.ops() {
+        let (denom, address, amount, withdraw) = match op {
+            BankOp::Withdraw {
+                denom,
+                address,
+                amount,
+            } => (denom.as_str(), *address, *amount, true),
+            BankOp::Deposit {
+                denom,
+                address,
+                amount,
+            } => (denom.as_str(), *address, *amount, false),
+        };
+
+        let balance = match balances.entry((denom, address)) {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(
+                bank.bank_balance(denom, &address)
+                    .map_err(Error::backend_failure)?,
+            ),
+        };
+
+        if withdraw {
+            *balance = balance.checked_sub(amount).ok_or_else(|| {
+                Error::backend_failure(format!(
+                    "insufficient balance to withdraw {}{} from {}",
+                    amount, denom, address
+                ))
+            })?;
+            *released.entry(denom).or_default() -= amount as i128;
+        } else {
+            let supply = bank.bank_supply(denom).map_err(Error::backend_failure)?;
+            let total_released = released.entry(denom).or_default();
+            *total_released += amount as i128;
+            if *total_released > supply as i128 {
+                return Err(Error::backend_failure(format!(
+                    "deposit of {}{} to {} exceeds the supply",
+                    amount, denom, address
+                )));
+            }
+
+            *balance = balance
+                .checked_add(amount)
+                .filter(|balance| *balance <= supply)
+                .ok_or_else(|| {
+                    Error::backend_failure(format!(
+                        "deposit of {}{} to {} exceeds the supply",
+                        amount, denom, address
+                    ))
+                })?;
+        }
+    }
+
+    Ok(())
+}
+
 /////////////////////////////////////////
 /// Script //////////////////////////////
 /////////////////////////////////////////
@@ -229,6 +423,7 @@ fn execute_script_internal(
     vm: &mut NovaVM,
     session_id: Vec<u8>, // seed for global unique session id
     sender: Option<AccountAddress>,
+    fee_payer: Option<AccountAddress>,
     payload: Vec<u8>,
     db_handle: Db,
     api: GoApi,
@@ -245,7 +440,10 @@ fn execute_script_internal(
     let gas_limit = Gas::new(gas);
 
     let script: Script = serde_json::from_slice(payload.as_slice()).unwrap();
-    let message: Message = Message::new_script(session_id, sender, script);
+    let mut message: Message = Message::new_script(session_id, sender, script);
+    if let Some(fee_payer) = fee_payer {
+        message = message.with_fee_payer(fee_payer);
+    }
 
     // NOTE - storage passed as mut for iterator implementation
     let state_view_impl = StateViewImpl::new(&storage);
@@ -264,6 +462,7 @@ fn execute_script_internal(
     match status {
         VMStatus::Executed => {
             if !is_query {
+                apply_bank_change_set(&api, output.bank_change_set())?;
                 push_write_set(&mut storage, output.write_set())?;
             }
 
@@ -37,6 +37,16 @@ func NewModuleBundle(modules ...Module) ModuleBundle {
 	return ModuleBundle{modules}
 }
 
+// Order defines the iteration order of table scans
+type Order int32
+
+const (
+	// OrderAscending iterates keys from the smallest to the largest
+	OrderAscending Order = 1
+	// OrderDescending iterates keys from the largest to the smallest
+	OrderDescending Order = 2
+)
+
 // AccountAddressLen address bytes length
 const AccountAddressLen = 20
 
@@ -165,6 +175,69 @@ type ExecuteEntryFunctionPayload struct {
 	Args     []Bytes    `json:"args"`
 }
 
+// GenesisConfig is applied by InitializeWithConfig in a single
+// session: modules are published, accounts are created, coins are
+// minted and init functions are executed, in that order.
+// GasSchedule and ModulePublishers are stored in 0x1::vm_config and
+// configure the vm once the genesis succeeded; VM.LoadVMConfig loads
+// them back from the store when the chain restarts.
+type GenesisConfig struct {
+	// Stdlib selects the standard libraries; every standard library
+	// is published when it is nil
+	Stdlib       *StdlibConfig    `json:"stdlib,omitempty"`
+	ModuleBundle *ModuleBundle    `json:"module_bundle,omitempty"`
+	Accounts     []AccountAddress `json:"accounts,omitempty"`
+	Coins        []GenesisCoin    `json:"coins,omitempty"`
+	// GasSchedule replaces the whole gas schedule when it is not empty
+	GasSchedule map[string]uint64 `json:"gas_schedule,omitempty"`
+	// ModulePublishers limits the accounts which can publish modules;
+	// every account can publish when it is empty
+	ModulePublishers []AccountAddress `json:"module_publishers,omitempty"`
+	// InitFunctions are executed with the 0x1 signer
+	InitFunctions []ExecuteEntryFunctionPayload `json:"init_functions,omitempty"`
+}
+
+// StdlibBundle is a bundle of the standard library
+type StdlibBundle string
+
+const (
+	StdlibBundleMoveStdlib  StdlibBundle = "move_stdlib"
+	StdlibBundleMoveNursery StdlibBundle = "move_nursery"
+	StdlibBundleNovaStdlib  StdlibBundle = "nova_stdlib"
+)
+
+// StdlibModule is a single module of a standard library bundle
+type StdlibModule struct {
+	Bundle StdlibBundle `json:"bundle"`
+	Name   string       `json:"name"`
+}
+
+// StdlibConfig selects the standard library modules published
+// at genesis: every module of Bundles and the single Modules
+// picked from the other bundles. The selected modules have to
+// contain all of their dependencies. The zero value selects
+// every standard library.
+type StdlibConfig struct {
+	Bundles []StdlibBundle `json:"bundles,omitempty"`
+	Modules []StdlibModule `json:"modules,omitempty"`
+}
+
+// GenesisCoin is a native coin, declared by a module at 0x1,
+// initialized at genesis with the initial balances
+type GenesisCoin struct {
+	CoinType TypeTag          `json:"coin_type"`
+	Name     string           `json:"name"`
+	Symbol   string           `json:"symbol"`
+	Decimals uint8            `json:"decimals"`
+	Balances []GenesisBalance `json:"balances,omitempty"`
+}
+
+// GenesisBalance is the initial balance of an account
+type GenesisBalance struct {
+	Address AccountAddress `json:"address"`
+	Amount  uint64         `json:"amount"`
+}
+
 type ExecuteScriptPayload struct {
 	Code   Bytes     `json:"code"`
 	TyArgs []TypeTag `json:"ty_args"`
@@ -250,6 +323,18 @@ func (o OutOfGasError) Error() string {
 	return "Out of gas"
 }
 
+// StorageQuotaExceededError is returned when a message grows the storage
+// of an account over the storage quota of the vm.
+type StorageQuotaExceededError struct {
+	Msg string
+}
+
+var _ error = StorageQuotaExceededError{}
+
+func (e StorageQuotaExceededError) Error() string {
+	return e.Msg
+}
+
 type Event struct {
 	Key     []byte  `json:"key"`
 	SeqNum  uint64  `json:"sequence_number"`
@@ -263,9 +348,22 @@ type SizeDelta struct {
 	IsDecreasing bool           `json:"is_decreasing"`
 }
 
+// StorageRefund is the part of the storage deposit refunded to an account
+// for the bytes it removed, in gas units
+type StorageRefund struct {
+	Address AccountAddress `json:"address"`
+	Amount  uint64         `json:"amount"`
+}
+
+// ExecutionResult is the result of a message; StorageFee is the storage
+// deposit charged for the bytes added, which is already included in GasUsed,
+// and StorageRefunds have to be paid back to the accounts by the chain.
 type ExecutionResult struct {
-	Result     []byte      `json:"result"`
-	Events     []Event     `json:"events"`
-	SizeDeltas []SizeDelta `json:"size_deltas"`
-	GasUsed    uint64      `json:"gas_used"`
+	Result         []byte          `json:"result"`
+	Events         []Event         `json:"events"`
+	SizeDeltas     []SizeDelta     `json:"size_deltas"`
+	GasUsed        uint64          `json:"gas_used"`
+	StorageFee     uint64          `json:"storage_fee"`
+	StorageRefunds []StorageRefund `json:"storage_refunds"`
+	FeePayer       *AccountAddress `json:"fee_payer"`
 }